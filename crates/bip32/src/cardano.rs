@@ -0,0 +1,709 @@
+//! BIP32-Ed25519 key derivation for Cardano.
+//!
+//! Cardano does not use SLIP-10. Its wallets follow BIP32-Ed25519
+//! (Khovratovich and Law), where the private key is an extended ed25519
+//! secret `kL || kR` used directly as a scalar, so that normal ("soft")
+//! children can be derived from the public key as in BIP32:
+//!
+//! - The master key is generated with the Icarus scheme from CIP-3: PBKDF2
+//!   over the BIP39 *entropy*, not the BIP39 seed, then clamped.
+//! - Children are derived with Cardano's "V2" scheme: the index is
+//!   serialized little-endian, `8·ZL` is added to `kL` and `ZR` to `kR`.
+//!
+//! Keys serialize to the raw 96-byte `kL || kR || chain code` and 64-byte
+//! `A || chain code` forms used by Cardano wallet libraries.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip32::{CardanoExtendedPrivateKey, DerivationPath};
+//! use khodpay_bip39::{Language, Mnemonic};
+//! use std::str::FromStr;
+//!
+//! let phrase = "eight country switch draw meat scout mystery blade tip drift useless good keep usage title";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English)?;
+//! let root = CardanoExtendedPrivateKey::from_mnemonic(&mnemonic, "");
+//!
+//! // CIP-1852 account, then the first payment key from the account public key
+//! let account = root.derive_path(&DerivationPath::from_str("m/1852'/1815'/0'")?)?;
+//! let payment = account
+//!     .to_extended_public_key()
+//!     .derive_path(&DerivationPath::from_str("m/0/0")?)?;
+//! assert_eq!(payment.depth(), 5);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{ChainCode, ChildNumber, DerivationPath, Error, Result};
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::Scalar;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use std::fmt;
+use zeroize::{Zeroize, Zeroizing};
+
+/// PBKDF2 iterations of the Icarus master key generation.
+const ICARUS_ITERATIONS: u32 = 4096;
+
+/// Size of a serialized extended private key: `kL || kR || chain code`.
+const PRIVATE_KEY_LENGTH: usize = 96;
+
+/// Size of a serialized extended public key: `A || chain code`.
+const PUBLIC_KEY_LENGTH: usize = 64;
+
+/// A Cardano BIP32-Ed25519 extended private key.
+///
+/// The 64-byte extended secret and the chain code are zeroized on drop and
+/// redacted from `Debug` output.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip32::{CardanoExtendedPrivateKey, ChildNumber};
+///
+/// let root = CardanoExtendedPrivateKey::from_entropy(&[0x0c; 16], "");
+/// let child = root.derive_child(ChildNumber::Normal(0))?;
+/// assert_eq!(child.depth(), 1);
+///
+/// let restored = CardanoExtendedPrivateKey::from_bytes(&child.to_bytes())?;
+/// assert_eq!(restored.public_key(), child.public_key());
+/// # Ok::<(), khodpay_bip32::Error>(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct CardanoExtendedPrivateKey {
+    depth: u8,
+    child_number: ChildNumber,
+    chain_code: ChainCode,
+    /// `kL || kR`, both little-endian.
+    extended_secret: Zeroizing<[u8; 64]>,
+}
+
+impl CardanoExtendedPrivateKey {
+    /// Maximum depth of the derivation tree, as in BIP32.
+    pub const MAX_DEPTH: u8 = 255;
+
+    /// Generates the Icarus master key from BIP39 entropy.
+    ///
+    /// # Arguments
+    ///
+    /// * `entropy` - The entropy encoded by the mnemonic, not its seed
+    /// * `passphrase` - The optional wallet passphrase, used as raw UTF-8
+    ///   bytes without normalization (empty for none)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::CardanoExtendedPrivateKey;
+    ///
+    /// let entropy = hex::decode("46e62370a138a182a498b8e2885bc032379ddf38").unwrap();
+    /// let root = CardanoExtendedPrivateKey::from_entropy(&entropy, "");
+    /// assert!(hex::encode(root.to_bytes().as_slice()).starts_with("c065afd2832cd8b0"));
+    /// ```
+    pub fn from_entropy(entropy: &[u8], passphrase: &str) -> Self {
+        let mut output = Zeroizing::new([0u8; PRIVATE_KEY_LENGTH]);
+        pbkdf2::pbkdf2_hmac::<Sha512>(
+            passphrase.as_bytes(),
+            entropy,
+            ICARUS_ITERATIONS,
+            output.as_mut_slice(),
+        );
+        output[0] &= 0xf8;
+        output[31] &= 0x1f;
+        output[31] |= 0x40;
+
+        let mut extended_secret = Zeroizing::new([0u8; 64]);
+        extended_secret.copy_from_slice(&output[..64]);
+        Self {
+            depth: 0,
+            child_number: ChildNumber::Normal(0),
+            chain_code: ChainCode::from_bytes(&output[64..]).expect("chain code is 32 bytes"),
+            extended_secret,
+        }
+    }
+
+    /// Generates the Icarus master key from a BIP39 mnemonic.
+    ///
+    /// Equivalent to [`from_entropy`](Self::from_entropy) with the
+    /// mnemonic's entropy.
+    pub fn from_mnemonic(mnemonic: &khodpay_bip39::Mnemonic, passphrase: &str) -> Self {
+        Self::from_entropy(mnemonic.entropy(), passphrase)
+    }
+
+    /// Parses a 96-byte `kL || kR || chain code` extended private key.
+    ///
+    /// The serialized form does not record the key's position, so the
+    /// result has depth 0 and child number 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrivateKey`] if `kL` does not have the low 3 bits
+    /// cleared and the highest 3 bits set to `010`.
+    pub fn from_bytes(bytes: &[u8; PRIVATE_KEY_LENGTH]) -> Result<Self> {
+        if bytes[0] & 0x07 != 0 {
+            return Err(Error::InvalidPrivateKey {
+                reason: "The lowest 3 bits of kL must be cleared".to_string(),
+            });
+        }
+        if bytes[31] & 0xe0 != 0x40 {
+            return Err(Error::InvalidPrivateKey {
+                reason: "The highest 3 bits of kL must be 010".to_string(),
+            });
+        }
+
+        let mut extended_secret = Zeroizing::new([0u8; 64]);
+        extended_secret.copy_from_slice(&bytes[..64]);
+        Ok(Self {
+            depth: 0,
+            child_number: ChildNumber::Normal(0),
+            chain_code: ChainCode::from_bytes(&bytes[64..])?,
+            extended_secret,
+        })
+    }
+
+    /// Serializes the key as `kL || kR || chain code`.
+    pub fn to_bytes(&self) -> Zeroizing<[u8; PRIVATE_KEY_LENGTH]> {
+        let mut bytes = Zeroizing::new([0u8; PRIVATE_KEY_LENGTH]);
+        bytes[..64].copy_from_slice(self.extended_secret.as_slice());
+        bytes[64..].copy_from_slice(self.chain_code.as_bytes());
+        bytes
+    }
+
+    /// Returns the depth in the derivation tree (0 for the master key).
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the child number this key was derived with.
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+
+    /// Returns the chain code.
+    pub fn chain_code(&self) -> &ChainCode {
+        &self.chain_code
+    }
+
+    /// Returns the 64-byte extended secret `kL || kR`.
+    pub fn extended_secret(&self) -> &[u8; 64] {
+        &self.extended_secret
+    }
+
+    /// Returns the 32-byte ed25519 public key `A = kL·B`.
+    pub fn public_key(&self) -> [u8; 32] {
+        // kL·B = (kL mod ℓ)·B, as B has order ℓ
+        let scalar = Zeroizing::new(Scalar::from_bytes_mod_order(*self.scalar()));
+        EdwardsPoint::mul_base(&scalar).compress().to_bytes()
+    }
+
+    /// Returns the extended public key with the same chain code and position.
+    pub fn to_extended_public_key(&self) -> CardanoExtendedPublicKey {
+        CardanoExtendedPublicKey {
+            depth: self.depth,
+            child_number: self.child_number,
+            chain_code: self.chain_code.clone(),
+            public_key: self.public_key(),
+        }
+    }
+
+    /// Derives a hardened or normal child key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MaxDepthExceeded`] if this key is already at maximum depth (255).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{CardanoExtendedPrivateKey, ChildNumber};
+    ///
+    /// let root = CardanoExtendedPrivateKey::from_entropy(&[0x0c; 16], "");
+    /// let purpose = root.derive_child(ChildNumber::Hardened(1852))?;
+    /// assert_eq!(purpose.child_number(), ChildNumber::Hardened(1852));
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self> {
+        if self.depth == Self::MAX_DEPTH {
+            return Err(Error::MaxDepthExceeded {
+                depth: Self::MAX_DEPTH,
+            });
+        }
+
+        let index = child_number.to_index().to_le_bytes();
+        let mut z;
+        let chain_code;
+        if child_number.is_hardened() {
+            z = hmac_sha512(
+                &self.chain_code,
+                &[&[0x00], &self.extended_secret[..], &index],
+            );
+            chain_code = child_chain_code(
+                &self.chain_code,
+                &[&[0x01], &self.extended_secret[..], &index],
+            );
+        } else {
+            let public_key = self.public_key();
+            z = hmac_sha512(&self.chain_code, &[&[0x02], &public_key, &index]);
+            chain_code = child_chain_code(&self.chain_code, &[&[0x03], &public_key, &index]);
+        }
+
+        // kL' = kL + 8·ZL[..28] and kR' = kR + ZR, both modulo 2^256
+        let mut extended_secret = Zeroizing::new([0u8; 64]);
+        let mut carry = 0u16;
+        for i in 0..32 {
+            let zl = if i < 28 { u16::from(z[i]) << 3 } else { 0 };
+            let sum = u16::from(self.extended_secret[i]) + zl + carry;
+            extended_secret[i] = sum as u8;
+            carry = sum >> 8;
+        }
+        carry = 0;
+        for i in 32..64 {
+            let sum = u16::from(self.extended_secret[i]) + u16::from(z[i]) + carry;
+            extended_secret[i] = sum as u8;
+            carry = sum >> 8;
+        }
+        z.zeroize();
+
+        Ok(Self {
+            depth: self.depth + 1,
+            child_number,
+            chain_code,
+            extended_secret,
+        })
+    }
+
+    /// Derives the key at `path` relative to this key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from [`derive_child`](Self::derive_child).
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        let mut current = self.clone();
+        for child_number in path.iter() {
+            current = current.derive_child(*child_number)?;
+        }
+        Ok(current)
+    }
+
+    /// Returns `kL`.
+    fn scalar(&self) -> &[u8; 32] {
+        self.extended_secret[..32]
+            .try_into()
+            .expect("kL is 32 bytes")
+    }
+}
+
+impl fmt::Debug for CardanoExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CardanoExtendedPrivateKey")
+            .field("depth", &self.depth)
+            .field("child_number", &self.child_number)
+            .field("chain_code", &"[REDACTED]")
+            .field("extended_secret", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// A Cardano BIP32-Ed25519 extended public key.
+///
+/// Derives the public keys of normal children without the private key, as
+/// Cardano wallets do for payment and stake addresses.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CardanoExtendedPublicKey {
+    depth: u8,
+    child_number: ChildNumber,
+    chain_code: ChainCode,
+    public_key: [u8; 32],
+}
+
+impl CardanoExtendedPublicKey {
+    /// Maximum depth of the derivation tree, as in BIP32.
+    pub const MAX_DEPTH: u8 = 255;
+
+    /// Parses a 64-byte `A || chain code` extended public key.
+    ///
+    /// The serialized form does not record the key's position, so the
+    /// result has depth 0 and child number 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPublicKey`] if `A` is not a valid ed25519 point.
+    pub fn from_bytes(bytes: &[u8; PUBLIC_KEY_LENGTH]) -> Result<Self> {
+        let public_key: [u8; 32] = bytes[..32].try_into().expect("A is 32 bytes");
+        if CompressedEdwardsY(public_key).decompress().is_none() {
+            return Err(Error::InvalidPublicKey {
+                reason: "Not a valid ed25519 point".to_string(),
+            });
+        }
+        Ok(Self {
+            depth: 0,
+            child_number: ChildNumber::Normal(0),
+            chain_code: ChainCode::from_bytes(&bytes[32..])?,
+            public_key,
+        })
+    }
+
+    /// Serializes the key as `A || chain code`.
+    pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_LENGTH] {
+        let mut bytes = [0u8; PUBLIC_KEY_LENGTH];
+        bytes[..32].copy_from_slice(&self.public_key);
+        bytes[32..].copy_from_slice(self.chain_code.as_bytes());
+        bytes
+    }
+
+    /// Returns the depth in the derivation tree (0 for the master key).
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the child number this key was derived with.
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+
+    /// Returns the chain code.
+    pub fn chain_code(&self) -> &ChainCode {
+        &self.chain_code
+    }
+
+    /// Returns the 32-byte ed25519 public key.
+    pub fn public_key(&self) -> &[u8; 32] {
+        &self.public_key
+    }
+
+    /// Derives the extended public key of a normal child.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::HardenedDerivationFromPublicKey`] if `child_number` is hardened.
+    /// Returns [`Error::MaxDepthExceeded`] if this key is already at maximum depth (255).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{CardanoExtendedPrivateKey, ChildNumber};
+    ///
+    /// let account = CardanoExtendedPrivateKey::from_entropy(&[0x0c; 16], "")
+    ///     .derive_child(ChildNumber::Hardened(0))?;
+    /// let from_public = account
+    ///     .to_extended_public_key()
+    ///     .derive_child(ChildNumber::Normal(3))?;
+    /// let from_private = account.derive_child(ChildNumber::Normal(3))?;
+    /// assert_eq!(from_public.public_key(), &from_private.public_key());
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self> {
+        if child_number.is_hardened() {
+            return Err(Error::HardenedDerivationFromPublicKey {
+                index: child_number.to_index(),
+            });
+        }
+        if self.depth == Self::MAX_DEPTH {
+            return Err(Error::MaxDepthExceeded {
+                depth: Self::MAX_DEPTH,
+            });
+        }
+
+        let index = child_number.to_index().to_le_bytes();
+        let z = hmac_sha512(&self.chain_code, &[&[0x02], &self.public_key, &index]);
+
+        // A' = A + (8·ZL[..28])·B
+        let mut tweak = [0u8; 32];
+        let mut carry = 0u16;
+        for i in 0..29 {
+            let zl = if i < 28 { u16::from(z[i]) << 3 } else { 0 };
+            let shifted = zl + carry;
+            tweak[i] = shifted as u8;
+            carry = shifted >> 8;
+        }
+        let parent = CompressedEdwardsY(self.public_key)
+            .decompress()
+            .ok_or_else(|| Error::InvalidPublicKey {
+                reason: "Not a valid ed25519 point".to_string(),
+            })?;
+        let tweak = EdwardsPoint::mul_base(&Scalar::from_bytes_mod_order(tweak));
+        let public_key = (parent + tweak).compress().to_bytes();
+
+        Ok(Self {
+            depth: self.depth + 1,
+            child_number,
+            chain_code: child_chain_code(&self.chain_code, &[&[0x03], &self.public_key, &index]),
+            public_key,
+        })
+    }
+
+    /// Derives the extended public key at `path` relative to this key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from [`derive_child`](Self::derive_child).
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        let mut current = self.clone();
+        for child_number in path.iter() {
+            current = current.derive_child(*child_number)?;
+        }
+        Ok(current)
+    }
+}
+
+impl fmt::Debug for CardanoExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CardanoExtendedPublicKey")
+            .field("depth", &self.depth)
+            .field("child_number", &self.child_number)
+            .field("chain_code", &self.chain_code)
+            .field("public_key", &hex::encode(self.public_key))
+            .finish()
+    }
+}
+
+/// Computes HMAC-SHA512 keyed with the chain code over the concatenation of `data`.
+fn hmac_sha512(chain_code: &ChainCode, data: &[&[u8]]) -> [u8; 64] {
+    let mut hmac = Hmac::<Sha512>::new_from_slice(chain_code.as_bytes())
+        .expect("HMAC can take key of any size");
+    for part in data {
+        hmac.update(part);
+    }
+    hmac.finalize().into_bytes().into()
+}
+
+/// Returns the right half of the chain code HMAC as the child chain code.
+fn child_chain_code(chain_code: &ChainCode, data: &[&[u8]]) -> ChainCode {
+    let mut result = hmac_sha512(chain_code, data);
+    let child = ChainCode::from_bytes(&result[32..]).expect("chain code is 32 bytes");
+    result.zeroize();
+    child
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blake2::digest::consts::U28;
+    use blake2::{Blake2b, Digest};
+    use khodpay_bip39::{Language, Mnemonic};
+    use std::str::FromStr;
+
+    const PHRASE: &str =
+        "eight country switch draw meat scout mystery blade tip drift useless good keep usage title";
+
+    fn root() -> CardanoExtendedPrivateKey {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        CardanoExtendedPrivateKey::from_mnemonic(&mnemonic, "")
+    }
+
+    #[test]
+    fn test_icarus_vectors() {
+        // CIP-3, Icarus master key generation
+        assert_eq!(
+            hex::encode(root().to_bytes().as_slice()),
+            "c065afd2832cd8b087c4d9ab7011f481ee1e0721e78ea5dd609f3ab3f156d245\
+             d176bd8fd4ec60b4731c3918a2a72a0226c0cd119ec35b47e4d55884667f552a\
+             23f7fdcd4a10c6cd2c7393ac61d877873e248f417634aa3d812af327ffe9d620"
+        );
+
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let with_passphrase = CardanoExtendedPrivateKey::from_mnemonic(&mnemonic, "foo");
+        assert_eq!(
+            hex::encode(with_passphrase.to_bytes().as_slice()),
+            "70531039904019351e1afb361cd1b312a4d0565d4ff9f8062d38acf4b15cce41\
+             d7b5738d9c893feea55512a3004acb0d222c35d3e3d5cde943a15a9824cbac59\
+             443cf67e589614076ba01e354b1a432e0e6db3b59e37fc56b5fb0222970a010e"
+        );
+    }
+
+    #[test]
+    fn test_cip1852_derivation() {
+        let root = root();
+        assert_eq!(
+            hex::encode(root.public_key()),
+            "757e95578798ef733ad93be322fb043053d56b445d3fe502bcf7cb4a6b0f0c6a"
+        );
+
+        let account = root
+            .derive_path(&DerivationPath::from_str("m/1852'/1815'/0'").unwrap())
+            .unwrap();
+        assert_eq!(
+            hex::encode(account.to_bytes().as_slice()),
+            "f80081fa05eece83236e612463aafad20d6b92eee67479a1977959540057d245\
+             2173fe9a0fccf61cf2cc7c52638f2ded6c08002a71424ca5b93681ee7a385828\
+             332b13689518700be3c6d330d72490c42e8a98b7495889a27851e543319fb095"
+        );
+        assert_eq!(
+            hex::encode(account.public_key()),
+            "7f376415131590bf8cc88e8466fd24a6f95eebd6c2271d89cb51a81402618c9b"
+        );
+
+        let payment = account
+            .derive_path(&DerivationPath::from_str("m/0/0").unwrap())
+            .unwrap();
+        assert_eq!(
+            hex::encode(payment.to_bytes().as_slice()),
+            "00df3ecf0e02979dd9ee569d09412c1f370f476054aaa1ef3cf5a08c0557d245\
+             a6ad0fe81ab55e36178f5866dc8f83cf57239fdeee35c737ef887964aae20500\
+             2b2dd0a9b83141f6650c40abec9ed52ecaa6a567825cb2c7a14b9452bca0c020"
+        );
+        assert_eq!(
+            hex::encode(payment.public_key()),
+            "cc9809944150c00f3913cd2b103e9b42fe6243fc36a76f9eb800692e2bda3f2e"
+        );
+        assert_eq!(payment.depth(), 5);
+    }
+
+    #[test]
+    fn test_csl_root_key() {
+        // cardano-serialization-lib `xprv_128_test`: "art forum devote street sure
+        // rather head chuckle guard poverty release quote oak craft enemy"
+        let entropy = hex::decode("0ccb74f36b7da1649a8144675522d4d8097c6412").unwrap();
+        let root = CardanoExtendedPrivateKey::from_entropy(&entropy, "");
+        assert_eq!(
+            hex::encode(root.to_bytes().as_slice()),
+            "b8f2bece9bdfe2b0282f5bad705562ac996efb6af96b648f4445ec44f47ad95c\
+             10e3d72f26ed075422a36ed8585c745a0e1150bcceba2357d058636991f38a37\
+             91e248de509c070d812ab2fda57860ac876bc489192c1ef4ce253c197ee219a4"
+        );
+        assert_eq!(
+            hex::encode(root.public_key()),
+            "cf76399a210de8720e9fa894e45e41e29ab525e30bc402801c076250d1585bcd"
+        );
+    }
+
+    #[test]
+    fn test_v2_hardened_derivation() {
+        // ed25519-bip32 `xprv_derive`: D1 and its child 0'
+        let parent: [u8; 96] = hex::decode(
+            "f8a29231ee38d6c5bf715d5bac21c750577aa3798b22d79d65bf97d6fadea15a\
+             dcd1ee1abdf78bd4be64731a12deb94d3671784112eb6f364b871851fd1c9a24\
+             7384db9ad6003bbd08b3b1ddc0d07a597293ff85e961bf252b331262eddfad0d",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let child = CardanoExtendedPrivateKey::from_bytes(&parent)
+            .unwrap()
+            .derive_child(ChildNumber::Hardened(0))
+            .unwrap();
+        assert_eq!(
+            hex::encode(child.to_bytes().as_slice()),
+            "60d399da83ef80d8d4f8d223239efdc2b8fef387e1b5219137ffb4e8fbdea15a\
+             dc9366b7d003af37c11396de9a83734e30e05e851efa32745c9cd7b42712c890\
+             608763770eddf77248ab652984b21b849760d1da74a6f5bd633ce41adceef07a"
+        );
+    }
+
+    #[test]
+    fn test_csl_base_address_keys() {
+        // cardano-serialization-lib `bip32_{12,15,24}_base`: the payment and
+        // stake key hashes (Blake2b-224 of m/1852'/1815'/0'/0/0 and
+        // m/1852'/1815'/0'/2/0) carried by addr1qx2fxv2u…, addr1q9u5vlrf… and
+        // addr1qyy6nhfy…
+        let cases = [
+            (
+                "df9ed25ed146bf43336a5d7cf7395994",
+                "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e",
+                "32c728d3861e164cab28cb8f006448139c8f1740ffb8e7aa9e5232dc",
+            ),
+            (
+                "0ccb74f36b7da1649a8144675522d4d8097c6412",
+                "79467c69a9ac66280174d09d62575ba955748b21dec3b483a9469a65",
+                "cc339a35f9e0fe039cf510c761d4dd29040c48e9657fdac7e9c01d94",
+            ),
+            (
+                "4e828f9a67ddcff0e6391ad4f26ddb7579f59ba14b6dd4baf63dcfdb9d2420da",
+                "09a9dd24b43ce6f22de361f2423e62a8a72a6ebb1e972a69ebb3bdcc",
+                "f63ad603a628ea1f7397b90b0d13274543fe50a4ef5819ec332ffc63",
+            ),
+        ];
+        for (entropy, payment, stake) in cases {
+            let root = CardanoExtendedPrivateKey::from_entropy(&hex::decode(entropy).unwrap(), "");
+            let account = root
+                .derive_path(&DerivationPath::from_str("m/1852'/1815'/0'").unwrap())
+                .unwrap();
+            for (path, expected) in [("m/0/0", payment), ("m/2/0", stake)] {
+                let path = DerivationPath::from_str(path).unwrap();
+                let from_private = account.derive_path(&path).unwrap().public_key();
+                let from_public = *account
+                    .to_extended_public_key()
+                    .derive_path(&path)
+                    .unwrap()
+                    .public_key();
+                assert_eq!(from_public, from_private);
+                assert_eq!(hex::encode(Blake2b::<U28>::digest(from_public)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_public_derivation_matches_private() {
+        let account = root()
+            .derive_path(&DerivationPath::from_str("m/1852'/1815'/0'").unwrap())
+            .unwrap();
+        let path = DerivationPath::from_str("m/0/7/2147483647").unwrap();
+        let from_private = account.derive_path(&path).unwrap();
+        let from_public = account.to_extended_public_key().derive_path(&path).unwrap();
+        assert_eq!(from_public, from_private.to_extended_public_key());
+    }
+
+    #[test]
+    fn test_public_derivation_rejects_hardened() {
+        let root = root().to_extended_public_key();
+        assert_eq!(
+            root.derive_child(ChildNumber::Hardened(0)),
+            Err(Error::HardenedDerivationFromPublicKey { index: 0x80000000 })
+        );
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let key = root().derive_child(ChildNumber::Hardened(1852)).unwrap();
+        let restored = CardanoExtendedPrivateKey::from_bytes(&key.to_bytes()).unwrap();
+        assert_eq!(restored.extended_secret(), key.extended_secret());
+        assert_eq!(restored.chain_code(), key.chain_code());
+        assert_eq!(restored.depth(), 0);
+
+        let public = key.to_extended_public_key();
+        let restored = CardanoExtendedPublicKey::from_bytes(&public.to_bytes()).unwrap();
+        assert_eq!(restored.public_key(), public.public_key());
+        assert_eq!(restored.chain_code(), public.chain_code());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_invalid_keys() {
+        let mut bytes = *root().to_bytes();
+        bytes[0] |= 0x01;
+        assert!(matches!(
+            CardanoExtendedPrivateKey::from_bytes(&bytes),
+            Err(Error::InvalidPrivateKey { .. })
+        ));
+
+        let mut bytes = *root().to_bytes();
+        bytes[31] |= 0x80;
+        assert!(matches!(
+            CardanoExtendedPrivateKey::from_bytes(&bytes),
+            Err(Error::InvalidPrivateKey { .. })
+        ));
+
+        // y = 2 is not on the curve
+        let mut bytes = [0u8; 64];
+        bytes[0] = 2;
+        assert!(matches!(
+            CardanoExtendedPublicKey::from_bytes(&bytes),
+            Err(Error::InvalidPublicKey { .. })
+        ));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let mut key = root();
+        key.depth = CardanoExtendedPrivateKey::MAX_DEPTH;
+        assert_eq!(
+            key.derive_child(ChildNumber::Normal(0)),
+            Err(Error::MaxDepthExceeded { depth: 255 })
+        );
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let key = root();
+        let debug = format!("{key:?}");
+        assert!(debug.contains("REDACTED"));
+        assert!(!debug.contains(&format!("{:?}", &key.extended_secret()[..8])));
+    }
+}
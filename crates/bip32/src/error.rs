@@ -8,7 +8,8 @@
 //! The main [`enum@Error`] enum covers all possible failure modes:
 //! - **Seed errors**: Invalid seed length
 //! - **Key errors**: Invalid keys, zero keys, key overflow
-//! - **Derivation errors**: Invalid paths, hardened derivation from public keys
+//! - **Derivation errors**: Invalid paths, hardened derivation from public keys,
+//!   normal derivation on hardened-only curves
 //! - **Serialization errors**: Invalid format, checksum, version bytes
 //! - **Cryptographic errors**: Invalid curve points, HMAC failures
 //!
@@ -38,8 +39,9 @@ use thiserror::Error;
 ///
 /// - **Seed Validation**: [`InvalidSeedLength`]
 /// - **Key Validation**: [`InvalidPrivateKey`], [`InvalidPublicKey`], [`ZeroKey`], [`KeyOverflow`]
-/// - **Derivation**: [`InvalidDerivationPath`], [`InvalidChildNumber`], [`HardenedDerivationFromPublicKey`], [`MaxDepthExceeded`]
-/// - **Serialization**: [`InvalidExtendedKey`], [`InvalidChecksum`], [`InvalidVersionBytes`]
+/// - **Derivation**: [`InvalidDerivationPath`], [`InvalidChildNumber`], [`HardenedDerivationFromPublicKey`], [`HardenedDerivationRequired`], [`MaxDepthExceeded`]
+/// - **Serialization**: [`InvalidExtendedKey`], [`InvalidChecksum`], [`InvalidVersionBytes`], [`UnsupportedScriptType`]
+/// - **Network Registry**: [`DuplicateVersionBytes`]
 /// - **Cryptographic**: [`InvalidCurvePoint`], [`Secp256k1Error`]
 /// - **External Dependencies**: [`Bip39Error`]
 ///
@@ -51,10 +53,13 @@ use thiserror::Error;
 /// [`InvalidDerivationPath`]: Error::InvalidDerivationPath
 /// [`InvalidChildNumber`]: Error::InvalidChildNumber
 /// [`HardenedDerivationFromPublicKey`]: Error::HardenedDerivationFromPublicKey
+/// [`HardenedDerivationRequired`]: Error::HardenedDerivationRequired
 /// [`MaxDepthExceeded`]: Error::MaxDepthExceeded
 /// [`InvalidExtendedKey`]: Error::InvalidExtendedKey
 /// [`InvalidChecksum`]: Error::InvalidChecksum
 /// [`InvalidVersionBytes`]: Error::InvalidVersionBytes
+/// [`UnsupportedScriptType`]: Error::UnsupportedScriptType
+/// [`DuplicateVersionBytes`]: Error::DuplicateVersionBytes
 /// [`InvalidCurvePoint`]: Error::InvalidCurvePoint
 /// [`Secp256k1Error`]: Error::Secp256k1Error
 /// [`Bip39Error`]: Error::Bip39Error
@@ -166,6 +171,26 @@ pub enum Error {
         index: u32,
     },
 
+    /// Attempted a normal (non-hardened) derivation on a curve that only
+    /// supports hardened derivation.
+    ///
+    /// SLIP-10 defines only hardened derivation for ed25519, so every
+    /// component of an ed25519 path must be hardened.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip32::Error;
+    /// let error = Error::HardenedDerivationRequired { curve: "ed25519".to_string(), index: 0 };
+    /// println!("{}", error);
+    /// ```
+    #[error("Curve {curve} only supports hardened derivation, got normal index {index}")]
+    HardenedDerivationRequired {
+        /// The curve name
+        curve: String,
+        /// The normal index that was attempted (< 2^31)
+        index: u32,
+    },
+
     /// The derivation depth exceeds the maximum allowed depth.
     ///
     /// BIP32 uses a single byte for depth, limiting the maximum depth to 255.
@@ -204,6 +229,46 @@ pub enum Error {
         got: u32,
     },
 
+    /// The network has no SLIP-132 version bytes for the requested script type.
+    ///
+    /// SLIP-132 only registers ypub/zpub/Ypub/Zpub style prefixes for Bitcoin
+    /// mainnet and testnet; other networks can only be serialized as P2PKH.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip32::Error;
+    /// let error = Error::UnsupportedScriptType {
+    ///     script_type: "p2wpkh".to_string(),
+    ///     network: "Dogecoin Mainnet".to_string(),
+    /// };
+    /// println!("{}", error);
+    /// ```
+    #[error("Script type {script_type} has no SLIP-132 version bytes on {network}")]
+    UnsupportedScriptType {
+        /// The requested script type
+        script_type: String,
+        /// The network of the key being serialized
+        network: String,
+    },
+
+    /// A custom network's version bytes are already in use.
+    ///
+    /// Every registered network needs distinct private and public version
+    /// bytes that no other network uses, so that parsing an extended key
+    /// identifies exactly one network.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip32::Error;
+    /// let error = Error::DuplicateVersionBytes { version: 0x0488ADE4 };
+    /// println!("{}", error);
+    /// ```
+    #[error("Version bytes {version:#010x} are already used by another network")]
+    DuplicateVersionBytes {
+        /// The version bytes that clash
+        version: u32,
+    },
+
     /// The public key point is not on the secp256k1 curve.
     ///
     /// This is a critical cryptographic error that should not occur with
@@ -272,6 +337,16 @@ impl PartialEq for Error {
                 Error::HardenedDerivationFromPublicKey { index: i1 },
                 Error::HardenedDerivationFromPublicKey { index: i2 },
             ) => i1 == i2,
+            (
+                Error::HardenedDerivationRequired {
+                    curve: c1,
+                    index: i1,
+                },
+                Error::HardenedDerivationRequired {
+                    curve: c2,
+                    index: i2,
+                },
+            ) => c1 == c2 && i1 == i2,
             (Error::MaxDepthExceeded { depth: d1 }, Error::MaxDepthExceeded { depth: d2 }) => {
                 d1 == d2
             }
@@ -290,6 +365,20 @@ impl PartialEq for Error {
                     got: g2,
                 },
             ) => e1 == e2 && g1 == g2,
+            (
+                Error::UnsupportedScriptType {
+                    script_type: s1,
+                    network: n1,
+                },
+                Error::UnsupportedScriptType {
+                    script_type: s2,
+                    network: n2,
+                },
+            ) => s1 == s2 && n1 == n2,
+            (
+                Error::DuplicateVersionBytes { version: v1 },
+                Error::DuplicateVersionBytes { version: v2 },
+            ) => v1 == v2,
             (Error::InvalidCurvePoint, Error::InvalidCurvePoint) => true,
             (Error::Secp256k1Error { message: m1 }, Error::Secp256k1Error { message: m2 }) => {
                 m1 == m2
@@ -398,6 +487,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hardened_derivation_required_error() {
+        let error = Error::HardenedDerivationRequired {
+            curve: "ed25519".to_string(),
+            index: 1,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Curve ed25519 only supports hardened derivation, got normal index 1"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_script_type_error() {
+        let error = Error::UnsupportedScriptType {
+            script_type: "P2WPKH".to_string(),
+            network: "Litecoin Mainnet".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Script type P2WPKH has no SLIP-132 version bytes on Litecoin Mainnet"
+        );
+    }
+
     #[test]
     fn test_zero_key_error() {
         let error = Error::ZeroKey;
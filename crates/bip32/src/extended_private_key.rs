@@ -4,11 +4,13 @@
 //! with metadata necessary for hierarchical key derivation according to BIP-32.
 
 use crate::{
-    ChainCode, ChildNumber, Error, ExtendedPublicKey, Network, PrivateKey, PublicKey, Result,
+    slip132, ChainCode, ChildNumber, Error, ExtendedPublicKey, KeyType, Network, PrivateKey,
+    PublicKey, Result, ScriptType,
 };
 use hmac::{Hmac, Mac};
 use ripemd::Ripemd160;
 use sha2::{Digest, Sha256, Sha512};
+use zeroize::Zeroize;
 
 /// An extended private key for BIP32 hierarchical deterministic wallets.
 ///
@@ -190,16 +192,20 @@ impl ExtendedPrivateKey {
         let mut hmac = HmacSha512::new_from_slice(Self::MASTER_HMAC_KEY)
             .expect("HMAC can take key of any size");
         hmac.update(seed);
-        let result = hmac.finalize().into_bytes();
+        let mut result = hmac.finalize().into_bytes();
 
         // Split into IL (first 32 bytes) and IR (last 32 bytes)
         let (il, ir) = result.split_at(32);
 
         // IL becomes the private key
-        let private_key = PrivateKey::from_bytes(il)?;
+        let private_key = PrivateKey::from_bytes(il);
 
         // IR becomes the chain code
-        let chain_code = ChainCode::from_bytes(ir)?;
+        let chain_code = ChainCode::from_bytes(ir);
+
+        // Wipe the HMAC output before returning, including on error
+        result.as_mut_slice().zeroize();
+        let (private_key, chain_code) = (private_key?, chain_code?);
 
         Ok(ExtendedPrivateKey {
             network,
@@ -325,10 +331,35 @@ impl ExtendedPrivateKey {
     ) -> Result<Self> {
         // Convert mnemonic to seed using BIP39
         // passphrase.unwrap_or("") follows BIP39 spec: empty string if no passphrase
+        // The seed is zeroized when it goes out of scope
         let seed = mnemonic.to_seed(passphrase.unwrap_or(""))?;
 
-        // Use existing from_seed implementation
-        Self::from_seed(&seed, network)
+        Self::from_bip39_seed(&seed, network)
+    }
+
+    /// Creates a master extended private key from a BIP39 [`Seed`](khodpay_bip39::Seed).
+    ///
+    /// Equivalent to [`from_seed`](Self::from_seed) with the seed bytes, but
+    /// keeps the seed inside its zeroizing container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{ExtendedPrivateKey, Network};
+    /// use khodpay_bip39::{Language, Mnemonic};
+    ///
+    /// let mnemonic = Mnemonic::new(&[0u8; 16], Language::English)?;
+    /// let seed = mnemonic.to_seed("")?;
+    /// let master = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet)?;
+    ///
+    /// assert_eq!(
+    ///     master,
+    ///     ExtendedPrivateKey::from_mnemonic(&mnemonic, None, Network::BitcoinMainnet)?
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bip39_seed(seed: &khodpay_bip39::Seed, network: Network) -> Result<Self> {
+        Self::from_seed(seed.as_bytes(), network)
     }
 
     /// Returns the network this key belongs to.
@@ -708,11 +739,103 @@ impl std::fmt::Display for ExtendedPrivateKey {
     }
 }
 
+impl ExtendedPrivateKey {
+    /// Serializes the key with the SLIP-132 version bytes for `script_type`.
+    ///
+    /// [`ScriptType::P2pkh`] gives the same string as [`Display`](std::fmt::Display).
+    /// The other script types produce the yprv/zprv/Yprv/Zprv forms (uprv/vprv/Uprv/Vprv
+    /// on testnet) that wallets such as Electrum expect for BIP-49, BIP-84 and
+    /// BIP-48 accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `script_type` - The script type to record in the version bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedScriptType`] if SLIP-132 defines no version
+    /// bytes for `script_type` on this key's network.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{DerivationPath, ExtendedPrivateKey, Network, ScriptType};
+    /// use std::str::FromStr;
+    ///
+    /// let seed = [0x42u8; 64];
+    /// let master = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet)?;
+    /// let account = master.derive_path(&DerivationPath::from_str("m/84'/0'/0'")?)?;
+    ///
+    /// let zprv = account.to_slip132_string(ScriptType::P2wpkh)?;
+    /// assert!(zprv.starts_with("zprv"));
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn to_slip132_string(&self, script_type: ScriptType) -> Result<String> {
+        let version = script_type
+            .version_bytes(self.network, KeyType::Private)
+            .ok_or_else(|| Error::UnsupportedScriptType {
+                script_type: script_type.to_string(),
+                network: self.network.to_string(),
+            })?;
+        Ok(self.encode(version))
+    }
+
+    /// Serializes the key like [`Display`](std::fmt::Display), but with the
+    /// given version bytes.
+    fn encode(&self, version: u32) -> String {
+        slip132::with_version(&self.to_string(), version)
+    }
+
+    /// Deserializes an extended private key and infers its SLIP-132 script type.
+    ///
+    /// Accepts plain BIP-32 strings of any built-in or registered network
+    /// (reported as [`ScriptType::P2pkh`]) as well as the SLIP-132 yprv/zprv/Yprv/Zprv
+    /// forms and their testnet counterparts.
+    /// Converting between encodings is a parse followed by
+    /// [`to_slip132_string`](Self::to_slip132_string).
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A Base58Check encoded extended private key
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidExtendedKey`] if the input is not valid Base58,
+    /// has the wrong length or checksum, uses unknown version bytes, or
+    /// contains invalid key data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{ExtendedPrivateKey, Network, ScriptType};
+    ///
+    /// let zprv = "zprvAdG4iTXWBoARxkkzNpNh8r6Qag3irQB8PzEMkAFeTRXxHpbF9z4QgEvBRmfvqWvGp42t42nvgGpNgYSJA9iefm1yYNZKEm7z6qUWCroSQnE";
+    /// let (account, script_type) = ExtendedPrivateKey::from_slip132_str(zprv)?;
+    /// assert_eq!(account.network(), Network::BitcoinMainnet);
+    /// assert_eq!(script_type, ScriptType::P2wpkh);
+    ///
+    /// // Convert to the plain xprv form
+    /// assert!(account.to_slip132_string(ScriptType::P2pkh)?.starts_with("xprv"));
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn from_slip132_str(s: &str) -> Result<(Self, ScriptType)> {
+        let key: Self = s.parse()?;
+        // Parsing succeeded, so the version bytes are known
+        let (_, script_type) = slip132::version_of(s)
+            .and_then(|version| ScriptType::from_version_bytes(version, KeyType::Private))
+            .expect("parsed extended key has known version bytes");
+        Ok((key, script_type))
+    }
+}
+
 impl std::str::FromStr for ExtendedPrivateKey {
     type Err = Error;
 
     /// Deserializes an extended private key from Base58Check encoding (xprv/tprv format).
     ///
+    /// SLIP-132 forms such as `zprv` are accepted too; use
+    /// [`from_slip132_str`](ExtendedPrivateKey::from_slip132_str) to also learn the script type.
+    ///
     /// # Arguments
     ///
     /// * `s` - A Base58Check encoded extended private key string (xprv... or tprv...)
@@ -769,9 +892,11 @@ impl std::str::FromStr for ExtendedPrivateKey {
 
         // 4. Parse version bytes to determine network
         let version = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
-        let network =
-            Network::from_xprv_version(version).ok_or_else(|| Error::InvalidExtendedKey {
-                reason: format!("Unknown xprv version bytes: 0x{:08X}", version),
+        let (network, _) =
+            ScriptType::from_version_bytes(version, KeyType::Private).ok_or_else(|| {
+                Error::InvalidExtendedKey {
+                    reason: format!("Unknown xprv version bytes: 0x{:08X}", version),
+                }
             })?;
 
         // 5. Parse depth
@@ -2481,6 +2606,45 @@ mod tests {
         assert_eq!(original, deserialized);
     }
 
+    #[test]
+    fn test_altcoin_serialization_roundtrip() {
+        let seed = [0x07; 32];
+        let prefixes = [
+            (Network::LitecoinMainnet, "Ltpv", "Ltub"),
+            (Network::LitecoinTestnet, "ttpv", "ttub"),
+            (Network::DogecoinMainnet, "dgpv", "dgub"),
+            (Network::DogecoinTestnet, "tgpv", "tgub"),
+        ];
+        for (network, xprv_prefix, xpub_prefix) in prefixes {
+            let key = ExtendedPrivateKey::from_seed(&seed, network).unwrap();
+            let xprv = key.to_string();
+            let xpub = key.to_extended_public_key().to_string();
+            assert!(xprv.starts_with(xprv_prefix), "{xprv}");
+            assert!(xpub.starts_with(xpub_prefix), "{xpub}");
+
+            let parsed = ExtendedPrivateKey::from_str(&xprv).unwrap();
+            assert_eq!(parsed.network(), network);
+            assert_eq!(parsed, key);
+            assert_eq!(
+                ExtendedPublicKey::from_str(&xpub).unwrap().network(),
+                network
+            );
+        }
+    }
+
+    #[test]
+    fn test_registered_network_serialization_roundtrip() {
+        let custom = crate::CustomNetwork::new("Roundtrip Coin", 0x7E57AA01, 0x7E57AA02);
+        let key = ExtendedPrivateKey::from_seed(&[0x09; 32], Network::Custom(custom)).unwrap();
+        let xprv = key.to_string();
+        assert!(ExtendedPrivateKey::from_str(&xprv).is_err());
+
+        let network = Network::register(custom).unwrap();
+        let parsed = ExtendedPrivateKey::from_str(&xprv).unwrap();
+        assert_eq!(parsed.network(), network);
+        assert_eq!(parsed, key);
+    }
+
     #[test]
     fn test_from_mnemonic_watch_only_export() {
         let mnemonic = khodpay_bip39::Mnemonic::from_phrase(
@@ -2558,6 +2722,42 @@ mod tests {
         assert_eq!(receiving.child_number(), ChildNumber::Normal(0));
     }
 
+    #[test]
+    fn test_electrum_seed_root_keys() {
+        // Electrum standard seed: the wallet key is the master key itself
+        let mnemonic = khodpay_bip39::ElectrumMnemonic::from_phrase(
+            "cycle rocket west magnet parrot shuffle foot correct salt library feed song",
+        )
+        .unwrap();
+        let seed = mnemonic.to_seed("").unwrap();
+        let master = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
+
+        let root_paths = mnemonic.seed_type().root_paths();
+        assert_eq!(root_paths, ["m"]);
+        let root = master
+            .derive_path(&DerivationPath::from_str(root_paths[0]).unwrap())
+            .unwrap();
+        assert_eq!(
+            root.to_extended_public_key().to_string(),
+            "xpub661MyMwAqRbcFWohJWt7PHsFEJfZAvw9ZxwQoDa4SoMgsDDM1T7WK3u9E4edkC4ugRnZ8E4xDZRpk8Rnts3Nbt97dPwT52CwBdDWroaZf8U"
+        );
+
+        // Electrum 2FA seed: two user keys at m/0' and m/1'
+        let mnemonic = khodpay_bip39::ElectrumMnemonic::from_phrase(
+            "science dawn member doll dutch real can brick knife deny drive list",
+        )
+        .unwrap();
+        let seed = mnemonic.to_seed("").unwrap();
+        let master = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
+        for path in mnemonic.seed_type().root_paths() {
+            let key = master
+                .derive_path(&DerivationPath::from_str(path).unwrap())
+                .unwrap();
+            assert_eq!(key.depth(), 1);
+            assert!(key.child_number().is_hardened());
+        }
+    }
+
     #[test]
     fn test_max_depth_constant() {
         // Verify MAX_DEPTH is 255 (BIP-32 spec)
@@ -2860,4 +3060,131 @@ mod tests {
         // Attempting to derive would need depth 256, which exceeds u8
         assert!(key_at_255.derive_child(ChildNumber::Normal(0)).is_err());
     }
+
+    fn abandon_master() -> ExtendedPrivateKey {
+        let mnemonic = khodpay_bip39::Mnemonic::from_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            khodpay_bip39::Language::English,
+        )
+        .unwrap();
+        ExtendedPrivateKey::from_mnemonic(&mnemonic, None, Network::BitcoinMainnet).unwrap()
+    }
+
+    #[test]
+    fn test_slip132_bip84_and_bip49_vectors() {
+        let master = abandon_master();
+
+        // BIP-84 test vector for account 0
+        let bip84 = master
+            .derive_path(&DerivationPath::from_str("m/84'/0'/0'").unwrap())
+            .unwrap();
+        assert_eq!(
+            bip84.to_slip132_string(ScriptType::P2wpkh).unwrap(),
+            "zprvAdG4iTXWBoARxkkzNpNh8r6Qag3irQB8PzEMkAFeTRXxHpbF9z4QgEvBRmfvqWvGp42t42nvgGpNgYSJA9iefm1yYNZKEm7z6qUWCroSQnE"
+        );
+        assert_eq!(
+            bip84
+                .to_extended_public_key()
+                .to_slip132_string(ScriptType::P2wpkh)
+                .unwrap(),
+            "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs"
+        );
+
+        // BIP-49 test vector for account 0
+        let bip49 = master
+            .derive_path(&DerivationPath::from_str("m/49'/0'/0'").unwrap())
+            .unwrap();
+        assert_eq!(
+            bip49.to_slip132_string(ScriptType::P2shP2wpkh).unwrap(),
+            "yprvAHwhK6RbpuS3dgCYHM5jc2ZvEKd7Bi61u9FVhYMpgMSuZS613T1xxQeKTffhrHY79hZ5PsskBjcc6C2V7DrnsMsNaGDaWev3GLRQRgV7hxF"
+        );
+        assert_eq!(
+            bip49
+                .to_extended_public_key()
+                .to_slip132_string(ScriptType::P2shP2wpkh)
+                .unwrap(),
+            "ypub6Ww3ibxVfGzLrAH1PNcjyAWenMTbbAosGNB6VvmSEgytSER9azLDWCxoJwW7Ke7icmizBMXrzBx9979FfaHxHcrArf3zbeJJJUZPf663zsP"
+        );
+    }
+
+    #[test]
+    fn test_slip132_bip48_multisig() {
+        let master = abandon_master();
+        let account = master
+            .derive_path(&DerivationPath::from_str("m/48'/0'/0'/2'").unwrap())
+            .unwrap();
+        let zprv = account.to_slip132_string(ScriptType::P2wsh).unwrap();
+        assert_eq!(
+            zprv,
+            "ZprvAqKWVb3n4aNzjiPjQDPNj5k87E38kbkFCCxoHKjDkUpdKEpq1jbo6ssDZS4WgM43mLCQ6Gx6LM4DD5sigBvnjEPqouRuC7HbNeVagXvJcnz"
+        );
+        assert_eq!(
+            account
+                .to_extended_public_key()
+                .to_slip132_string(ScriptType::P2wsh)
+                .unwrap(),
+            "Zpub74Jru6aftwwHxCUCWEvP6DgrfFsdA4U6ZRtQ5i8qJpMcC39yZGv3egBhQfV3MS9pZtH5z8iV5qWkJsK6ESs6mSzt4qvGhzJxPeeVS2e1zUG"
+        );
+
+        let (parsed, script_type) = ExtendedPrivateKey::from_slip132_str(&zprv).unwrap();
+        assert_eq!(script_type, ScriptType::P2wsh);
+        assert_eq!(parsed, account);
+    }
+
+    #[test]
+    fn test_slip132_testnet_roundtrip() {
+        let key = ExtendedPrivateKey::from_seed(&[0x0A; 32], Network::BitcoinTestnet).unwrap();
+        let prefixes = [
+            (ScriptType::P2pkh, "tprv"),
+            (ScriptType::P2shP2wpkh, "uprv"),
+            (ScriptType::P2wpkh, "vprv"),
+            (ScriptType::P2shP2wsh, "Uprv"),
+            (ScriptType::P2wsh, "Vprv"),
+        ];
+        for (script_type, prefix) in prefixes {
+            let encoded = key.to_slip132_string(script_type).unwrap();
+            assert!(encoded.starts_with(prefix), "{encoded}");
+
+            let (parsed, parsed_type) = ExtendedPrivateKey::from_slip132_str(&encoded).unwrap();
+            assert_eq!(parsed, key);
+            assert_eq!(parsed_type, script_type);
+            assert_eq!(ExtendedPrivateKey::from_str(&encoded).unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn test_slip132_plain_key_is_p2pkh() {
+        let key = abandon_master();
+        assert_eq!(
+            key.to_slip132_string(ScriptType::P2pkh).unwrap(),
+            key.to_string()
+        );
+        let (_, script_type) = ExtendedPrivateKey::from_slip132_str(&key.to_string()).unwrap();
+        assert_eq!(script_type, ScriptType::P2pkh);
+    }
+
+    #[test]
+    fn test_slip132_unsupported_network() {
+        let key = ExtendedPrivateKey::from_seed(&[0x0B; 32], Network::LitecoinMainnet).unwrap();
+        assert_eq!(
+            key.to_slip132_string(ScriptType::P2wpkh),
+            Err(Error::UnsupportedScriptType {
+                script_type: "P2WPKH".to_string(),
+                network: "Litecoin Mainnet".to_string(),
+            })
+        );
+        assert!(key
+            .to_slip132_string(ScriptType::P2pkh)
+            .unwrap()
+            .starts_with("Ltpv"));
+    }
+
+    #[test]
+    fn test_slip132_public_version_rejected() {
+        let zpub = abandon_master()
+            .to_extended_public_key()
+            .to_slip132_string(ScriptType::P2wpkh)
+            .unwrap();
+        assert!(ExtendedPrivateKey::from_slip132_str(&zpub).is_err());
+    }
 }
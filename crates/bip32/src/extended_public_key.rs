@@ -3,7 +3,9 @@
 //! This module provides the ExtendedPublicKey type which combines a public key
 //! with metadata necessary for hierarchical key derivation according to BIP-32.
 
-use crate::{ChainCode, ChildNumber, Error, Network, PublicKey, Result};
+use crate::{
+    slip132, ChainCode, ChildNumber, Error, KeyType, Network, PublicKey, Result, ScriptType,
+};
 use hmac::{Hmac, Mac};
 use ripemd::Ripemd160;
 use sha2::{Digest, Sha256, Sha512};
@@ -472,11 +474,105 @@ impl std::fmt::Display for ExtendedPublicKey {
     }
 }
 
+impl ExtendedPublicKey {
+    /// Serializes the key with the SLIP-132 version bytes for `script_type`.
+    ///
+    /// [`ScriptType::P2pkh`] gives the same string as [`Display`](std::fmt::Display).
+    /// The other script types produce the ypub/zpub/Ypub/Zpub forms (upub/vpub/Upub/Vpub
+    /// on testnet) that wallets such as Electrum expect for BIP-49, BIP-84 and
+    /// BIP-48 accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `script_type` - The script type to record in the version bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedScriptType`] if SLIP-132 defines no version
+    /// bytes for `script_type` on this key's network.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{DerivationPath, ExtendedPrivateKey, Network, ScriptType};
+    /// use std::str::FromStr;
+    ///
+    /// let seed = [0x42u8; 64];
+    /// let master = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet)?;
+    /// let account = master.derive_path(&DerivationPath::from_str("m/84'/0'/0'")?)?;
+    /// let account_xpub = account.to_extended_public_key();
+    ///
+    /// let zpub = account_xpub.to_slip132_string(ScriptType::P2wpkh)?;
+    /// assert!(zpub.starts_with("zpub"));
+    /// assert_eq!(account_xpub.to_slip132_string(ScriptType::P2pkh)?, account_xpub.to_string());
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn to_slip132_string(&self, script_type: ScriptType) -> Result<String> {
+        let version = script_type
+            .version_bytes(self.network, KeyType::Public)
+            .ok_or_else(|| Error::UnsupportedScriptType {
+                script_type: script_type.to_string(),
+                network: self.network.to_string(),
+            })?;
+        Ok(self.encode(version))
+    }
+
+    /// Serializes the key like [`Display`](std::fmt::Display), but with the
+    /// given version bytes.
+    fn encode(&self, version: u32) -> String {
+        slip132::with_version(&self.to_string(), version)
+    }
+
+    /// Deserializes an extended public key and infers its SLIP-132 script type.
+    ///
+    /// Accepts plain BIP-32 strings of any built-in or registered network
+    /// (reported as [`ScriptType::P2pkh`]) as well as the SLIP-132 ypub/zpub/Ypub/Zpub
+    /// forms and their testnet counterparts.
+    /// Converting between encodings is a parse followed by
+    /// [`to_slip132_string`](Self::to_slip132_string).
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A Base58Check encoded extended public key
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidExtendedKey`] if the input is not valid Base58,
+    /// has the wrong length or checksum, uses unknown version bytes, or
+    /// contains invalid key data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{ExtendedPublicKey, Network, ScriptType};
+    ///
+    /// let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+    /// let (account, script_type) = ExtendedPublicKey::from_slip132_str(zpub)?;
+    /// assert_eq!(account.network(), Network::BitcoinMainnet);
+    /// assert_eq!(script_type, ScriptType::P2wpkh);
+    ///
+    /// // Convert to the plain xpub form
+    /// assert!(account.to_slip132_string(ScriptType::P2pkh)?.starts_with("xpub"));
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn from_slip132_str(s: &str) -> Result<(Self, ScriptType)> {
+        let key: Self = s.parse()?;
+        // Parsing succeeded, so the version bytes are known
+        let (_, script_type) = slip132::version_of(s)
+            .and_then(|version| ScriptType::from_version_bytes(version, KeyType::Public))
+            .expect("parsed extended key has known version bytes");
+        Ok((key, script_type))
+    }
+}
+
 impl std::str::FromStr for ExtendedPublicKey {
     type Err = Error;
 
     /// Deserializes an extended public key from Base58Check encoding (xpub/tpub format).
     ///
+    /// SLIP-132 forms such as `zpub` are accepted too; use
+    /// [`from_slip132_str`](ExtendedPublicKey::from_slip132_str) to also learn the script type.
+    ///
     /// Parses a Base58Check-encoded string and validates:
     /// - Base58 decoding succeeds
     /// - Length is exactly 82 bytes (78 data + 4 checksum)
@@ -546,9 +642,11 @@ impl std::str::FromStr for ExtendedPublicKey {
 
         // 4. Parse version bytes to determine network
         let version = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
-        let network =
-            Network::from_xpub_version(version).ok_or_else(|| Error::InvalidExtendedKey {
-                reason: format!("Unknown xpub version bytes: 0x{:08X}", version),
+        let (network, _) =
+            ScriptType::from_version_bytes(version, KeyType::Public).ok_or_else(|| {
+                Error::InvalidExtendedKey {
+                    reason: format!("Unknown xpub version bytes: 0x{:08X}", version),
+                }
             })?;
 
         // 5. Parse depth
@@ -1752,4 +1850,111 @@ mod tests {
         assert_eq!(address_pub.depth(), 4);
         assert_eq!(address_pub.child_number(), ChildNumber::Normal(0));
     }
+
+    // BIP-84 account 0 for the "abandon ... about" mnemonic
+    const BIP84_ZPUB: &str = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+    const BIP84_XPUB: &str = "xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V";
+
+    #[test]
+    fn test_from_slip132_str_infers_script_type() {
+        let (key, script_type) = ExtendedPublicKey::from_slip132_str(BIP84_ZPUB).unwrap();
+        assert_eq!(script_type, ScriptType::P2wpkh);
+        assert_eq!(key.network(), Network::BitcoinMainnet);
+        assert_eq!(key.depth(), 3);
+        assert_eq!(key.child_number(), ChildNumber::Hardened(0));
+
+        let (_, script_type) = ExtendedPublicKey::from_slip132_str(BIP84_XPUB).unwrap();
+        assert_eq!(script_type, ScriptType::P2pkh);
+    }
+
+    #[test]
+    fn test_from_str_accepts_slip132() {
+        let from_zpub = ExtendedPublicKey::from_str(BIP84_ZPUB).unwrap();
+        let from_xpub = ExtendedPublicKey::from_str(BIP84_XPUB).unwrap();
+        assert_eq!(from_zpub, from_xpub);
+        // Display always uses the network's plain version bytes
+        assert_eq!(from_zpub.to_string(), BIP84_XPUB);
+    }
+
+    #[test]
+    fn test_slip132_conversion() {
+        let (key, _) = ExtendedPublicKey::from_slip132_str(BIP84_ZPUB).unwrap();
+        assert_eq!(
+            key.to_slip132_string(ScriptType::P2pkh).unwrap(),
+            BIP84_XPUB
+        );
+        assert_eq!(
+            key.to_slip132_string(ScriptType::P2wpkh).unwrap(),
+            BIP84_ZPUB
+        );
+
+        for script_type in ScriptType::ALL {
+            let encoded = key.to_slip132_string(script_type).unwrap();
+            let (parsed, parsed_type) = ExtendedPublicKey::from_slip132_str(&encoded).unwrap();
+            assert_eq!(parsed, key);
+            assert_eq!(parsed_type, script_type);
+        }
+    }
+
+    #[test]
+    fn test_slip132_testnet_prefixes() {
+        let seed = [0x0C; 32];
+        let master_priv = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinTestnet).unwrap();
+        let key = master_priv.to_extended_public_key();
+        let prefixes = [
+            (ScriptType::P2pkh, "tpub"),
+            (ScriptType::P2shP2wpkh, "upub"),
+            (ScriptType::P2wpkh, "vpub"),
+            (ScriptType::P2shP2wsh, "Upub"),
+            (ScriptType::P2wsh, "Vpub"),
+        ];
+        for (script_type, prefix) in prefixes {
+            let encoded = key.to_slip132_string(script_type).unwrap();
+            assert!(encoded.starts_with(prefix), "{encoded}");
+            assert_eq!(
+                ExtendedPublicKey::from_slip132_str(&encoded).unwrap(),
+                (key.clone(), script_type)
+            );
+        }
+    }
+
+    #[test]
+    fn test_slip132_litecoin_mtub() {
+        let seed = [0x0E; 32];
+        let master_priv = ExtendedPrivateKey::from_seed(&seed, Network::LitecoinMainnet).unwrap();
+        let key = master_priv.to_extended_public_key();
+
+        let mtub = key.to_slip132_string(ScriptType::P2shP2wpkh).unwrap();
+        assert!(mtub.starts_with("Mtub"), "{mtub}");
+        assert_eq!(
+            ExtendedPublicKey::from_slip132_str(&mtub).unwrap(),
+            (key.clone(), ScriptType::P2shP2wpkh)
+        );
+
+        let mtpv = master_priv
+            .to_slip132_string(ScriptType::P2shP2wpkh)
+            .unwrap();
+        assert!(mtpv.starts_with("Mtpv"), "{mtpv}");
+        assert_eq!(
+            ExtendedPrivateKey::from_slip132_str(&mtpv).unwrap(),
+            (master_priv, ScriptType::P2shP2wpkh)
+        );
+
+        // SLIP-132 registers no native SegWit version bytes for Litecoin
+        assert!(key.to_slip132_string(ScriptType::P2wpkh).is_err());
+    }
+
+    #[test]
+    fn test_slip132_unsupported_network() {
+        let seed = [0x0D; 32];
+        let master_priv = ExtendedPrivateKey::from_seed(&seed, Network::DogecoinMainnet).unwrap();
+        let key = master_priv.to_extended_public_key();
+        assert_eq!(
+            key.to_slip132_string(ScriptType::P2shP2wsh),
+            Err(Error::UnsupportedScriptType {
+                script_type: "P2SH-P2WSH".to_string(),
+                network: "Dogecoin Mainnet".to_string(),
+            })
+        );
+    }
 }
@@ -15,7 +15,8 @@
 //! - **Type-Safe API** - Leverages Rust's type system for safety
 //! - **BIP39 Integration** - Seamlessly works with BIP39 mnemonics
 //! - **Hardened & Normal Derivation** - Supports both derivation types
-//! - **Network Support** - Bitcoin mainnet and testnet
+//! - **Network Support** - Bitcoin, Litecoin and Dogecoin, plus runtime-registered networks
+//! - **SLIP-132 Encodings** - ypub/zpub/Ypub/Zpub export and parsing with script type detection
 //! - **Zero Unsafe Code** - Pure safe Rust implementation
 //! - **Production Ready** - Validated against official test vectors
 //! - **Cross-Compatible** - Interoperable with major wallet implementations
@@ -83,6 +84,67 @@
 //! # Ok::<(), khodpay_bip32::Error>(())
 //! ```
 //!
+//! ### SLIP-10 Ed25519 and NIST P-256 Keys
+//!
+//! [`Slip10ExtendedPrivateKey`] derives keys on other curves with the same
+//! [`DerivationPath`] type. Ed25519 (Solana, Stellar, Aptos) only supports
+//! hardened derivation; [`NistP256`] (WebAuthn, HSMs) also supports normal
+//! and public derivation through [`Slip10ExtendedPublicKey`].
+//!
+//! ```rust
+//! use khodpay_bip32::{DerivationPath, Ed25519, Slip10ExtendedPrivateKey};
+//! use std::str::FromStr;
+//!
+//! # let seed = b"your-secure-seed-bytes-here-at-least-16-bytes-long";
+//! let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(seed)?;
+//! let account = master.derive_path(&DerivationPath::from_str("m/44'/501'/0'/0'")?)?;
+//! let solana_public_key = &account.public_key()[1..];
+//! # assert_eq!(solana_public_key.len(), 32);
+//! # Ok::<(), khodpay_bip32::Error>(())
+//! ```
+//!
+//! ### Cardano (BIP32-Ed25519)
+//!
+//! [`CardanoExtendedPrivateKey`] implements the Icarus master key and
+//! BIP32-Ed25519 derivation used by Cardano wallets, including public
+//! derivation of payment keys through [`CardanoExtendedPublicKey`].
+//!
+//! ```rust
+//! use khodpay_bip32::{CardanoExtendedPrivateKey, DerivationPath};
+//! use khodpay_bip39::{Language, Mnemonic};
+//! use std::str::FromStr;
+//!
+//! # let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English)?;
+//! let root = CardanoExtendedPrivateKey::from_mnemonic(&mnemonic, "");
+//! let account = root.derive_path(&DerivationPath::from_str("m/1852'/1815'/0'")?)?;
+//! let account_xpub = account.to_extended_public_key().to_bytes();
+//! # assert_eq!(account_xpub.len(), 64);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### SLIP-132 (ypub/zpub) Export
+//!
+//! Wallets such as Electrum and BlueWallet expect SegWit and multisig account
+//! keys in their [`ScriptType`]-specific SLIP-132 form. Parsing infers the
+//! script type, and converting is a parse followed by a new encoding.
+//!
+//! ```rust
+//! use khodpay_bip32::{DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, Network, ScriptType};
+//! use std::str::FromStr;
+//!
+//! # let seed = b"your-secure-seed-bytes-here-at-least-16-bytes-long";
+//! let master = ExtendedPrivateKey::from_seed(seed, Network::BitcoinMainnet)?;
+//! let account = master.derive_path(&DerivationPath::from_str("m/84'/0'/0'")?)?;
+//! let zpub = account.to_extended_public_key().to_slip132_string(ScriptType::P2wpkh)?;
+//!
+//! let (imported, script_type) = ExtendedPublicKey::from_slip132_str(&zpub)?;
+//! assert_eq!(script_type, ScriptType::P2wpkh);
+//! let xpub = imported.to_slip132_string(ScriptType::P2pkh)?;
+//! # assert!(xpub.starts_with("xpub"));
+//! # Ok::<(), khodpay_bip32::Error>(())
+//! ```
+//!
 //! ## Common Derivation Paths
 //!
 //! - **BIP44** - `m/44'/0'/0'` - Multi-account hierarchy for Bitcoin
@@ -105,6 +167,7 @@
 //! - All BIP32/44/49/84 compliant implementations
 
 // Module declarations
+mod cardano;
 mod chain_code;
 mod child_number;
 mod derivation_path;
@@ -114,6 +177,8 @@ mod extended_public_key;
 mod network;
 mod private_key;
 mod public_key;
+mod slip10;
+mod slip132;
 
 /// Utility functions and convenience methods for common BIP32 operations.
 ///
@@ -122,12 +187,17 @@ mod public_key;
 pub mod utils;
 
 // Public re-exports
+pub use cardano::{CardanoExtendedPrivateKey, CardanoExtendedPublicKey};
 pub use chain_code::ChainCode;
 pub use child_number::ChildNumber;
 pub use derivation_path::DerivationPath;
 pub use error::{Error, Result};
 pub use extended_private_key::ExtendedPrivateKey;
 pub use extended_public_key::ExtendedPublicKey;
-pub use network::{KeyType, Network};
+pub use network::{CustomNetwork, KeyType, Network};
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
+pub use slip10::{
+    Curve, Ed25519, NistP256, Secp256k1, Slip10ExtendedPrivateKey, Slip10ExtendedPublicKey,
+};
+pub use slip132::ScriptType;
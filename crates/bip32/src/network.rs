@@ -4,6 +4,10 @@
 //! version bytes. Different networks use different version byte prefixes when
 //! serializing extended keys to Base58Check format.
 //!
+//! Bitcoin, Litecoin and Dogecoin are built in. Other coins can be added at
+//! runtime with [`Network::register`], after which extended keys with their
+//! version bytes parse like any built-in network.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -14,6 +18,12 @@
 //! assert_eq!(mainnet.version_bytes(KeyType::Public), 0x0488B21E);
 //! ```
 
+use crate::{Error, Result};
+use std::sync::{PoisonError, RwLock};
+
+/// Networks registered at runtime with [`Network::register`].
+static REGISTRY: RwLock<Vec<CustomNetwork>> = RwLock::new(Vec::new());
+
 /// Key type identifier for extended keys.
 ///
 /// BIP32 defines two types of extended keys:
@@ -114,6 +124,13 @@ impl std::fmt::Display for KeyType {
 ///
 /// - `xprv`/`xpub` - Bitcoin Mainnet
 /// - `tprv`/`tpub` - Bitcoin Testnet
+/// - `Ltpv`/`Ltub` - Litecoin Mainnet
+/// - `ttpv`/`ttub` - Litecoin Testnet
+/// - `dgpv`/`dgub` - Dogecoin Mainnet
+/// - `tgpv`/`tgub` - Dogecoin Testnet
+///
+/// Any other version-byte pair can be used through [`Network::Custom`], and
+/// registered with [`Network::register`] so that parsing recognizes it.
 ///
 /// # Examples
 ///
@@ -129,6 +146,7 @@ impl std::fmt::Display for KeyType {
 /// println!("Testnet tprv: {:#x}", testnet.xprv_version());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Network {
     /// Bitcoin mainnet.
     ///
@@ -145,9 +163,108 @@ pub enum Network {
     /// - Private version: `0x04358394`
     /// - Public version: `0x043587CF`
     BitcoinTestnet,
+
+    /// Litecoin mainnet.
+    ///
+    /// Extended keys serialize with `Ltpv` (private) and `Ltub` (public) prefixes.
+    ///
+    /// - Private version: `0x019D9CFE`
+    /// - Public version: `0x019DA462`
+    LitecoinMainnet,
+
+    /// Litecoin testnet.
+    ///
+    /// Extended keys serialize with `ttpv` (private) and `ttub` (public) prefixes.
+    ///
+    /// - Private version: `0x0436EF7D`
+    /// - Public version: `0x0436F6E1`
+    LitecoinTestnet,
+
+    /// Dogecoin mainnet.
+    ///
+    /// Extended keys serialize with `dgpv` (private) and `dgub` (public) prefixes.
+    ///
+    /// - Private version: `0x02FAC398`
+    /// - Public version: `0x02FACAFD`
+    DogecoinMainnet,
+
+    /// Dogecoin testnet.
+    ///
+    /// Extended keys serialize with `tgpv` (private) and `tgub` (public) prefixes.
+    ///
+    /// - Private version: `0x0432A243`
+    /// - Public version: `0x0432A9A8`
+    DogecoinTestnet,
+
+    /// A network with caller-supplied version bytes.
+    ///
+    /// Keys on a custom network always serialize with its version bytes;
+    /// parsing recognizes them once the network is registered with
+    /// [`Network::register`].
+    Custom(CustomNetwork),
+}
+
+/// Name and version bytes of a network that is not built in.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip32::{CustomNetwork, Network};
+///
+/// let network = CustomNetwork::new("Example Coin", 0x0A0B0C0D, 0x0A0B0C0E);
+/// assert_eq!(Network::Custom(network).xprv_version(), 0x0A0B0C0D);
+/// assert_eq!(Network::Custom(network).name(), "Example Coin");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomNetwork {
+    name: &'static str,
+    xprv_version: u32,
+    xpub_version: u32,
+}
+
+impl CustomNetwork {
+    /// Creates a network description from its name and version bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Human-readable name, returned by [`Network::name`]
+    /// * `xprv_version` - Version bytes of extended private keys
+    /// * `xpub_version` - Version bytes of extended public keys
+    pub const fn new(name: &'static str, xprv_version: u32, xpub_version: u32) -> Self {
+        CustomNetwork {
+            name,
+            xprv_version,
+            xpub_version,
+        }
+    }
+
+    /// Returns the human-readable name of the network.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the version bytes for extended private keys.
+    pub fn xprv_version(&self) -> u32 {
+        self.xprv_version
+    }
+
+    /// Returns the version bytes for extended public keys.
+    pub fn xpub_version(&self) -> u32 {
+        self.xpub_version
+    }
 }
 
 impl Network {
+    /// The built-in networks.
+    pub const BUILT_IN: [Network; 6] = [
+        Network::BitcoinMainnet,
+        Network::BitcoinTestnet,
+        Network::LitecoinMainnet,
+        Network::LitecoinTestnet,
+        Network::DogecoinMainnet,
+        Network::DogecoinTestnet,
+    ];
+
     /// Returns the version bytes for the specified key type.
     ///
     /// This is the primary method for getting version bytes, combining network
@@ -182,6 +299,7 @@ impl Network {
     ///
     /// - `0x0488ADE4` for Bitcoin Mainnet (xprv)
     /// - `0x04358394` for Bitcoin Testnet (tprv)
+    /// - The documented version of each other network
     ///
     /// # Examples
     ///
@@ -195,6 +313,11 @@ impl Network {
         match self {
             Network::BitcoinMainnet => 0x0488ADE4,
             Network::BitcoinTestnet => 0x04358394,
+            Network::LitecoinMainnet => 0x019D9CFE,
+            Network::LitecoinTestnet => 0x0436EF7D,
+            Network::DogecoinMainnet => 0x02FAC398,
+            Network::DogecoinTestnet => 0x0432A243,
+            Network::Custom(network) => network.xprv_version,
         }
     }
 
@@ -207,6 +330,7 @@ impl Network {
     ///
     /// - `0x0488B21E` for Bitcoin Mainnet (xpub)
     /// - `0x043587CF` for Bitcoin Testnet (tpub)
+    /// - The documented version of each other network
     ///
     /// # Examples
     ///
@@ -220,6 +344,11 @@ impl Network {
         match self {
             Network::BitcoinMainnet => 0x0488B21E,
             Network::BitcoinTestnet => 0x043587CF,
+            Network::LitecoinMainnet => 0x019DA462,
+            Network::LitecoinTestnet => 0x0436F6E1,
+            Network::DogecoinMainnet => 0x02FACAFD,
+            Network::DogecoinTestnet => 0x0432A9A8,
+            Network::Custom(network) => network.xpub_version,
         }
     }
 
@@ -237,14 +366,102 @@ impl Network {
         match self {
             Network::BitcoinMainnet => "Bitcoin Mainnet",
             Network::BitcoinTestnet => "Bitcoin Testnet",
+            Network::LitecoinMainnet => "Litecoin Mainnet",
+            Network::LitecoinTestnet => "Litecoin Testnet",
+            Network::DogecoinMainnet => "Dogecoin Mainnet",
+            Network::DogecoinTestnet => "Dogecoin Testnet",
+            Network::Custom(network) => network.name,
+        }
+    }
+
+    /// Registers a custom network so that its version bytes are recognized
+    /// by [`from_xprv_version`](Self::from_xprv_version),
+    /// [`from_xpub_version`](Self::from_xpub_version) and extended key parsing.
+    ///
+    /// The registry is process-wide. Registering the same network again is
+    /// a no-op.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - The name and version bytes to register
+    ///
+    /// # Returns
+    ///
+    /// The registered network as [`Network::Custom`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DuplicateVersionBytes`] if the private and public
+    /// versions are equal, or if either is already used by a built-in network,
+    /// another registered network or a SLIP-132 ypub/zpub style encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{CustomNetwork, Error, Network};
+    ///
+    /// let network = Network::register(CustomNetwork::new("Example Coin", 0x0A0B0C0D, 0x0A0B0C0E))?;
+    /// assert_eq!(Network::from_xprv_version(0x0A0B0C0D), Some(network));
+    ///
+    /// // Bitcoin's xprv version is taken
+    /// let clash = CustomNetwork::new("Clash", 0x0488ADE4, 0x0A0B0C0F);
+    /// assert!(matches!(Network::register(clash), Err(Error::DuplicateVersionBytes { .. })));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn register(network: CustomNetwork) -> Result<Network> {
+        if network.xprv_version == network.xpub_version {
+            return Err(Error::DuplicateVersionBytes {
+                version: network.xprv_version,
+            });
         }
+
+        let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+        if registry.contains(&network) {
+            return Ok(Network::Custom(network));
+        }
+
+        let existing = Self::BUILT_IN
+            .into_iter()
+            .chain(registry.iter().copied().map(Network::Custom));
+        for other in existing {
+            for version in [network.xprv_version, network.xpub_version] {
+                if version == other.xprv_version() || version == other.xpub_version() {
+                    return Err(Error::DuplicateVersionBytes { version });
+                }
+            }
+        }
+        for version in [network.xprv_version, network.xpub_version] {
+            if crate::slip132::is_slip132_version(version) {
+                return Err(Error::DuplicateVersionBytes { version });
+            }
+        }
+
+        registry.push(network);
+        Ok(Network::Custom(network))
+    }
+
+    /// Returns the built-in networks followed by the registered ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::Network;
+    ///
+    /// assert!(Network::registered().contains(&Network::DogecoinMainnet));
+    /// ```
+    pub fn registered() -> Vec<Network> {
+        let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+        Self::BUILT_IN
+            .into_iter()
+            .chain(registry.iter().copied().map(Network::Custom))
+            .collect()
     }
 
     /// Attempts to identify the network from extended private key version bytes.
     ///
-    /// This method iterates through all known networks and checks if the provided
-    /// version matches any of their xprv version bytes. This avoids hardcoding
-    /// version bytes in multiple places.
+    /// This method iterates through the built-in and registered networks and
+    /// checks if the provided version matches any of their xprv version bytes.
+    /// This avoids hardcoding version bytes in multiple places.
     ///
     /// # Arguments
     ///
@@ -262,22 +479,18 @@ impl Network {
     ///
     /// assert_eq!(Network::from_xprv_version(0x0488ADE4), Some(Network::BitcoinMainnet));
     /// assert_eq!(Network::from_xprv_version(0x04358394), Some(Network::BitcoinTestnet));
+    /// assert_eq!(Network::from_xprv_version(0x019D9CFE), Some(Network::LitecoinMainnet));
     /// assert_eq!(Network::from_xprv_version(0xFFFFFFFF), None);
     /// ```
     pub fn from_xprv_version(version: u32) -> Option<Network> {
-        // Iterate through all network variants
-        const NETWORKS: [Network; 2] = [Network::BitcoinMainnet, Network::BitcoinTestnet];
-
-        NETWORKS
-            .into_iter()
-            .find(|&network| network.xprv_version() == version)
+        Self::find(|network| network.xprv_version() == version)
     }
 
     /// Attempts to identify the network from extended public key version bytes.
     ///
-    /// This method iterates through all known networks and checks if the provided
-    /// version matches any of their xpub version bytes. This avoids hardcoding
-    /// version bytes in multiple places.
+    /// This method iterates through the built-in and registered networks and
+    /// checks if the provided version matches any of their xpub version bytes.
+    /// This avoids hardcoding version bytes in multiple places.
     ///
     /// # Arguments
     ///
@@ -295,15 +508,26 @@ impl Network {
     ///
     /// assert_eq!(Network::from_xpub_version(0x0488B21E), Some(Network::BitcoinMainnet));
     /// assert_eq!(Network::from_xpub_version(0x043587CF), Some(Network::BitcoinTestnet));
+    /// assert_eq!(Network::from_xpub_version(0x02FACAFD), Some(Network::DogecoinMainnet));
     /// assert_eq!(Network::from_xpub_version(0xFFFFFFFF), None);
     /// ```
     pub fn from_xpub_version(version: u32) -> Option<Network> {
-        // Iterate through all network variants
-        const NETWORKS: [Network; 2] = [Network::BitcoinMainnet, Network::BitcoinTestnet];
+        Self::find(|network| network.xpub_version() == version)
+    }
 
-        NETWORKS
-            .into_iter()
-            .find(|&network| network.xpub_version() == version)
+    /// Returns the first built-in or registered network matching `predicate`.
+    ///
+    /// Built-in networks are checked without taking the registry lock.
+    fn find(predicate: impl Fn(&Network) -> bool) -> Option<Network> {
+        if let Some(network) = Self::BUILT_IN.into_iter().find(&predicate) {
+            return Some(network);
+        }
+        let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+        registry
+            .iter()
+            .copied()
+            .map(Network::Custom)
+            .find(predicate)
     }
 }
 
@@ -460,6 +684,76 @@ mod tests {
         assert_ne!(Network::BitcoinMainnet, Network::BitcoinTestnet);
     }
 
+    #[test]
+    fn test_altcoin_versions() {
+        for network in Network::BUILT_IN {
+            assert_eq!(
+                Network::from_xprv_version(network.xprv_version()),
+                Some(network)
+            );
+            assert_eq!(
+                Network::from_xpub_version(network.xpub_version()),
+                Some(network)
+            );
+        }
+        assert_eq!(Network::LitecoinMainnet.name(), "Litecoin Mainnet");
+        assert_eq!(Network::DogecoinTestnet.to_string(), "Dogecoin Testnet");
+    }
+
+    #[test]
+    fn test_register_custom_network() {
+        let custom = CustomNetwork::new("Test Coin", 0x7E570001, 0x7E570002);
+        assert_eq!(Network::from_xprv_version(0x7E570001), None);
+
+        let network = Network::register(custom).unwrap();
+        assert_eq!(network, Network::Custom(custom));
+        assert_eq!(Network::from_xprv_version(0x7E570001), Some(network));
+        assert_eq!(Network::from_xpub_version(0x7E570002), Some(network));
+        assert_eq!(Network::from_xpub_version(0x7E570001), None);
+        assert!(Network::registered().contains(&network));
+        assert_eq!(network.name(), "Test Coin");
+
+        // Registering again is a no-op
+        assert_eq!(Network::register(custom), Ok(network));
+    }
+
+    #[test]
+    fn test_register_rejects_duplicate_versions() {
+        let same = CustomNetwork::new("Same", 0x7E570003, 0x7E570003);
+        assert_eq!(
+            Network::register(same),
+            Err(Error::DuplicateVersionBytes {
+                version: 0x7E570003
+            })
+        );
+
+        let built_in = CustomNetwork::new("Clash", 0x7E570004, 0x019DA462);
+        assert_eq!(
+            Network::register(built_in),
+            Err(Error::DuplicateVersionBytes {
+                version: 0x019DA462
+            })
+        );
+
+        let zpub = CustomNetwork::new("Zpub Clash", 0x7E570008, 0x04B24746);
+        assert_eq!(
+            Network::register(zpub),
+            Err(Error::DuplicateVersionBytes {
+                version: 0x04B24746
+            })
+        );
+
+        Network::register(CustomNetwork::new("First", 0x7E570005, 0x7E570006)).unwrap();
+        let registered = CustomNetwork::new("Second", 0x7E570006, 0x7E570007);
+        assert_eq!(
+            Network::register(registered),
+            Err(Error::DuplicateVersionBytes {
+                version: 0x7E570006
+            })
+        );
+        assert_eq!(Network::from_xprv_version(0x7E570004), None);
+    }
+
     #[test]
     fn test_clone_and_copy() {
         let network1 = Network::BitcoinMainnet;
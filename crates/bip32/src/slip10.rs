@@ -0,0 +1,1126 @@
+//! SLIP-10 hierarchical derivation for curves other than secp256k1.
+//!
+//! [SLIP-10](https://github.com/satoshilabs/slips/blob/master/slip-0010.md)
+//! generalizes BIP32 to other elliptic curves. Each curve has its own master
+//! HMAC key, and ed25519 only allows hardened derivation because its private
+//! keys cannot be tweaked additively. Solana, Stellar, Aptos and most other
+//! ed25519 chains derive their keys this way.
+//!
+//! NIST P-256 (secp256r1), used by WebAuthn passkeys and HSMs, supports
+//! normal and hardened derivation like secp256k1, including public derivation
+//! from a [`Slip10ExtendedPublicKey`].
+//!
+//! [`Slip10ExtendedPrivateKey`] is generic over a [`Curve`] and reuses
+//! [`DerivationPath`] and [`ChildNumber`]. [`Secp256k1`] produces the same
+//! keys as [`ExtendedPrivateKey`](crate::ExtendedPrivateKey).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip32::{DerivationPath, Ed25519, Slip10ExtendedPrivateKey};
+//! use std::str::FromStr;
+//!
+//! let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+//! let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&seed)?;
+//!
+//! // Solana's default account path
+//! let path = DerivationPath::from_str("m/44'/501'/0'/0'")?;
+//! let account = master.derive_path(&path)?;
+//! assert_eq!(account.depth(), 4);
+//! assert_eq!(account.public_key()[0], 0x00);
+//! # Ok::<(), khodpay_bip32::Error>(())
+//! ```
+
+use crate::{ChainCode, ChildNumber, DerivationPath, Error, Result};
+use ed25519_dalek::SigningKey;
+use hmac::{Hmac, Mac};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::elliptic_curve::{Field, PrimeField};
+use ripemd::Ripemd160;
+use secp256k1::{PublicKey, Scalar, SecretKey, SECP256K1};
+use sha2::{Digest, Sha256, Sha512};
+use std::fmt;
+use std::marker::PhantomData;
+use zeroize::{Zeroize, Zeroizing};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An elliptic curve supported by SLIP-10 derivation.
+///
+/// This trait is sealed; the curves are [`Ed25519`], [`NistP256`] and [`Secp256k1`].
+pub trait Curve: sealed::Sealed + Clone + Copy + fmt::Debug + PartialEq + Eq {
+    /// Human-readable curve name, used in error messages.
+    const NAME: &'static str;
+
+    /// HMAC-SHA512 key used to generate the master key from a seed.
+    const SEED_KEY: &'static [u8];
+
+    /// Whether non-hardened child keys can be derived on this curve.
+    const SUPPORTS_NORMAL_DERIVATION: bool;
+
+    /// Returns the 33-byte public key of a private key.
+    fn public_key(private_key: &[u8; 32]) -> [u8; 33];
+
+    /// Turns the left half of an HMAC output into a master private key.
+    ///
+    /// Returns `None` if SLIP-10 requires another HMAC round.
+    fn master_private_key(il: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>>;
+
+    /// Computes a child private key from the left half of an HMAC output.
+    ///
+    /// Returns `None` if SLIP-10 requires another HMAC round.
+    fn child_private_key(il: &[u8; 32], parent: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>>;
+
+    /// Computes a child public key from the left half of an HMAC output.
+    ///
+    /// Only called on curves that support normal derivation. Returns `None`
+    /// if SLIP-10 requires another HMAC round.
+    fn child_public_key(il: &[u8; 32], parent: &[u8; 33]) -> Option<[u8; 33]>;
+}
+
+/// The ed25519 curve.
+///
+/// Private keys are the raw 32-byte HMAC output and public keys are the
+/// RFC 8032 key prefixed with `0x00`. Only hardened derivation is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ed25519;
+
+impl sealed::Sealed for Ed25519 {}
+
+impl Curve for Ed25519 {
+    const NAME: &'static str = "ed25519";
+    const SEED_KEY: &'static [u8] = b"ed25519 seed";
+    const SUPPORTS_NORMAL_DERIVATION: bool = false;
+
+    fn public_key(private_key: &[u8; 32]) -> [u8; 33] {
+        let mut public_key = [0u8; 33];
+        public_key[1..].copy_from_slice(
+            SigningKey::from_bytes(private_key)
+                .verifying_key()
+                .as_bytes(),
+        );
+        public_key
+    }
+
+    fn master_private_key(il: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>> {
+        Some(Zeroizing::new(*il))
+    }
+
+    fn child_private_key(il: &[u8; 32], _parent: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>> {
+        Some(Zeroizing::new(*il))
+    }
+
+    fn child_public_key(_il: &[u8; 32], _parent: &[u8; 33]) -> Option<[u8; 33]> {
+        unreachable!("ed25519 only supports hardened derivation")
+    }
+}
+
+/// The NIST P-256 curve, also known as secp256r1 or prime256v1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NistP256;
+
+impl sealed::Sealed for NistP256 {}
+
+impl Curve for NistP256 {
+    const NAME: &'static str = "nist256p1";
+    const SEED_KEY: &'static [u8] = b"Nist256p1 seed";
+    const SUPPORTS_NORMAL_DERIVATION: bool = true;
+
+    fn public_key(private_key: &[u8; 32]) -> [u8; 33] {
+        let secret_key = p256::SecretKey::from_bytes(p256::FieldBytes::from_slice(private_key))
+            .expect("private key is valid");
+        compressed_p256(&secret_key.public_key())
+    }
+
+    fn master_private_key(il: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>> {
+        let scalar = Option::<p256::NonZeroScalar>::from(p256::NonZeroScalar::from_repr(
+            *p256::FieldBytes::from_slice(il),
+        ))?;
+        Some(p256_scalar_bytes(&scalar))
+    }
+
+    fn child_private_key(il: &[u8; 32], parent: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>> {
+        let tweak = p256_scalar(il)?;
+        let parent = p256_scalar(parent).expect("private key is valid");
+        let child = tweak + parent;
+        if bool::from(child.is_zero()) {
+            return None;
+        }
+        Some(p256_scalar_bytes(&child))
+    }
+
+    fn child_public_key(il: &[u8; 32], parent: &[u8; 33]) -> Option<[u8; 33]> {
+        let tweak = p256_scalar(il)?;
+        let parent = p256::PublicKey::from_sec1_bytes(parent).expect("public key is valid");
+        let child = p256::ProjectivePoint::GENERATOR * tweak + parent.to_projective();
+        // Fails only for the point at infinity
+        let child = p256::PublicKey::from_affine(child.to_affine()).ok()?;
+        Some(compressed_p256(&child))
+    }
+}
+
+/// Parses a big-endian P-256 scalar, returning `None` if it is not below the order.
+fn p256_scalar(bytes: &[u8; 32]) -> Option<p256::Scalar> {
+    p256::Scalar::from_repr(*p256::FieldBytes::from_slice(bytes)).into()
+}
+
+/// Serializes a P-256 scalar as 32 big-endian bytes.
+fn p256_scalar_bytes(scalar: &p256::Scalar) -> Zeroizing<[u8; 32]> {
+    let mut bytes = Zeroizing::new([0u8; 32]);
+    bytes.copy_from_slice(&scalar.to_repr());
+    bytes
+}
+
+/// Serializes a P-256 public key in 33-byte compressed SEC1 form.
+fn compressed_p256(public_key: &p256::PublicKey) -> [u8; 33] {
+    public_key
+        .to_encoded_point(true)
+        .as_bytes()
+        .try_into()
+        .expect("compressed point is 33 bytes")
+}
+
+/// The secp256k1 curve, as used by BIP32.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl sealed::Sealed for Secp256k1 {}
+
+impl Curve for Secp256k1 {
+    const NAME: &'static str = "secp256k1";
+    const SEED_KEY: &'static [u8] = b"Bitcoin seed";
+    const SUPPORTS_NORMAL_DERIVATION: bool = true;
+
+    fn public_key(private_key: &[u8; 32]) -> [u8; 33] {
+        let secret_key = SecretKey::from_slice(private_key).expect("private key is valid");
+        PublicKey::from_secret_key(SECP256K1, &secret_key).serialize()
+    }
+
+    fn master_private_key(il: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>> {
+        SecretKey::from_slice(il)
+            .ok()
+            .map(|key| Zeroizing::new(key.secret_bytes()))
+    }
+
+    fn child_private_key(il: &[u8; 32], parent: &[u8; 32]) -> Option<Zeroizing<[u8; 32]>> {
+        let tweak = Scalar::from_be_bytes(*il).ok()?;
+        let parent = SecretKey::from_slice(parent).expect("private key is valid");
+        parent
+            .add_tweak(&tweak)
+            .ok()
+            .map(|key| Zeroizing::new(key.secret_bytes()))
+    }
+
+    fn child_public_key(il: &[u8; 32], parent: &[u8; 33]) -> Option<[u8; 33]> {
+        let tweak = Scalar::from_be_bytes(*il).ok()?;
+        let parent = PublicKey::from_slice(parent).expect("public key is valid");
+        parent
+            .add_exp_tweak(SECP256K1, &tweak)
+            .ok()
+            .map(|key| key.serialize())
+    }
+}
+
+/// A SLIP-10 extended private key on the curve `C`.
+///
+/// Like [`ExtendedPrivateKey`](crate::ExtendedPrivateKey), it pairs a private
+/// key with a chain code and its position in the tree. SLIP-10 defines no
+/// serialization format for curves other than secp256k1, so the key has no
+/// network and no `Display` implementation.
+///
+/// The private key is zeroized on drop and redacted from `Debug` output.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip32::{ChildNumber, Ed25519, Error, Slip10ExtendedPrivateKey};
+///
+/// let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0x42; 32])?;
+/// let child = master.derive_child(ChildNumber::Hardened(0))?;
+/// assert_eq!(child.parent_fingerprint(), &master.fingerprint());
+///
+/// assert!(matches!(
+///     master.derive_child(ChildNumber::Normal(0)),
+///     Err(Error::HardenedDerivationRequired { .. })
+/// ));
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Slip10ExtendedPrivateKey<C: Curve> {
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: ChainCode,
+    private_key: Zeroizing<[u8; 32]>,
+    curve: PhantomData<C>,
+}
+
+impl<C: Curve> Slip10ExtendedPrivateKey<C> {
+    /// Maximum depth of the derivation tree, as in BIP32.
+    pub const MAX_DEPTH: u8 = 255;
+
+    /// Generates the master key from a seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A seed of 16 to 64 bytes, typically from a BIP39 mnemonic
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSeedLength`] if the seed is not between 16 and 64 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{Ed25519, Slip10ExtendedPrivateKey};
+    ///
+    /// let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    /// let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&seed)?;
+    /// assert_eq!(
+    ///     hex::encode(master.private_key()),
+    ///     "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+    /// );
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
+        if seed.len() < 16 || seed.len() > 64 {
+            return Err(Error::InvalidSeedLength { length: seed.len() });
+        }
+
+        let mut data = Zeroizing::new(seed.to_vec());
+        loop {
+            let mut result = hmac_sha512(C::SEED_KEY, &[&data]);
+            let (il, ir) = split(&result);
+            if let Some(private_key) = C::master_private_key(il) {
+                let chain_code = ChainCode::new(*ir);
+                result.zeroize();
+                return Ok(Self {
+                    depth: 0,
+                    parent_fingerprint: [0u8; 4],
+                    child_number: ChildNumber::Normal(0),
+                    chain_code,
+                    private_key,
+                    curve: PhantomData,
+                });
+            }
+            // SLIP-10: retry with the whole HMAC output as the new seed
+            data.zeroize();
+            data.extend_from_slice(&result);
+            result.zeroize();
+        }
+    }
+
+    /// Generates the master key from a BIP39 seed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{Ed25519, Slip10ExtendedPrivateKey};
+    /// use khodpay_bip39::{Language, Mnemonic};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English)?;
+    /// let master = Slip10ExtendedPrivateKey::<Ed25519>::from_bip39_seed(&mnemonic.to_seed("")?)?;
+    /// assert_eq!(master.depth(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bip39_seed(seed: &khodpay_bip39::Seed) -> Result<Self> {
+        Self::from_seed(seed.as_bytes())
+    }
+
+    /// Returns the depth in the derivation tree (0 for the master key).
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the fingerprint of the parent key (zero for the master key).
+    pub fn parent_fingerprint(&self) -> &[u8; 4] {
+        &self.parent_fingerprint
+    }
+
+    /// Returns the child number this key was derived with.
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+
+    /// Returns the chain code.
+    pub fn chain_code(&self) -> &ChainCode {
+        &self.chain_code
+    }
+
+    /// Returns the raw 32-byte private key.
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.private_key
+    }
+
+    /// Returns the 33-byte public key.
+    ///
+    /// For ed25519 this is `0x00` followed by the RFC 8032 public key; the
+    /// 32-byte key used by ed25519 chains is `&public_key()[1..]`.
+    pub fn public_key(&self) -> [u8; 33] {
+        C::public_key(&self.private_key)
+    }
+
+    /// Returns the first 4 bytes of the HASH160 of the public key.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.public_key())
+    }
+
+    /// Returns the extended public key with the same chain code and position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{NistP256, Slip10ExtendedPrivateKey};
+    ///
+    /// let master = Slip10ExtendedPrivateKey::<NistP256>::from_seed(&[0x01; 32])?;
+    /// let master_pub = master.to_extended_public_key();
+    /// assert_eq!(master_pub.public_key(), &master.public_key());
+    /// assert_eq!(master_pub.chain_code(), master.chain_code());
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn to_extended_public_key(&self) -> Slip10ExtendedPublicKey<C> {
+        Slip10ExtendedPublicKey {
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code.clone(),
+            public_key: self.public_key(),
+            curve: PhantomData,
+        }
+    }
+
+    /// Derives a child key.
+    ///
+    /// # Arguments
+    ///
+    /// * `child_number` - The child index; it must be hardened on curves
+    ///   without normal derivation such as [`Ed25519`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::HardenedDerivationRequired`] if `child_number` is normal
+    /// and the curve only supports hardened derivation.
+    /// Returns [`Error::MaxDepthExceeded`] if this key is already at maximum depth (255).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{ChildNumber, Ed25519, Slip10ExtendedPrivateKey};
+    ///
+    /// let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0x01; 64])?;
+    /// let child = master.derive_child(ChildNumber::Hardened(0))?;
+    /// assert_eq!(child.depth(), 1);
+    /// assert_eq!(child.child_number(), ChildNumber::Hardened(0));
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self> {
+        if !C::SUPPORTS_NORMAL_DERIVATION && !child_number.is_hardened() {
+            return Err(Error::HardenedDerivationRequired {
+                curve: C::NAME.to_string(),
+                index: child_number.to_index(),
+            });
+        }
+        if self.depth == Self::MAX_DEPTH {
+            return Err(Error::MaxDepthExceeded {
+                depth: Self::MAX_DEPTH,
+            });
+        }
+
+        let index = child_number.to_index().to_be_bytes();
+        let public_key = self.public_key();
+        let data: [&[u8]; 3] = if child_number.is_hardened() {
+            [&[0x00], &self.private_key[..], &index]
+        } else {
+            [&public_key, &[], &index]
+        };
+        let (private_key, chain_code) = derive_with_retry(&self.chain_code, &data, &index, |il| {
+            C::child_private_key(il, &self.private_key)
+        });
+
+        Ok(Self {
+            depth: self.depth + 1,
+            parent_fingerprint: fingerprint(&public_key),
+            child_number,
+            chain_code,
+            private_key,
+            curve: PhantomData,
+        })
+    }
+
+    /// Derives the key at `path` relative to this key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from [`derive_child`](Self::derive_child).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{DerivationPath, Ed25519, Slip10ExtendedPrivateKey};
+    /// use std::str::FromStr;
+    ///
+    /// let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0x01; 64])?;
+    /// let path = DerivationPath::from_str("m/44'/148'/0'")?;
+    /// assert_eq!(master.derive_path(&path)?.depth(), 3);
+    /// # Ok::<(), khodpay_bip32::Error>(())
+    /// ```
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        let mut current = self.clone();
+        for child_number in path.iter() {
+            current = current.derive_child(*child_number)?;
+        }
+        Ok(current)
+    }
+}
+
+impl<C: Curve> fmt::Debug for Slip10ExtendedPrivateKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Slip10ExtendedPrivateKey")
+            .field("curve", &C::NAME)
+            .field("depth", &self.depth)
+            .field("parent_fingerprint", &self.parent_fingerprint)
+            .field("child_number", &self.child_number)
+            .field("chain_code", &"[REDACTED]")
+            .field("private_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// A SLIP-10 extended public key on the curve `C`.
+///
+/// Obtained from [`Slip10ExtendedPrivateKey::to_extended_public_key`]. On
+/// curves with normal derivation it derives the public keys of normal
+/// children without the private key, like
+/// [`ExtendedPublicKey`](crate::ExtendedPublicKey) does for BIP32.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip32::{ChildNumber, NistP256, Slip10ExtendedPrivateKey};
+///
+/// let account = Slip10ExtendedPrivateKey::<NistP256>::from_seed(&[0x01; 32])?
+///     .derive_child(ChildNumber::Hardened(0))?;
+/// let watch_only = account.to_extended_public_key();
+///
+/// let from_private = account.derive_child(ChildNumber::Normal(5))?;
+/// let from_public = watch_only.derive_child(ChildNumber::Normal(5))?;
+/// assert_eq!(from_public.public_key(), &from_private.public_key());
+/// # Ok::<(), khodpay_bip32::Error>(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Slip10ExtendedPublicKey<C: Curve> {
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: ChildNumber,
+    chain_code: ChainCode,
+    public_key: [u8; 33],
+    curve: PhantomData<C>,
+}
+
+impl<C: Curve> Slip10ExtendedPublicKey<C> {
+    /// Maximum depth of the derivation tree, as in BIP32.
+    pub const MAX_DEPTH: u8 = 255;
+
+    /// Returns the depth in the derivation tree (0 for the master key).
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the fingerprint of the parent key (zero for the master key).
+    pub fn parent_fingerprint(&self) -> &[u8; 4] {
+        &self.parent_fingerprint
+    }
+
+    /// Returns the child number this key was derived with.
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+
+    /// Returns the chain code.
+    pub fn chain_code(&self) -> &ChainCode {
+        &self.chain_code
+    }
+
+    /// Returns the 33-byte public key.
+    pub fn public_key(&self) -> &[u8; 33] {
+        &self.public_key
+    }
+
+    /// Returns the first 4 bytes of the HASH160 of the public key.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.public_key)
+    }
+
+    /// Derives the extended public key of a normal child.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::HardenedDerivationRequired`] if the curve only supports
+    /// hardened derivation, so no child can be derived from a public key.
+    /// Returns [`Error::HardenedDerivationFromPublicKey`] if `child_number` is hardened.
+    /// Returns [`Error::MaxDepthExceeded`] if this key is already at maximum depth (255).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{ChildNumber, Error, NistP256, Slip10ExtendedPrivateKey};
+    ///
+    /// let master = Slip10ExtendedPrivateKey::<NistP256>::from_seed(&[0x01; 32])?;
+    /// let master_pub = master.to_extended_public_key();
+    /// assert_eq!(master_pub.derive_child(ChildNumber::Normal(0))?.depth(), 1);
+    /// assert!(matches!(
+    ///     master_pub.derive_child(ChildNumber::Hardened(0)),
+    ///     Err(Error::HardenedDerivationFromPublicKey { .. })
+    /// ));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self> {
+        if !C::SUPPORTS_NORMAL_DERIVATION {
+            return Err(Error::HardenedDerivationRequired {
+                curve: C::NAME.to_string(),
+                index: child_number.to_index(),
+            });
+        }
+        if child_number.is_hardened() {
+            return Err(Error::HardenedDerivationFromPublicKey {
+                index: child_number.to_index(),
+            });
+        }
+        if self.depth == Self::MAX_DEPTH {
+            return Err(Error::MaxDepthExceeded {
+                depth: Self::MAX_DEPTH,
+            });
+        }
+
+        let index = child_number.to_index().to_be_bytes();
+        let (public_key, chain_code) = derive_with_retry(
+            &self.chain_code,
+            &[&self.public_key, &index],
+            &index,
+            |il| C::child_public_key(il, &self.public_key),
+        );
+
+        Ok(Self {
+            depth: self.depth + 1,
+            parent_fingerprint: self.fingerprint(),
+            child_number,
+            chain_code,
+            public_key,
+            curve: PhantomData,
+        })
+    }
+
+    /// Derives the extended public key at `path` relative to this key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from [`derive_child`](Self::derive_child).
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        let mut current = self.clone();
+        for child_number in path.iter() {
+            current = current.derive_child(*child_number)?;
+        }
+        Ok(current)
+    }
+}
+
+impl<C: Curve> fmt::Debug for Slip10ExtendedPublicKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Slip10ExtendedPublicKey")
+            .field("curve", &C::NAME)
+            .field("depth", &self.depth)
+            .field("parent_fingerprint", &self.parent_fingerprint)
+            .field("child_number", &self.child_number)
+            .field("chain_code", &self.chain_code)
+            .field("public_key", &hex::encode(self.public_key))
+            .finish()
+    }
+}
+
+/// Runs the child key HMAC over `data` and passes IL to `key`.
+///
+/// While `key` rejects IL, SLIP-10 retries with `0x01 || IR || ser32(i)`.
+fn derive_with_retry<T>(
+    chain_code: &ChainCode,
+    data: &[&[u8]],
+    index: &[u8; 4],
+    mut key: impl FnMut(&[u8; 32]) -> Option<T>,
+) -> (T, ChainCode) {
+    let mut result = hmac_sha512(chain_code.as_bytes(), data);
+    loop {
+        let (il, ir) = split(&result);
+        if let Some(key) = key(il) {
+            let chain_code = ChainCode::new(*ir);
+            result.zeroize();
+            return (key, chain_code);
+        }
+        let retry = hmac_sha512(chain_code.as_bytes(), &[&[0x01], &ir[..], index]);
+        result.zeroize();
+        result = retry;
+    }
+}
+
+/// Returns the first 4 bytes of the HASH160 of a public key.
+fn fingerprint(public_key: &[u8; 33]) -> [u8; 4] {
+    let hash = Ripemd160::digest(Sha256::digest(public_key));
+    let mut fingerprint = [0u8; 4];
+    fingerprint.copy_from_slice(&hash[..4]);
+    fingerprint
+}
+
+/// Computes HMAC-SHA512 over the concatenation of `data`.
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut hmac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC can take key of any size");
+    for part in data {
+        hmac.update(part);
+    }
+    hmac.finalize().into_bytes().into()
+}
+
+/// Splits an HMAC output into IL and IR.
+fn split(result: &[u8; 64]) -> (&[u8; 32], &[u8; 32]) {
+    let (il, ir) = result.split_at(32);
+    (
+        il.try_into().expect("IL is 32 bytes"),
+        ir.try_into().expect("IR is 32 bytes"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExtendedPrivateKey;
+    use crate::Network;
+    use std::str::FromStr;
+
+    /// Expected fingerprint, chain code, private key and public key.
+    type Row = (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    );
+
+    fn check_vector<C: Curve>(seed: &str, rows: &[Row]) {
+        let master = Slip10ExtendedPrivateKey::<C>::from_seed(&hex::decode(seed).unwrap()).unwrap();
+        for (path, fingerprint, chain_code, private_key, public_key) in rows {
+            let key = master
+                .derive_path(&DerivationPath::from_str(path).unwrap())
+                .unwrap();
+            assert_eq!(
+                hex::encode(key.parent_fingerprint()),
+                *fingerprint,
+                "{path}"
+            );
+            assert_eq!(
+                hex::encode(key.chain_code().as_bytes()),
+                *chain_code,
+                "{path}"
+            );
+            assert_eq!(hex::encode(key.private_key()), *private_key, "{path}");
+            assert_eq!(hex::encode(key.public_key()), *public_key, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_ed25519_vector_1() {
+        check_vector::<Ed25519>(
+            "000102030405060708090a0b0c0d0e0f",
+            &[
+                (
+                    "m",
+                    "00000000",
+                    "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+                    "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+                    "00a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed",
+                ),
+                (
+                    "m/0H",
+                    "ddebc675",
+                    "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+                    "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+                    "008c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c",
+                ),
+                (
+                    "m/0H/1H",
+                    "13dab143",
+                    "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+                    "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+                    "001932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187",
+                ),
+                (
+                    "m/0H/1H/2H",
+                    "ebe4cb29",
+                    "2e69929e00b5ab250f49c3fb1c12f252de4fed2c1db88387094a0f8c4c9ccd6c",
+                    "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9",
+                    "00ae98736566d30ed0e9d2f4486a64bc95740d89c7db33f52121f8ea8f76ff0fc1",
+                ),
+                (
+                    "m/0H/1H/2H/2H",
+                    "316ec1c6",
+                    "8f6d87f93d750e0efccda017d662a1b31a266e4a6f5993b15f5c1f07f74dd5cc",
+                    "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662",
+                    "008abae2d66361c879b900d204ad2cc4984fa2aa344dd7ddc46007329ac76c429c",
+                ),
+                (
+                    "m/0H/1H/2H/2H/1000000000H",
+                    "d6322ccd",
+                    "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+                    "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+                    "003c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_ed25519_vector_2() {
+        check_vector::<Ed25519>(
+            "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+            &[
+                (
+                    "m",
+                    "00000000",
+                    "ef70a74db9c3a5af931b5fe73ed8e1a53464133654fd55e7a66f8570b8e33c3b",
+                    "171cb88b1b3c1db25add599712e36245d75bc65a1a5c9e18d76f9f2b1eab4012",
+                    "008fe9693f8fa62a4305a140b9764c5ee01e455963744fe18204b4fb948249308a",
+                ),
+                (
+                    "m/0H",
+                    "31981b50",
+                    "0b78a3226f915c082bf118f83618a618ab6dec793752624cbeb622acb562862d",
+                    "1559eb2bbec5790b0c65d8693e4d0875b1747f4970ae8b650486ed7470845635",
+                    "0086fab68dcb57aa196c77c5f264f215a112c22a912c10d123b0d03c3c28ef1037",
+                ),
+                (
+                    "m/0H/2147483647H",
+                    "1e9411b1",
+                    "138f0b2551bcafeca6ff2aa88ba8ed0ed8de070841f0c4ef0165df8181eaad7f",
+                    "ea4f5bfe8694d8bb74b7b59404632fd5968b774ed545e810de9c32a4fb4192f4",
+                    "005ba3b9ac6e90e83effcd25ac4e58a1365a9e35a3d3ae5eb07b9e4d90bcf7506d",
+                ),
+                (
+                    "m/0H/2147483647H/1H",
+                    "fcadf38c",
+                    "73bd9fff1cfbde33a1b846c27085f711c0fe2d66fd32e139d3ebc28e5a4a6b90",
+                    "3757c7577170179c7868353ada796c839135b3d30554bbb74a4b1e4a5a58505c",
+                    "002e66aa57069c86cc18249aecf5cb5a9cebbfd6fadeab056254763874a9352b45",
+                ),
+                (
+                    "m/0H/2147483647H/1H/2147483646H",
+                    "aca70953",
+                    "0902fe8a29f9140480a00ef244bd183e8a13288e4412d8389d140aac1794825a",
+                    "5837736c89570de861ebc173b1086da4f505d4adb387c6a1b1342d5e4ac9ec72",
+                    "00e33c0f7d81d843c572275f287498e8d408654fdf0d1e065b84e2e6f157aab09b",
+                ),
+                (
+                    "m/0H/2147483647H/1H/2147483646H/2H",
+                    "422c654b",
+                    "5d70af781f3a37b829f0d060924d5e960bdc02e85423494afc0b1a41bbe196d4",
+                    "551d333177df541ad876a60ea71f00447931c0a9da16f227c11ea080d7391b8d",
+                    "0047150c75db263559a70d5778bf36abbab30fb061ad69f69ece61a72b0cfa4fc0",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_nist256p1_vector_1() {
+        check_vector::<NistP256>(
+            "000102030405060708090a0b0c0d0e0f",
+            &[
+                (
+                    "m",
+                    "00000000",
+                    "beeb672fe4621673f722f38529c07392fecaa61015c80c34f29ce8b41b3cb6ea",
+                    "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2",
+                    "0266874dc6ade47b3ecd096745ca09bcd29638dd52c2c12117b11ed3e458cfa9e8",
+                ),
+                (
+                    "m/0H",
+                    "be6105b5",
+                    "3460cea53e6a6bb5fb391eeef3237ffd8724bf0a40e94943c98b83825342ee11",
+                    "6939694369114c67917a182c59ddb8cafc3004e63ca5d3b84403ba8613debc0c",
+                    "0384610f5ecffe8fda089363a41f56a5c7ffc1d81b59a612d0d649b2d22355590c",
+                ),
+                (
+                    "m/0H/1",
+                    "9b02312f",
+                    "4187afff1aafa8445010097fb99d23aee9f599450c7bd140b6826ac22ba21d0c",
+                    "284e9d38d07d21e4e281b645089a94f4cf5a5a81369acf151a1c3a57f18b2129",
+                    "03526c63f8d0b4bbbf9c80df553fe66742df4676b241dabefdef67733e070f6844",
+                ),
+                (
+                    "m/0H/1/2H",
+                    "b98005c1",
+                    "98c7514f562e64e74170cc3cf304ee1ce54d6b6da4f880f313e8204c2a185318",
+                    "694596e8a54f252c960eb771a3c41e7e32496d03b954aeb90f61635b8e092aa7",
+                    "0359cf160040778a4b14c5f4d7b76e327ccc8c4a6086dd9451b7482b5a4972dda0",
+                ),
+                (
+                    "m/0H/1/2H/2",
+                    "0e9f3274",
+                    "ba96f776a5c3907d7fd48bde5620ee374d4acfd540378476019eab70790c63a0",
+                    "5996c37fd3dd2679039b23ed6f70b506c6b56b3cb5e424681fb0fa64caf82aaa",
+                    "029f871f4cb9e1c97f9f4de9ccd0d4a2f2a171110c61178f84430062230833ff20",
+                ),
+                (
+                    "m/0H/1/2H/2/1000000000",
+                    "8b2b5c4b",
+                    "b9b7b82d326bb9cb5b5b121066feea4eb93d5241103c9e7a18aad40f1dde8059",
+                    "21c4f269ef0a5fd1badf47eeacebeeaa3de22eb8e5b0adcd0f27dd99d34d0119",
+                    "02216cd26d31147f72427a453c443ed2cde8a1e53c9cc44e5ddf739725413fe3f4",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_nist256p1_vector_2() {
+        check_vector::<NistP256>(
+            "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+            &[
+                (
+                    "m",
+                    "00000000",
+                    "96cd4465a9644e31528eda3592aa35eb39a9527769ce1855beafc1b81055e75d",
+                    "eaa31c2e46ca2962227cf21d73a7ef0ce8b31c756897521eb6c7b39796633357",
+                    "02c9e16154474b3ed5b38218bb0463e008f89ee03e62d22fdcc8014beab25b48fa",
+                ),
+                (
+                    "m/0",
+                    "607f628f",
+                    "84e9c258bb8557a40e0d041115b376dd55eda99c0042ce29e81ebe4efed9b86a",
+                    "d7d065f63a62624888500cdb4f88b6d59c2927fee9e6d0cdff9cad555884df6e",
+                    "039b6df4bece7b6c81e2adfeea4bcf5c8c8a6e40ea7ffa3cf6e8494c61a1fc82cc",
+                ),
+                (
+                    "m/0/2147483647H",
+                    "946d2a54",
+                    "f235b2bc5c04606ca9c30027a84f353acf4e4683edbd11f635d0dcc1cd106ea6",
+                    "96d2ec9316746a75e7793684ed01e3d51194d81a42a3276858a5b7376d4b94b9",
+                    "02f89c5deb1cae4fedc9905f98ae6cbf6cbab120d8cb85d5bd9a91a72f4c068c76",
+                ),
+                (
+                    "m/0/2147483647H/1",
+                    "218182d8",
+                    "7c0b833106235e452eba79d2bdd58d4086e663bc8cc55e9773d2b5eeda313f3b",
+                    "974f9096ea6873a915910e82b29d7c338542ccde39d2064d1cc228f371542bbc",
+                    "03abe0ad54c97c1d654c1852dfdc32d6d3e487e75fa16f0fd6304b9ceae4220c64",
+                ),
+                (
+                    "m/0/2147483647H/1/2147483646H",
+                    "931223e4",
+                    "5794e616eadaf33413aa309318a26ee0fd5163b70466de7a4512fd4b1a5c9e6a",
+                    "da29649bbfaff095cd43819eda9a7be74236539a29094cd8336b07ed8d4eff63",
+                    "03cb8cb067d248691808cd6b5a5a06b48e34ebac4d965cba33e6dc46fe13d9b933",
+                ),
+                (
+                    "m/0/2147483647H/1/2147483646H/2",
+                    "956c4629",
+                    "3bfb29ee8ac4484f09db09c2079b520ea5616df7820f071a20320366fbe226a7",
+                    "bb0a77ba01cc31d77205d51d08bd313b979a71ef4de9b062f8958297e746bd67",
+                    "020ee02e18967237cf62672983b253ee62fa4dd431f8243bfeccdf39dbe181387f",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_nist256p1_derivation_retry() {
+        // IL >= n for m/28578H/33941, so the second HMAC round is used
+        check_vector::<NistP256>(
+            "000102030405060708090a0b0c0d0e0f",
+            &[
+                (
+                    "m/28578H",
+                    "be6105b5",
+                    "e94c8ebe30c2250a14713212f6449b20f3329105ea15b652ca5bdfc68f6c65c2",
+                    "06f0db126f023755d0b8d86d4591718a5210dd8d024e3e14b6159d63f53aa669",
+                    "02519b5554a4872e8c9c1c847115363051ec43e93400e030ba3c36b52a3e70a5b7",
+                ),
+                (
+                    "m/28578H/33941",
+                    "3e2b7bc6",
+                    "9e87fe95031f14736774cd82f25fd885065cb7c358c1edf813c72af535e83071",
+                    "092154eed4af83e078ff9b84322015aefe5769e31270f62c3f66c33888335f3a",
+                    "0235bfee614c0d5b2cae260000bb1d0d84b270099ad790022c1ae0b2e782efe120",
+                ),
+            ],
+        );
+
+        let account = Slip10ExtendedPrivateKey::<NistP256>::from_seed(
+            &hex::decode("000102030405060708090a0b0c0d0e0f").unwrap(),
+        )
+        .unwrap()
+        .derive_child(ChildNumber::Hardened(28578))
+        .unwrap();
+        let child = account
+            .to_extended_public_key()
+            .derive_child(ChildNumber::Normal(33941))
+            .unwrap();
+        assert_eq!(
+            hex::encode(child.chain_code().as_bytes()),
+            "9e87fe95031f14736774cd82f25fd885065cb7c358c1edf813c72af535e83071"
+        );
+        assert_eq!(
+            hex::encode(child.public_key()),
+            "0235bfee614c0d5b2cae260000bb1d0d84b270099ad790022c1ae0b2e782efe120"
+        );
+    }
+
+    #[test]
+    fn test_nist256p1_seed_retry() {
+        // IL >= n for the first master HMAC, so it is hashed again
+        check_vector::<NistP256>(
+            "a7305bc8df8d0951f0cb224c0e95d7707cbdf2c6ce7e8d481fec69c7ff5e9446",
+            &[(
+                "m",
+                "00000000",
+                "7762f9729fed06121fd13f326884c82f59aa95c57ac492ce8c9654e60efd130c",
+                "3b8c18469a4634517d6d0b65448f8e6c62091b45540a1743c5846be55d47d88f",
+                "0383619fadcde31063d8c5cb00dbfe1713f3e6fa169d8541a798752a1c1ca0cb20",
+            )],
+        );
+    }
+
+    #[test]
+    fn test_public_derivation_matches_private() {
+        let seed = [0x5a; 64];
+        let path = DerivationPath::from_str("m/0/7/2147483647/1").unwrap();
+
+        let master = Slip10ExtendedPrivateKey::<NistP256>::from_seed(&seed).unwrap();
+        let from_private = master.derive_path(&path).unwrap();
+        let from_public = master.to_extended_public_key().derive_path(&path).unwrap();
+        assert_eq!(from_public, from_private.to_extended_public_key());
+
+        let master = Slip10ExtendedPrivateKey::<Secp256k1>::from_seed(&seed).unwrap();
+        let from_public = master.to_extended_public_key().derive_path(&path).unwrap();
+        let bip32 = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet)
+            .unwrap()
+            .to_extended_public_key()
+            .derive_path(&path)
+            .unwrap();
+        assert_eq!(from_public.public_key(), &bip32.public_key().to_bytes());
+        assert_eq!(from_public.chain_code(), bip32.chain_code());
+    }
+
+    #[test]
+    fn test_public_derivation_errors() {
+        let master = Slip10ExtendedPrivateKey::<NistP256>::from_seed(&[0x01; 32])
+            .unwrap()
+            .to_extended_public_key();
+        assert_eq!(
+            master.derive_child(ChildNumber::Hardened(1)),
+            Err(Error::HardenedDerivationFromPublicKey { index: 0x80000001 })
+        );
+
+        let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0x01; 32])
+            .unwrap()
+            .to_extended_public_key();
+        assert_eq!(
+            master.derive_child(ChildNumber::Normal(1)),
+            Err(Error::HardenedDerivationRequired {
+                curve: "ed25519".to_string(),
+                index: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_rfc8032_public_keys() {
+        // RFC 8032, section 7.1, tests 1 to 3
+        let cases = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            ),
+        ];
+        for (private_key, expected) in cases {
+            let private_key: [u8; 32] = hex::decode(private_key).unwrap().try_into().unwrap();
+            assert_eq!(
+                hex::encode(&Ed25519::public_key(&private_key)[1..]),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_ed25519_rejects_normal_derivation() {
+        let master = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0x01; 32]).unwrap();
+        assert_eq!(
+            master.derive_child(ChildNumber::Normal(7)),
+            Err(Error::HardenedDerivationRequired {
+                curve: "ed25519".to_string(),
+                index: 7,
+            })
+        );
+        let path = DerivationPath::from_str("m/44'/501'/0'/0").unwrap();
+        assert!(matches!(
+            master.derive_path(&path),
+            Err(Error::HardenedDerivationRequired { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_secp256k1_matches_bip32() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let path = DerivationPath::from_str("m/0'/1/2'/2/1000000000").unwrap();
+        let slip10 = Slip10ExtendedPrivateKey::<Secp256k1>::from_seed(&seed)
+            .unwrap()
+            .derive_path(&path)
+            .unwrap();
+        let bip32 = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet)
+            .unwrap()
+            .derive_path(&path)
+            .unwrap();
+
+        assert_eq!(slip10.private_key(), &bip32.private_key().to_bytes());
+        assert_eq!(slip10.chain_code(), bip32.chain_code());
+        assert_eq!(slip10.parent_fingerprint(), bip32.parent_fingerprint());
+        assert_eq!(slip10.fingerprint(), bip32.fingerprint());
+    }
+
+    #[test]
+    fn test_invalid_seed_length() {
+        assert_eq!(
+            Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0u8; 15]),
+            Err(Error::InvalidSeedLength { length: 15 })
+        );
+        assert_eq!(
+            Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0u8; 65]),
+            Err(Error::InvalidSeedLength { length: 65 })
+        );
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let mut key = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0x01; 32]).unwrap();
+        key.depth = Slip10ExtendedPrivateKey::<Ed25519>::MAX_DEPTH;
+        assert_eq!(
+            key.derive_child(ChildNumber::Hardened(0)),
+            Err(Error::MaxDepthExceeded { depth: 255 })
+        );
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let key = Slip10ExtendedPrivateKey::<Ed25519>::from_seed(&[0x01; 32]).unwrap();
+        let debug = format!("{key:?}");
+        assert!(debug.contains("ed25519"));
+        assert!(!debug.contains(&hex::encode(key.private_key())));
+        assert!(!debug.contains(&format!("{:?}", key.private_key())));
+    }
+}
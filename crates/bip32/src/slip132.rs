@@ -0,0 +1,420 @@
+//! SLIP-132 version bytes for script-type specific extended keys.
+//!
+//! BIP-32 serializes every extended key with the network's xprv/xpub version
+//! bytes. [SLIP-132](https://github.com/satoshilabs/slips/blob/master/slip-0132.md)
+//! registers additional version bytes that also record which script type an
+//! account pays to, which is how wallets such as Electrum and BlueWallet tell
+//! a BIP-84 `zpub` apart from a BIP-44 `xpub`.
+//!
+//! | Script type   | Mainnet       | Testnet       | Derivation       |
+//! |---------------|---------------|---------------|------------------|
+//! | P2PKH         | `xprv`/`xpub` | `tprv`/`tpub` | BIP-44           |
+//! | P2SH-P2WPKH   | `yprv`/`ypub` | `uprv`/`upub` | BIP-49           |
+//! | P2WPKH        | `zprv`/`zpub` | `vprv`/`vpub` | BIP-84           |
+//! | P2SH-P2WSH    | `Yprv`/`Ypub` | `Uprv`/`Upub` | BIP-48 (`1'`)    |
+//! | P2WSH         | `Zprv`/`Zpub` | `Vprv`/`Vpub` | BIP-48 (`2'`)    |
+//!
+//! P2PKH uses the network's own version bytes, so it works on every network.
+//! The other script types are registered for Bitcoin mainnet and testnet,
+//! plus P2SH-P2WPKH (`Mtpv`/`Mtub`) on Litecoin mainnet.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip32::{KeyType, Network, ScriptType};
+//!
+//! let zpub = ScriptType::P2wpkh.version_bytes(Network::BitcoinMainnet, KeyType::Public);
+//! assert_eq!(zpub, Some(0x04B24746));
+//! assert_eq!(
+//!     ScriptType::from_version_bytes(0x04B24746, KeyType::Public),
+//!     Some((Network::BitcoinMainnet, ScriptType::P2wpkh))
+//! );
+//! ```
+
+use crate::{KeyType, Network};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+/// SLIP-132 version bytes beyond the plain BIP-32 ones, as
+/// `(network, script type, xprv version, xpub version)`.
+const VERSIONS: [(Network, ScriptType, u32, u32); 9] = [
+    (
+        Network::BitcoinMainnet,
+        ScriptType::P2shP2wpkh,
+        0x049D7878,
+        0x049D7CB2,
+    ),
+    (
+        Network::BitcoinMainnet,
+        ScriptType::P2wpkh,
+        0x04B2430C,
+        0x04B24746,
+    ),
+    (
+        Network::BitcoinMainnet,
+        ScriptType::P2shP2wsh,
+        0x0295B005,
+        0x0295B43F,
+    ),
+    (
+        Network::BitcoinMainnet,
+        ScriptType::P2wsh,
+        0x02AA7A99,
+        0x02AA7ED3,
+    ),
+    (
+        Network::BitcoinTestnet,
+        ScriptType::P2shP2wpkh,
+        0x044A4E28,
+        0x044A5262,
+    ),
+    (
+        Network::BitcoinTestnet,
+        ScriptType::P2wpkh,
+        0x045F18BC,
+        0x045F1CF6,
+    ),
+    (
+        Network::BitcoinTestnet,
+        ScriptType::P2shP2wsh,
+        0x024285B5,
+        0x024289EF,
+    ),
+    (
+        Network::BitcoinTestnet,
+        ScriptType::P2wsh,
+        0x02575048,
+        0x02575483,
+    ),
+    (
+        Network::LitecoinMainnet,
+        ScriptType::P2shP2wpkh,
+        0x01B26792,
+        0x01B26EF6,
+    ),
+];
+
+/// Script type recorded in the version bytes of a SLIP-132 extended key.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip32::ScriptType;
+///
+/// assert!(ScriptType::P2wsh.is_multisig());
+/// assert_eq!(ScriptType::P2shP2wpkh.to_string(), "P2SH-P2WPKH");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptType {
+    /// Legacy pay-to-public-key-hash (xpub/tpub, BIP-44).
+    P2pkh,
+
+    /// SegWit P2WPKH nested in P2SH (ypub/upub, BIP-49).
+    P2shP2wpkh,
+
+    /// Native SegWit P2WPKH (zpub/vpub, BIP-84).
+    P2wpkh,
+
+    /// Multisig P2WSH nested in P2SH (Ypub/Upub, BIP-48 script type `1'`).
+    P2shP2wsh,
+
+    /// Native SegWit multisig P2WSH (Zpub/Vpub, BIP-48 script type `2'`).
+    P2wsh,
+}
+
+impl ScriptType {
+    /// All script types, in SLIP-132 order.
+    pub const ALL: [ScriptType; 5] = [
+        ScriptType::P2pkh,
+        ScriptType::P2shP2wpkh,
+        ScriptType::P2wpkh,
+        ScriptType::P2shP2wsh,
+        ScriptType::P2wsh,
+    ];
+
+    /// Returns the human-readable name of the script type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::ScriptType;
+    ///
+    /// assert_eq!(ScriptType::P2wpkh.name(), "P2WPKH");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScriptType::P2pkh => "P2PKH",
+            ScriptType::P2shP2wpkh => "P2SH-P2WPKH",
+            ScriptType::P2wpkh => "P2WPKH",
+            ScriptType::P2shP2wsh => "P2SH-P2WSH",
+            ScriptType::P2wsh => "P2WSH",
+        }
+    }
+
+    /// Returns `true` for the multisig script types (Ypub/Zpub).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::ScriptType;
+    ///
+    /// assert!(ScriptType::P2shP2wsh.is_multisig());
+    /// assert!(!ScriptType::P2wpkh.is_multisig());
+    /// ```
+    pub fn is_multisig(&self) -> bool {
+        matches!(self, ScriptType::P2shP2wsh | ScriptType::P2wsh)
+    }
+
+    /// Returns the version bytes for this script type on `network`.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - The network of the extended key
+    /// * `key_type` - Whether the key is private or public
+    ///
+    /// # Returns
+    ///
+    /// - `Some(u32)` with the version bytes
+    /// - `None` if SLIP-132 defines no version bytes for this script type on
+    ///   `network` (outside Bitcoin only P2PKH and Litecoin's P2SH-P2WPKH exist)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{KeyType, Network, ScriptType};
+    ///
+    /// assert_eq!(
+    ///     ScriptType::P2shP2wpkh.version_bytes(Network::BitcoinTestnet, KeyType::Private),
+    ///     Some(0x044A4E28)
+    /// );
+    /// assert_eq!(
+    ///     ScriptType::P2pkh.version_bytes(Network::LitecoinMainnet, KeyType::Public),
+    ///     Some(0x019DA462)
+    /// );
+    /// assert_eq!(ScriptType::P2wpkh.version_bytes(Network::LitecoinMainnet, KeyType::Public), None);
+    /// ```
+    pub fn version_bytes(&self, network: Network, key_type: KeyType) -> Option<u32> {
+        if *self == ScriptType::P2pkh {
+            return Some(network.version_bytes(key_type));
+        }
+
+        VERSIONS
+            .iter()
+            .find(|(n, script_type, _, _)| *n == network && script_type == self)
+            .map(|&(_, _, xprv, xpub)| match key_type {
+                KeyType::Private => xprv,
+                KeyType::Public => xpub,
+            })
+    }
+
+    /// Identifies the network and script type from extended key version bytes.
+    ///
+    /// Plain BIP-32 version bytes of any built-in or registered network map to
+    /// [`ScriptType::P2pkh`].
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The 4-byte version prefix from an extended key
+    /// * `key_type` - Whether the key is private or public
+    ///
+    /// # Returns
+    ///
+    /// - `Some((Network, ScriptType))` if the version is recognized
+    /// - `None` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::{KeyType, Network, ScriptType};
+    ///
+    /// assert_eq!(
+    ///     ScriptType::from_version_bytes(0x0488B21E, KeyType::Public),
+    ///     Some((Network::BitcoinMainnet, ScriptType::P2pkh))
+    /// );
+    /// assert_eq!(
+    ///     ScriptType::from_version_bytes(0x02575048, KeyType::Private),
+    ///     Some((Network::BitcoinTestnet, ScriptType::P2wsh))
+    /// );
+    /// assert_eq!(ScriptType::from_version_bytes(0x02575048, KeyType::Public), None);
+    /// ```
+    pub fn from_version_bytes(version: u32, key_type: KeyType) -> Option<(Network, ScriptType)> {
+        let network = match key_type {
+            KeyType::Private => Network::from_xprv_version(version),
+            KeyType::Public => Network::from_xpub_version(version),
+        };
+        if let Some(network) = network {
+            return Some((network, ScriptType::P2pkh));
+        }
+
+        VERSIONS
+            .iter()
+            .find(|&&(_, _, xprv, xpub)| match key_type {
+                KeyType::Private => xprv == version,
+                KeyType::Public => xpub == version,
+            })
+            .map(|&(network, script_type, _, _)| (network, script_type))
+    }
+}
+
+impl std::fmt::Display for ScriptType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns `true` if `version` is one of the SLIP-132 version bytes, so that
+/// [`Network::register`] can keep custom networks from shadowing them.
+pub(crate) fn is_slip132_version(version: u32) -> bool {
+    VERSIONS
+        .iter()
+        .any(|&(_, _, xprv, xpub)| version == xprv || version == xpub)
+}
+
+/// Replaces the version bytes of a Base58Check extended key and recomputes
+/// its checksum.
+///
+/// `encoded` must be a well-formed extended key, such as the output of the
+/// `Display` implementations.
+pub(crate) fn with_version(encoded: &str, version: u32) -> String {
+    let mut data = bs58::decode(encoded)
+        .into_vec()
+        .expect("extended key encoding is valid Base58");
+    data.truncate(78);
+    data[0..4].copy_from_slice(&version.to_be_bytes());
+
+    let checksum = Sha256::digest(Sha256::digest(&data));
+    data.extend_from_slice(&checksum[0..4]);
+
+    let encoded = bs58::encode(&data).into_string();
+    data.zeroize();
+    encoded
+}
+
+/// Returns the version bytes of a Base58 extended key, or `None` if `encoded`
+/// is not valid Base58 or too short.
+pub(crate) fn version_of(encoded: &str) -> Option<u32> {
+    let mut data = bs58::decode(encoded).into_vec().ok()?;
+    let version = data
+        .get(0..4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+    data.zeroize();
+    version
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Base58 prefix of an extended key with the given version bytes.
+    fn prefix(version: u32) -> String {
+        let mut data = version.to_be_bytes().to_vec();
+        data.extend_from_slice(&[0u8; 78]);
+        bs58::encode(data).into_string()[..4].to_string()
+    }
+
+    #[test]
+    fn test_version_bytes_prefixes() {
+        let cases = [
+            (Network::BitcoinMainnet, ScriptType::P2pkh, "xprv", "xpub"),
+            (
+                Network::BitcoinMainnet,
+                ScriptType::P2shP2wpkh,
+                "yprv",
+                "ypub",
+            ),
+            (Network::BitcoinMainnet, ScriptType::P2wpkh, "zprv", "zpub"),
+            (
+                Network::BitcoinMainnet,
+                ScriptType::P2shP2wsh,
+                "Yprv",
+                "Ypub",
+            ),
+            (Network::BitcoinMainnet, ScriptType::P2wsh, "Zprv", "Zpub"),
+            (Network::BitcoinTestnet, ScriptType::P2pkh, "tprv", "tpub"),
+            (
+                Network::BitcoinTestnet,
+                ScriptType::P2shP2wpkh,
+                "uprv",
+                "upub",
+            ),
+            (Network::BitcoinTestnet, ScriptType::P2wpkh, "vprv", "vpub"),
+            (
+                Network::BitcoinTestnet,
+                ScriptType::P2shP2wsh,
+                "Uprv",
+                "Upub",
+            ),
+            (Network::BitcoinTestnet, ScriptType::P2wsh, "Vprv", "Vpub"),
+            (Network::LitecoinMainnet, ScriptType::P2pkh, "Ltpv", "Ltub"),
+            (
+                Network::LitecoinMainnet,
+                ScriptType::P2shP2wpkh,
+                "Mtpv",
+                "Mtub",
+            ),
+        ];
+
+        for (network, script_type, xprv, xpub) in cases {
+            let private = script_type
+                .version_bytes(network, KeyType::Private)
+                .unwrap();
+            let public = script_type.version_bytes(network, KeyType::Public).unwrap();
+            assert_eq!(prefix(private), xprv);
+            assert_eq!(prefix(public), xpub);
+        }
+    }
+
+    #[test]
+    fn test_from_version_bytes_roundtrip() {
+        for network in [Network::BitcoinMainnet, Network::BitcoinTestnet] {
+            for script_type in ScriptType::ALL {
+                for key_type in [KeyType::Private, KeyType::Public] {
+                    let version = script_type.version_bytes(network, key_type).unwrap();
+                    assert_eq!(
+                        ScriptType::from_version_bytes(version, key_type),
+                        Some((network, script_type))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_other_networks_only_support_p2pkh() {
+        for network in [Network::LitecoinTestnet, Network::DogecoinMainnet] {
+            for script_type in ScriptType::ALL {
+                let version = script_type.version_bytes(network, KeyType::Public);
+                assert_eq!(version.is_some(), script_type == ScriptType::P2pkh);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_version_bytes_unknown() {
+        assert_eq!(
+            ScriptType::from_version_bytes(0xFFFFFFFF, KeyType::Public),
+            None
+        );
+        // A zpub version is not a valid private version
+        assert_eq!(
+            ScriptType::from_version_bytes(0x04B24746, KeyType::Private),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_multisig() {
+        let multisig: Vec<_> = ScriptType::ALL
+            .into_iter()
+            .filter(ScriptType::is_multisig)
+            .collect();
+        assert_eq!(multisig, [ScriptType::P2shP2wsh, ScriptType::P2wsh]);
+    }
+
+    #[test]
+    fn test_is_slip132_version() {
+        assert!(is_slip132_version(0x04B24746));
+        assert!(is_slip132_version(0x0295B005));
+        assert!(!is_slip132_version(0x0488B21E));
+    }
+}
@@ -67,7 +67,7 @@ use crate::{ExtendedPrivateKey, ExtendedPublicKey, Network, Result};
 /// let seed = mnemonic.to_seed("")?;
 ///
 /// // 2. Generate both keys at once
-/// let (master_priv, master_pub) = generate_master_keypair(&seed, Network::BitcoinMainnet)?;
+/// let (master_priv, master_pub) = generate_master_keypair(seed.as_bytes(), Network::BitcoinMainnet)?;
 ///
 /// // 3. Export for backup
 /// let xprv = master_priv.to_string();  // Store securely
@@ -403,7 +403,8 @@ mod tests {
         ).unwrap();
 
         let seed = mnemonic.to_seed("").unwrap();
-        let (priv_key, pub_key) = generate_master_keypair(&seed, Network::BitcoinMainnet).unwrap();
+        let (priv_key, pub_key) =
+            generate_master_keypair(seed.as_bytes(), Network::BitcoinMainnet).unwrap();
 
         // Should work with BIP39-derived seeds
         assert_eq!(priv_key.depth(), 0);
@@ -84,6 +84,36 @@ fn bench_seed_generation(c: &mut Criterion) {
         b.iter(|| mnemonic.to_seed(black_box("пароль 密码 🔑")).unwrap());
     });
 
+    // A passphrase search batch: 32 candidates for one 24-word mnemonic
+    let mnemonic_24 = Mnemonic::generate(WordCount::TwentyFour, Language::English).unwrap();
+    let passphrases: Vec<String> = (0..32).map(|i| format!("candidate-{i}")).collect();
+
+    group.bench_function("to_seed_batch_of_32", |b| {
+        b.iter(|| {
+            for passphrase in &passphrases {
+                black_box(mnemonic_24.to_seed(black_box(passphrase)).unwrap());
+            }
+        });
+    });
+
+    group.bench_function("seed_deriver_derive_of_32", |b| {
+        b.iter(|| {
+            let deriver = mnemonic_24.seed_deriver();
+            for passphrase in &passphrases {
+                black_box(deriver.derive(black_box(passphrase)));
+            }
+        });
+    });
+
+    // Eight lanes at a time when built with AVX-512 (-C target-cpu=native)
+    let refs: Vec<&str> = passphrases.iter().map(String::as_str).collect();
+    group.bench_function("seed_deriver_derive_batch_of_32", |b| {
+        b.iter(|| {
+            let deriver = mnemonic_24.seed_deriver();
+            black_box(deriver.derive_batch(black_box(&refs)));
+        });
+    });
+
     group.finish();
 }
 
@@ -137,9 +167,10 @@ fn bench_multi_language(c: &mut Criterion) {
         group.bench_with_input(
             BenchmarkId::new("generate", language.name()),
             &language,
-            |b, &lang| {
+            |b, lang| {
                 b.iter(|| {
-                    Mnemonic::generate(black_box(WordCount::Twelve), black_box(lang)).unwrap()
+                    Mnemonic::generate(black_box(WordCount::Twelve), black_box(lang.clone()))
+                        .unwrap()
                 });
             },
         );
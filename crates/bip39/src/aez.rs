@@ -0,0 +1,333 @@
+//! The AEZ v5 authenticated cipher, limited to the short messages of aezeed.
+//!
+//! AEZ enciphers the whole message with a tweakable wide-block cipher built
+//! from AES rounds; authenticity comes from `tau` zero bytes appended before
+//! enciphering and checked after deciphering. Only AEZ-tiny, the Feistel
+//! network used for messages shorter than 32 bytes, is implemented, which is
+//! all LND's 23-byte cipher seed ciphertext needs.
+//!
+//! See the [AEZ v5 specification](https://web.cs.ucdavis.edu/~rogaway/aez/aez.pdf).
+
+use alloc::vec::Vec;
+
+use blake2::digest::consts::U48;
+use blake2::{Blake2b, Digest};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// A 128-bit AEZ block.
+type Block = [u8; 16];
+
+/// Messages from this length on are handled by AEZ-core, which is not
+/// implemented.
+const CORE_THRESHOLD: usize = 32;
+
+/// The AEZ key schedule: the three 128-bit subkeys extracted from the key.
+#[derive(Zeroize, ZeroizeOnDrop)]
+struct Aez {
+    i: Block,
+    j: Block,
+    l: Block,
+}
+
+impl Aez {
+    /// Extracts the subkeys, hashing the key with BLAKE2b-384 unless it is
+    /// exactly 48 bytes long.
+    fn new(key: &[u8]) -> Self {
+        let mut extracted = Zeroizing::new([0u8; 48]);
+        if key.len() == 48 {
+            extracted.copy_from_slice(key);
+        } else {
+            extracted.copy_from_slice(&Blake2b::<U48>::digest(key));
+        }
+
+        let mut aez = Self {
+            i: [0; 16],
+            j: [0; 16],
+            l: [0; 16],
+        };
+        aez.i.copy_from_slice(&extracted[..16]);
+        aez.j.copy_from_slice(&extracted[16..32]);
+        aez.l.copy_from_slice(&extracted[32..]);
+        aez
+    }
+
+    /// The tweakable block cipher `E(j, i)` for `j ≥ 0`: four AES rounds
+    /// keyed with J, I, L and zero over the input offset by
+    /// `j·J ⊕ 2^⌈i/8⌉·I ⊕ (i mod 8)·L`.
+    fn e(&self, j: u32, i: u32, input: &Block) -> Block {
+        let mut offset = multiply(j, &self.j);
+        xor_into(&mut offset, &multiply(1 << i.div_ceil(8), &self.i));
+        xor_into(&mut offset, &multiply(i % 8, &self.l));
+
+        let mut block = *input;
+        xor_into(&mut block, &offset);
+        for round_key in [&self.j, &self.i, &self.l, &[0u8; 16]] {
+            block = aes_round(&block, round_key);
+        }
+        block
+    }
+
+    /// AEZ-hash of the tag length, nonce and associated data items.
+    fn hash(&self, tau: usize, nonce: &[u8], ad: &[&[u8]]) -> Block {
+        let tau_block = ((tau * 8) as u128).to_be_bytes();
+        let items = [&tau_block[..], nonce]
+            .into_iter()
+            .chain(ad.iter().copied());
+
+        let mut delta = [0u8; 16];
+        for (index, item) in items.enumerate() {
+            let j = index as u32 + 3;
+            if item.is_empty() {
+                xor_into(&mut delta, &self.e(j, 0, &pad_block(item)));
+            }
+            for (position, chunk) in item.chunks(16).enumerate() {
+                let block = match <&Block>::try_from(chunk) {
+                    Ok(full) => self.e(j, position as u32 + 1, full),
+                    Err(_) => self.e(j, 0, &pad_block(chunk)),
+                };
+                xor_into(&mut delta, &block);
+            }
+        }
+        delta
+    }
+
+    /// Deciphers a message shorter than 32 bytes with AEZ-tiny.
+    fn decipher_tiny(&self, delta: &Block, input: &[u8]) -> Zeroizing<Vec<u8>> {
+        let bits = input.len() * 8;
+        let half = bits / 2;
+        let rounds = match bits {
+            8 => 24,
+            16 => 16,
+            _ if bits < 128 => 10,
+            _ => 8,
+        };
+        let j = if bits >= 128 { 6 } else { 7 };
+
+        let mut output = Zeroizing::new(input.to_vec());
+        if bits < 128 {
+            self.flip_first_bit(delta, &mut output);
+        }
+
+        let mut left = read_bits(&output, 0, half);
+        let mut right = read_bits(&output, half, half);
+        for round in (0..rounds).rev() {
+            let mut block = (((right << 1) | 1) << (127 - half)).to_be_bytes();
+            xor_into(&mut block, delta);
+            xor_into(&mut block, &(round as u128).to_be_bytes());
+            let f = u128::from_be_bytes(self.e(0, j, &block)) >> (128 - half);
+            (left, right) = (right, left ^ f);
+        }
+        write_bits(&mut output, 0, half, right);
+        write_bits(&mut output, half, half, left);
+        output
+    }
+
+    /// Toggles the first bit of a short AEZ-tiny block, which keeps the
+    /// enciphering of messages under 128 bits from being an involution.
+    fn flip_first_bit(&self, delta: &Block, message: &mut [u8]) {
+        let mut block = [0u8; 16];
+        block[..message.len()].copy_from_slice(message);
+        block[0] |= 0x80;
+        xor_into(&mut block, delta);
+        message[0] ^= self.e(0, 3, &block)[0] & 0x80;
+    }
+}
+
+/// Decrypts and authenticates an AEZ ciphertext of fewer than 32 bytes.
+///
+/// Returns the plaintext without its `tau` bytes of zero padding, or `None`
+/// if the padding is not zero, i.e. the key, nonce or associated data is
+/// wrong or the ciphertext was modified.
+///
+/// # Panics
+///
+/// Panics if the ciphertext is not longer than `tau` or is 32 bytes or more.
+pub(crate) fn decrypt(
+    key: &[u8],
+    nonce: &[u8],
+    ad: &[&[u8]],
+    tau: usize,
+    ciphertext: &[u8],
+) -> Option<Zeroizing<Vec<u8>>> {
+    assert!(
+        tau < ciphertext.len() && ciphertext.len() < CORE_THRESHOLD,
+        "AEZ-core ciphertexts are not supported"
+    );
+
+    let aez = Aez::new(key);
+    let delta = aez.hash(tau, nonce, ad);
+    let mut plaintext = aez.decipher_tiny(&delta, ciphertext);
+
+    let length = ciphertext.len() - tau;
+    let padding = plaintext[length..]
+        .iter()
+        .fold(0u8, |acc, &byte| acc | byte);
+    if padding != 0 {
+        return None;
+    }
+    plaintext.truncate(length);
+    Some(plaintext)
+}
+
+/// One AES encryption round (SubBytes, ShiftRows, MixColumns, AddRoundKey).
+fn aes_round(block: &Block, round_key: &Block) -> Block {
+    let mut state = aes::Block::from(*block);
+    aes::hazmat::cipher_round(&mut state, &aes::Block::from(*round_key));
+    state.into()
+}
+
+/// Multiplies a block by `factor` in GF(2¹²⁸).
+fn multiply(mut factor: u32, block: &Block) -> Block {
+    let mut product = [0u8; 16];
+    let mut power = *block;
+    while factor != 0 {
+        if factor & 1 == 1 {
+            xor_into(&mut product, &power);
+        }
+        power = double(&power);
+        factor >>= 1;
+    }
+    product
+}
+
+/// Multiplies a block by two in GF(2¹²⁸), big-endian with the polynomial
+/// x¹²⁸ + x⁷ + x² + x + 1.
+fn double(block: &Block) -> Block {
+    let value = u128::from_be_bytes(*block);
+    let reduction = if value >> 127 == 1 { 0x87 } else { 0 };
+    ((value << 1) ^ reduction).to_be_bytes()
+}
+
+fn xor_into(target: &mut Block, other: &Block) {
+    for (t, o) in target.iter_mut().zip(other) {
+        *t ^= o;
+    }
+}
+
+/// Pads a partial block with a single one bit followed by zeros.
+fn pad_block(chunk: &[u8]) -> Block {
+    let mut block = [0u8; 16];
+    block[..chunk.len()].copy_from_slice(chunk);
+    block[chunk.len()] = 0x80;
+    block
+}
+
+/// Reads `count` (at most 128) bits starting at bit `start`, most significant
+/// first.
+fn read_bits(bytes: &[u8], start: usize, count: usize) -> u128 {
+    (start..start + count).fold(0, |acc, bit| {
+        (acc << 1) | u128::from((bytes[bit / 8] >> (7 - bit % 8)) & 1)
+    })
+}
+
+/// Writes the low `count` bits of `value` starting at bit `start`.
+fn write_bits(bytes: &mut [u8], start: usize, count: usize, value: u128) {
+    for offset in 0..count {
+        let bit = start + offset;
+        let mask = 0x80 >> (bit % 8);
+        if (value >> (count - 1 - offset)) & 1 == 1 {
+            bytes[bit / 8] |= mask;
+        } else {
+            bytes[bit / 8] &= !mask;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Key, nonce, associated data, tau, plaintext and ciphertext.
+    type Vector = (
+        &'static str,
+        &'static str,
+        [&'static str; 3],
+        usize,
+        &'static str,
+        &'static str,
+    );
+
+    /// AEZ-tiny vectors from the AEZ v5 reference test suite.
+    const VECTORS: [Vector; 6] = [
+        (
+            "77adeb01d75d4af09b2a9812b1344da69e6ef090973291678f56df2d244e68fd270c40f0bc2f39d1e994a94c7bd7b687",
+            "dfd45ad5a2be21b64c2953aeb2317b93",
+            ["251dff82b0f705c5f6dc", "", "5aa30e167d58e070384183def8e046"],
+            0,
+            "59",
+            "53",
+        ),
+        (
+            "afc167f3cb82dfc8bda74c91fbf3c36a0552668db62e61f4eeb51ac2893ab28849abefc657361d0e9e24915e41c3e273",
+            "7cad786f60063a7d8eff8700683d329e",
+            ["6459b8acb22a25018ab5", "", "ab0ad1ab5b1062f43931cdcc2c563b"],
+            0,
+            "66bb",
+            "82e9",
+        ),
+        (
+            "772d745f91663b6b599a44f0b7f918217f41cebe50f5ac37d189d385ab3c203dfce91c6857bc3a19bc35ba4d99871c03",
+            "27d4c48213aad80b2f81d09221bbd50e",
+            ["b182570ae089aaeb8c2f", "", "c12e5d5fea70bc0d9b9d4bc3c2043f"],
+            16,
+            "8e3de4dde317d9",
+            "a3e4286cfb4ddd4f973d5b93e15d7fba2f6c690daecc09",
+        ),
+        (
+            "c3be64fbd3d0059cd5a68b0526c7e1d04ede3e67d76715bc107d2d752cdfde9c3e141d417324c91e468d7fa11dd1a3a8",
+            "bbe0ce6859fd840ebea27897729c4228",
+            ["d049632ef4cb9fa7be47", "", "0b7bb890a0fadf9f77200047b4e261"],
+            16,
+            "50812349bce817b9f266bf6ed66f2f",
+            "177e5471d3605260a2f1cab2b1d337e83eed867df63460e56806a9e516d928",
+        ),
+        (
+            "42179784686ecaba5d7f83e2a61d1c309a9a956dd73ac7ec90e55ba5b720b5732f63d5b0ea10e7685ea19c81d7d3177e",
+            "ce2f5d48730ee39ca4237b3066632b27",
+            ["2172b558698675f11e17", "", "ae417e99a4c211bbf94a866932fbc5"],
+            0,
+            "c9f603129f8348e9d23860ff374a309f",
+            "e79a7b58c8444e2841de4c08184a59ef",
+        ),
+        (
+            "1d7d29ea833c89e7559bb7e8cedc0aabba082fdfa5ec944fed73adb1bfa224eefa1cd1ab5b5e195c32aa5dc9733775f0",
+            "a8f70688e45f1ca3db4e8084970fedfa",
+            ["aa825c6c56ab4a759a39", "", "8ed2fe1c4a49750a3443ae80646c9f"],
+            0,
+            "055188d06c2cac0bdba86615e35c04ea227f003a3578baa74662daecc0fd4e",
+            "8be3f77a2caf031f039c57076e65309db48878ea39cce912cf8a86b827fb51",
+        ),
+    ];
+
+    #[test]
+    fn test_reference_vectors() {
+        for (key, nonce, ad, tau, plaintext, ciphertext) in VECTORS {
+            let ad: Vec<Vec<u8>> = ad.iter().map(|item| hex::decode(item).unwrap()).collect();
+            let ad: Vec<&[u8]> = ad.iter().map(Vec::as_slice).collect();
+            let decrypted = decrypt(
+                &hex::decode(key).unwrap(),
+                &hex::decode(nonce).unwrap(),
+                &ad,
+                tau,
+                &hex::decode(ciphertext).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(hex::encode(&decrypted[..]), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_rejects_modified_ciphertext() {
+        let (key, nonce, ad, tau, _, ciphertext) = VECTORS[2];
+        let ad: Vec<Vec<u8>> = ad.iter().map(|item| hex::decode(item).unwrap()).collect();
+        let ad: Vec<&[u8]> = ad.iter().map(Vec::as_slice).collect();
+        let key = hex::decode(key).unwrap();
+        let nonce = hex::decode(nonce).unwrap();
+
+        let mut ciphertext = hex::decode(ciphertext).unwrap();
+        assert!(decrypt(&key, &nonce, &ad[..1], tau, &ciphertext).is_none());
+        assert!(decrypt(&key, &[], &ad, tau, &ciphertext).is_none());
+        ciphertext[0] ^= 1;
+        assert!(decrypt(&key, &nonce, &ad, tau, &ciphertext).is_none());
+    }
+}
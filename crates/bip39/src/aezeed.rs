@@ -0,0 +1,450 @@
+//! LND aezeed cipher seeds.
+//!
+//! Lightning nodes running LND back up their wallet as a 24-word "aezeed"
+//! rather than a BIP39 mnemonic. The words use the English BIP39 wordlist but
+//! carry a different 33-byte payload:
+//!
+//! | Bytes   | Field                                                  |
+//! |---------|--------------------------------------------------------|
+//! | 0       | External version (0 is the only version defined)       |
+//! | 1..24   | AEZ ciphertext of the birthday and 16 bytes of entropy  |
+//! | 24..29  | scrypt salt                                            |
+//! | 29..33  | CRC-32C of bytes 0..29, big-endian                     |
+//!
+//! [`AezeedCipherSeed`] decodes the words, verifies the checksum and exposes
+//! the version, salt and ciphertext, so an aezeed backup can be recognized
+//! and reported as such instead of failing as an invalid BIP39 phrase.
+//! [`AezeedCipherSeed::decipher`] derives the key with scrypt and decrypts the
+//! ciphertext with AEZ into an [`AezeedEntropy`]: the wallet birthday and the
+//! 16 bytes of entropy LND derives its wallet from.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{AezeedCipherSeed, Mnemonic};
+//!
+//! let phrase = "ability liquid travel stem barely drastic pact cupboard apple thrive \
+//!               morning oak feature tissue couch old math inform success suggest drink \
+//!               motion know royal";
+//! assert!(Mnemonic::from_phrase(phrase, khodpay_bip39::Language::English).is_err());
+//!
+//! let seed = AezeedCipherSeed::from_phrase(phrase)?;
+//! assert_eq!(seed.version(), 0);
+//! assert_eq!(seed.salt(), b"salt1");
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+use zeroize::Zeroizing;
+
+use crate::aez;
+use crate::codec::BITS_PER_WORD;
+use crate::utils::constant_time_eq;
+use crate::{Error, Language, Result};
+
+/// Number of words in an aezeed phrase.
+const WORD_COUNT: usize = 24;
+
+/// Size of the decoded payload in bytes.
+const PAYLOAD_SIZE: usize = WORD_COUNT * BITS_PER_WORD / 8;
+
+/// The only external version LND defines.
+const SUPPORTED_VERSION: u8 = 0;
+
+/// Offset of the scrypt salt in the payload.
+const SALT_OFFSET: usize = 24;
+
+/// Offset of the checksum in the payload.
+const CHECKSUM_OFFSET: usize = 29;
+
+/// The only internal (plaintext) version LND defines.
+const SUPPORTED_INTERNAL_VERSION: u8 = 0;
+
+/// Passphrase LND substitutes when none was set.
+const DEFAULT_PASSPHRASE: &str = "aezeed";
+
+/// scrypt cost parameters of the cipher seed key: N = 2^15, r = 8, p = 1.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Size of the scrypt-derived AEZ key in bytes.
+const KEY_SIZE: usize = 32;
+
+/// Bytes of zero padding AEZ authenticates the plaintext with.
+const TAG_SIZE: usize = 4;
+
+/// Reflected CRC-32C (Castagnoli) polynomial.
+const CRC32C_POLYNOMIAL: u32 = 0x82F6_3B78;
+
+/// A checksum-verified LND aezeed phrase.
+///
+/// The ciphertext is zeroized on drop and redacted from `Debug` output: with
+/// the default passphrase it protects nothing but the scrypt work factor.
+#[derive(Clone)]
+pub struct AezeedCipherSeed {
+    payload: Zeroizing<[u8; PAYLOAD_SIZE]>,
+}
+
+impl AezeedCipherSeed {
+    /// Decodes and verifies an aezeed phrase.
+    ///
+    /// # Arguments
+    ///
+    /// * `phrase` - 24 words from the English BIP39 wordlist, separated by
+    ///   whitespace; case is ignored as in [`Language::index_of`]
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidAezeed`] - If the phrase does not have 24 words, its
+    ///   checksum does not match or its version is not supported
+    /// * [`Error::InvalidWord`] - If a word is not in the English wordlist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::AezeedCipherSeed;
+    ///
+    /// let phrase = "able tree stool crush transfer cloud cross three profit outside hen \
+    ///               citizen plate ride require leg siren drum success suggest drink \
+    ///               require fiscal upgrade";
+    /// assert!(AezeedCipherSeed::from_phrase(phrase).is_ok());
+    ///
+    /// let bip39 = "abandon abandon abandon abandon abandon abandon abandon abandon \
+    ///              abandon abandon abandon abandon abandon abandon abandon abandon \
+    ///              abandon abandon abandon abandon abandon abandon abandon art";
+    /// assert!(AezeedCipherSeed::from_phrase(bip39).is_err());
+    /// ```
+    pub fn from_phrase(phrase: &str) -> Result<Self> {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        if words.len() != WORD_COUNT {
+            return Err(Error::InvalidAezeed {
+                reason: format!("Expected {WORD_COUNT} words, found {}", words.len()),
+            });
+        }
+
+        let mut payload = Zeroizing::new([0u8; PAYLOAD_SIZE]);
+        let mut offset = 0;
+        for (position, &word) in words.iter().enumerate() {
+            let index = Language::English
+                .index_of(word)
+                .ok_or_else(|| Error::InvalidWord {
+                    word: word.into(),
+                    position,
+                })?;
+            for bit in (0..BITS_PER_WORD).rev() {
+                if (index >> bit) & 1 == 1 {
+                    payload[offset / 8] |= 0x80 >> (offset % 8);
+                }
+                offset += 1;
+            }
+        }
+
+        let checksum = crc32c(&payload[..CHECKSUM_OFFSET]).to_be_bytes();
+        if !constant_time_eq(&checksum, &payload[CHECKSUM_OFFSET..]) {
+            return Err(Error::InvalidAezeed {
+                reason: "Checksum mismatch".to_string(),
+            });
+        }
+        if payload[0] != SUPPORTED_VERSION {
+            return Err(Error::InvalidAezeed {
+                reason: format!("Unsupported aezeed version {}", payload[0]),
+            });
+        }
+
+        Ok(Self { payload })
+    }
+
+    /// Returns the external version byte.
+    pub fn version(&self) -> u8 {
+        self.payload[0]
+    }
+
+    /// Returns the 5-byte scrypt salt.
+    pub fn salt(&self) -> &[u8; 5] {
+        self.payload[SALT_OFFSET..CHECKSUM_OFFSET]
+            .try_into()
+            .expect("salt is 5 bytes")
+    }
+
+    /// Returns the 23-byte AEZ ciphertext.
+    ///
+    /// It decrypts, with the scrypt key of the passphrase (`"aezeed"` when
+    /// none was set) and the version byte followed by the salt as associated
+    /// data, to an internal version byte, the wallet birthday in days since
+    /// the Bitcoin genesis block and 16 bytes of entropy.
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.payload[1..SALT_OFFSET]
+    }
+
+    /// Decrypts the seed with its passphrase.
+    ///
+    /// The AEZ key is scrypt(passphrase, salt, N = 32768, r = 8, p = 1); an
+    /// empty passphrase stands for LND's default `"aezeed"`. The passphrase
+    /// bytes are used as given, without Unicode normalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The passphrase chosen when the seed was created, or
+    ///   `""` if none was
+    ///
+    /// # Returns
+    ///
+    /// The wallet birthday and entropy
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidAezeed`] - If the passphrase is wrong or the
+    ///   decrypted internal version is not supported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::AezeedCipherSeed;
+    ///
+    /// let phrase = "abandon region unit donor clutch possible dynamic quote possible \
+    ///               tourist demand firm wink renew kick garment citizen opera sure tell \
+    ///               drink table kitten easy";
+    /// let seed = AezeedCipherSeed::from_phrase(phrase)?;
+    ///
+    /// let entropy = seed.decipher("")?;
+    /// assert_eq!(entropy.birthday(), 6000);
+    /// assert!(seed.decipher("wrong passphrase").is_err());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn decipher(&self, passphrase: &str) -> Result<AezeedEntropy> {
+        self.decipher_with_cost(passphrase, SCRYPT_LOG_N)
+    }
+
+    /// Decrypts the seed with a scrypt cost of 2^`log_n`.
+    ///
+    /// LND's published test vectors are generated with N = 16.
+    fn decipher_with_cost(&self, passphrase: &str, log_n: u8) -> Result<AezeedEntropy> {
+        let passphrase = if passphrase.is_empty() {
+            DEFAULT_PASSPHRASE
+        } else {
+            passphrase
+        };
+        let params = scrypt::Params::new(log_n, SCRYPT_R, SCRYPT_P, KEY_SIZE)
+            .expect("aezeed scrypt parameters are valid");
+        let mut key = Zeroizing::new([0u8; KEY_SIZE]);
+        scrypt::scrypt(passphrase.as_bytes(), self.salt(), &params, key.as_mut())
+            .expect("key size is valid");
+
+        let mut ad = [0u8; 1 + 5];
+        ad[0] = self.version();
+        ad[1..].copy_from_slice(self.salt());
+        let plaintext = aez::decrypt(&key[..], &[], &[&ad], TAG_SIZE, self.ciphertext())
+            .ok_or_else(|| Error::InvalidAezeed {
+                reason: "Wrong passphrase".to_string(),
+            })?;
+
+        if plaintext[0] != SUPPORTED_INTERNAL_VERSION {
+            return Err(Error::InvalidAezeed {
+                reason: format!("Unsupported internal version {}", plaintext[0]),
+            });
+        }
+        let mut entropy = Zeroizing::new([0u8; 16]);
+        entropy.copy_from_slice(&plaintext[3..]);
+        Ok(AezeedEntropy {
+            birthday: u16::from_be_bytes([plaintext[1], plaintext[2]]),
+            entropy,
+        })
+    }
+}
+
+/// Compares the payloads in constant time.
+impl PartialEq for AezeedCipherSeed {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(&self.payload[..], &other.payload[..])
+    }
+}
+
+impl Eq for AezeedCipherSeed {}
+
+impl fmt::Debug for AezeedCipherSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AezeedCipherSeed")
+            .field("version", &self.version())
+            .field("salt", self.salt())
+            .field("ciphertext", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// The decrypted contents of an [`AezeedCipherSeed`].
+///
+/// The entropy is zeroized on drop and redacted from `Debug` output.
+#[derive(Clone)]
+pub struct AezeedEntropy {
+    birthday: u16,
+    entropy: Zeroizing<[u8; 16]>,
+}
+
+impl AezeedEntropy {
+    /// Returns the wallet birthday in days since the Bitcoin genesis block
+    /// (2009-01-03), from which a rescan for the wallet's transactions can
+    /// start.
+    pub fn birthday(&self) -> u16 {
+        self.birthday
+    }
+
+    /// Returns the 16 bytes of entropy LND uses as the wallet's BIP32 seed.
+    pub fn entropy(&self) -> &[u8; 16] {
+        &self.entropy
+    }
+}
+
+impl fmt::Debug for AezeedEntropy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AezeedEntropy")
+            .field("birthday", &self.birthday)
+            .field("entropy", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Computes the CRC-32C (Castagnoli) checksum used by aezeed.
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (CRC32C_POLYNOMIAL & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// LND's version 0 test vectors: the default passphrase at the genesis
+    /// birthday, and `!very_safe_55345_password*` at birthday 3365.
+    const VECTORS: [&str; 2] = [
+        "ability liquid travel stem barely drastic pact cupboard apple thrive morning oak \
+         feature tissue couch old math inform success suggest drink motion know royal",
+        "able tree stool crush transfer cloud cross three profit outside hen citizen plate \
+         ride require leg siren drum success suggest drink require fiscal upgrade",
+    ];
+
+    #[test]
+    fn test_crc32c() {
+        // RFC 3720, B.4
+        assert_eq!(crc32c(&[0u8; 32]), 0x8a91_36aa);
+        assert_eq!(crc32c(&[0xffu8; 32]), 0x62a8_ab43);
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    #[test]
+    fn test_lnd_vectors() {
+        for phrase in VECTORS {
+            let seed = AezeedCipherSeed::from_phrase(phrase).unwrap();
+            assert_eq!(seed.version(), 0);
+            assert_eq!(seed.salt(), b"salt1");
+            assert_eq!(seed.ciphertext().len(), 23);
+        }
+
+        let first = AezeedCipherSeed::from_phrase(VECTORS[0]).unwrap();
+        let second = AezeedCipherSeed::from_phrase(VECTORS[1]).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(
+            first,
+            AezeedCipherSeed::from_phrase(&VECTORS[0].to_uppercase()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decipher_lnd_vectors() {
+        let entropy = hex::decode("81b637d86359e6960de795e41e0b4cfd").unwrap();
+
+        let seed = AezeedCipherSeed::from_phrase(VECTORS[0]).unwrap();
+        let deciphered = seed.decipher_with_cost("", 4).unwrap();
+        assert_eq!(deciphered.birthday(), 0);
+        assert_eq!(deciphered.entropy()[..], entropy[..]);
+        assert_eq!(
+            seed.decipher_with_cost("aezeed", 4).unwrap().entropy(),
+            deciphered.entropy()
+        );
+
+        let seed = AezeedCipherSeed::from_phrase(VECTORS[1]).unwrap();
+        let deciphered = seed
+            .decipher_with_cost("!very_safe_55345_password*", 4)
+            .unwrap();
+        assert_eq!(deciphered.birthday(), 3365);
+        assert_eq!(deciphered.entropy()[..], entropy[..]);
+    }
+
+    #[test]
+    fn test_decipher_wrong_passphrase() {
+        let seed = AezeedCipherSeed::from_phrase(VECTORS[1]).unwrap();
+        assert_eq!(
+            seed.decipher_with_cost("", 4).unwrap_err(),
+            Error::InvalidAezeed {
+                reason: "Wrong passphrase".to_string()
+            }
+        );
+        // The right passphrase at the wrong scrypt cost derives another key.
+        assert!(seed
+            .decipher_with_cost("!very_safe_55345_password*", 5)
+            .is_err());
+    }
+
+    #[test]
+    fn test_decipher_default_cost() {
+        let phrase = "abandon region unit donor clutch possible dynamic quote possible tourist \
+                      demand firm wink renew kick garment citizen opera sure tell drink table \
+                      kitten easy";
+        let deciphered = AezeedCipherSeed::from_phrase(phrase)
+            .unwrap()
+            .decipher("")
+            .unwrap();
+        assert_eq!(deciphered.birthday(), 6000);
+        assert_eq!(
+            hex::encode(deciphered.entropy()),
+            "81b637d86359e6960de795e41e0b4cfd"
+        );
+        assert!(!format!("{deciphered:?}").contains("81b6"));
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let tampered = VECTORS[0].replacen("ability", "able", 1);
+        assert_eq!(
+            AezeedCipherSeed::from_phrase(&tampered),
+            Err(Error::InvalidAezeed {
+                reason: "Checksum mismatch".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_phrases() {
+        assert!(matches!(
+            AezeedCipherSeed::from_phrase("ability liquid travel"),
+            Err(Error::InvalidAezeed { .. })
+        ));
+        let unknown = VECTORS[0].replacen("stem", "lightning", 1);
+        assert_eq!(
+            AezeedCipherSeed::from_phrase(&unknown),
+            Err(Error::InvalidWord {
+                word: "lightning".into(),
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_debug_redacts_ciphertext() {
+        let seed = AezeedCipherSeed::from_phrase(VECTORS[0]).unwrap();
+        let debug = format!("{seed:?}");
+        assert!(debug.contains("REDACTED"));
+        assert!(!debug.contains(&format!("{:?}", seed.ciphertext())));
+    }
+}
@@ -0,0 +1,216 @@
+//! Conversion between entropy and BIP39 phrases.
+//!
+//! A phrase encodes the entropy followed by the first `entropy_bits / 32` bits
+//! of its SHA-256 hash, split into 11-bit indices into the wordlist. This is
+//! the core of the BIP39 engine; [`Mnemonic`](crate::Mnemonic) and the phrase
+//! validation functions are built on it.
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::{Error, Language, Result, WordCount};
+
+/// Number of bits encoded by each word.
+pub(crate) const BITS_PER_WORD: usize = 11;
+
+/// Encodes `entropy` as a phrase in `language`, with words separated by
+/// [`Language::word_separator`].
+///
+/// Fails with [`Error::InvalidEntropyLength`] unless the entropy is 16, 20,
+/// 24, 28 or 32 bytes long.
+pub(crate) fn entropy_to_phrase(entropy: &[u8], language: &Language) -> Result<Zeroizing<String>> {
+    let word_list = language.word_list();
+    let separator = language.word_separator();
+    let mut phrase = Zeroizing::new(String::new());
+    for (position, &index) in entropy_to_indices(entropy)?.iter().enumerate() {
+        if position > 0 {
+            phrase.push_str(separator);
+        }
+        phrase.push_str(word_list[usize::from(index)]);
+    }
+    Ok(phrase)
+}
+
+/// Splits `entropy` and its checksum into the 11-bit wordlist indices of its
+/// phrase.
+///
+/// Fails with [`Error::InvalidEntropyLength`] unless the entropy is 16, 20,
+/// 24, 28 or 32 bytes long.
+pub(crate) fn entropy_to_indices(entropy: &[u8]) -> Result<Zeroizing<Vec<u16>>> {
+    let word_count = WordCount::from_entropy_length(entropy.len())?;
+    // At most 8 checksum bits are used, so the first hash byte is enough.
+    let checksum = Sha256::digest(entropy)[0];
+    let bit_at = |position: usize| {
+        let byte = entropy.get(position / 8).copied().unwrap_or(checksum);
+        u16::from((byte >> (7 - position % 8)) & 1)
+    };
+
+    let indices = (0..word_count.word_count())
+        .map(|word| {
+            (0..BITS_PER_WORD).fold(0, |index, bit| {
+                (index << 1) | bit_at(word * BITS_PER_WORD + bit)
+            })
+        })
+        .collect();
+    Ok(Zeroizing::new(indices))
+}
+
+/// Decodes a phrase in `language` back to its entropy, verifying the checksum.
+///
+/// Words may be separated by any whitespace, including the ideographic space,
+/// and are matched case-insensitively after NFKD normalization.
+/// Errors are reported in the order a user would fix them: an empty phrase,
+/// then the word count, then the first unknown word, then the checksum.
+pub(crate) fn phrase_to_entropy(phrase: &str, language: &Language) -> Result<Zeroizing<Vec<u8>>> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    if words.is_empty() {
+        return Err(Error::InvalidMnemonic {
+            reason: "Empty phrase".to_string(),
+        });
+    }
+    let word_count = WordCount::from_word_count(words.len())?;
+
+    let mut bits = Zeroizing::new(vec![0u8; (words.len() * BITS_PER_WORD).div_ceil(8)]);
+    for (position, word) in words.iter().enumerate() {
+        let index = language.index_of(word).ok_or_else(|| Error::InvalidWord {
+            word: word.to_string(),
+            position,
+        })?;
+        for bit in 0..BITS_PER_WORD {
+            if (index >> (BITS_PER_WORD - 1 - bit)) & 1 == 1 {
+                let offset = position * BITS_PER_WORD + bit;
+                bits[offset / 8] |= 0x80 >> (offset % 8);
+            }
+        }
+    }
+
+    let entropy_length = word_count.entropy_length();
+    let entropy = Zeroizing::new(bits[..entropy_length].to_vec());
+    let unused_bits = 8 - word_count.checksum_bits();
+    let expected = Sha256::digest(&entropy[..])[0] >> unused_bits;
+    if bits[entropy_length] >> unused_bits != expected {
+        return Err(Error::InvalidChecksum);
+    }
+    Ok(entropy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_normalization::UnicodeNormalization;
+
+    #[test]
+    fn test_round_trip_all_lengths_and_languages() {
+        for language in Language::all_variants() {
+            for &word_count in WordCount::all_variants() {
+                let entropy: Vec<u8> = (0..word_count.entropy_length() as u8)
+                    .map(|i| i.wrapping_mul(37).wrapping_add(11))
+                    .collect();
+                let phrase = entropy_to_phrase(&entropy, language).unwrap();
+                assert_eq!(
+                    phrase.split(language.word_separator()).count(),
+                    word_count.word_count()
+                );
+                assert_eq!(*phrase_to_entropy(&phrase, language).unwrap(), entropy);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_upstream_crate() {
+        for language in Language::all_variants() {
+            let upstream_language = language.to_upstream();
+            for seed in 0..8u8 {
+                let entropy: Vec<u8> = (0..32u8)
+                    .map(|i| i.wrapping_mul(seed.wrapping_add(3)) ^ seed)
+                    .collect();
+                let upstream =
+                    bip39_upstream::Mnemonic::from_entropy_in(upstream_language, &entropy).unwrap();
+                // Upstream joins Japanese words with an ASCII space
+                let phrase = entropy_to_phrase(&entropy, language).unwrap();
+                assert_eq!(
+                    phrase.replace(language.word_separator(), " "),
+                    upstream.to_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_vector() {
+        let entropy = hex::decode("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f").unwrap();
+        let phrase = entropy_to_phrase(&entropy, &Language::English).unwrap();
+        assert_eq!(
+            phrase.as_str(),
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        );
+    }
+
+    #[test]
+    fn test_invalid_entropy_length() {
+        assert!(matches!(
+            entropy_to_phrase(&[0u8; 15], &Language::English),
+            Err(Error::InvalidEntropyLength { length: 15 })
+        ));
+    }
+
+    #[test]
+    fn test_error_order() {
+        assert!(matches!(
+            phrase_to_entropy("  ", &Language::English),
+            Err(Error::InvalidMnemonic { .. })
+        ));
+        assert!(matches!(
+            phrase_to_entropy("abandon abandon", &Language::English),
+            Err(Error::InvalidWordCount { .. })
+        ));
+        let unknown = "abandon abandon abandon bitcoin abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(matches!(
+            phrase_to_entropy(unknown, &Language::English),
+            Err(Error::InvalidWord { position: 3, .. })
+        ));
+        let bad_checksum = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(matches!(
+            phrase_to_entropy(bad_checksum, &Language::English),
+            Err(Error::InvalidChecksum)
+        ));
+    }
+
+    #[test]
+    fn test_japanese_separator() {
+        let phrase = entropy_to_phrase(&[0u8; 16], &Language::Japanese).unwrap();
+        assert_eq!(phrase.split('\u{3000}').count(), 12);
+        assert!(!phrase.contains(' '));
+        let ascii = phrase.replace('\u{3000}', " ");
+        assert_eq!(
+            *phrase_to_entropy(&ascii, &Language::Japanese).unwrap(),
+            vec![0u8; 16]
+        );
+    }
+
+    #[test]
+    fn test_composed_input() {
+        // Accented words typed with precomposed letters, as most keyboards produce them
+        let entropy = [0x4d; 16];
+        let phrase = entropy_to_phrase(&entropy, &Language::French).unwrap();
+        let composed: String = phrase.nfc().collect();
+        assert_ne!(composed, *phrase);
+        assert_eq!(
+            *phrase_to_entropy(&composed, &Language::French).unwrap(),
+            entropy
+        );
+    }
+
+    #[test]
+    fn test_case_and_whitespace_insensitive() {
+        let phrase = "  ABANDON abandon\tabandon abandon abandon abandon abandon abandon abandon abandon abandon About ";
+        assert_eq!(
+            *phrase_to_entropy(phrase, &Language::English).unwrap(),
+            vec![0u8; 16]
+        );
+    }
+}
@@ -0,0 +1,781 @@
+//! Codex32 (BIP-93) secret shares for seed entropy.
+//!
+//! [BIP-93] encodes a master seed as a bech32-style string with a strong BCH
+//! checksum that can be verified, and shares that can be split and recombined,
+//! with paper worksheets and no computer. This module implements encoding,
+//! checksum verification, k-of-n share generation and recovery for seeds of
+//! 128 to 256 bits.
+//!
+//! A codex32 string consists of:
+//! - the human-readable part `ms` and separator `1`
+//! - the threshold `k` (`0` for an unshared secret, or `2`-`9`)
+//! - a four-character identifier shared by all shares of one secret
+//! - the share index (`s` for the secret itself)
+//! - the payload and a 13-character checksum
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{codex32_combine, codex32_split, Codex32String};
+//!
+//! let seed = [0x42u8; 16];
+//! let shares = codex32_split(&seed, "cash", 2, 3)?;
+//! assert_eq!(shares.len(), 3);
+//!
+//! let secret = codex32_combine(&shares[1..])?;
+//! assert_eq!(secret.payload(), seed);
+//! assert_eq!(secret.share_index(), 's');
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+//!
+//! [BIP-93]: https://github.com/bitcoin/bips/blob/master/bip-0093.mediawiki
+
+use alloc::format;
+use alloc::string::{String, ToString};
+#[cfg(feature = "rand")]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "rand")]
+use rand::rngs::OsRng;
+#[cfg(feature = "rand")]
+use rand::RngCore;
+use zeroize::Zeroizing;
+
+use crate::utils::constant_time_eq;
+use crate::{Error, Language, Mnemonic, Result};
+
+/// The bech32 character set, indexed by 5-bit value.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Human-readable part of every codex32 string.
+const HRP: &str = "ms";
+
+/// Number of checksum characters in a short codex32 string.
+const CHECKSUM_LENGTH: usize = 13;
+
+/// Number of header characters: threshold, identifier and share index.
+const HEADER_LENGTH: usize = 6;
+
+/// Maximum data part length covered by the short checksum.
+const MAX_DATA_LENGTH: usize = 93;
+
+/// Residue constant of a valid short checksum.
+const MS32_CONST: u128 = 0x10ce0795c2fd1e62a;
+
+/// The 5-bit value of the secret share index `s`.
+const SECRET_INDEX: u8 = 16;
+
+/// Share indices in the order they are assigned, skipping the secret index `s`.
+#[cfg(feature = "rand")]
+const SHARE_INDICES: &[u8] = b"acdefghjklmnpqrtuvwxyz023456789";
+
+/// A parsed and checksum-verified codex32 string.
+///
+/// This is either the secret itself (share index `s`) or one share of it.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::Codex32String;
+///
+/// let secret: Codex32String = "ms10testsxxxxxxxxxxxxxxxxxxxxxxxxxx4nzvca9cmczlw".parse()?;
+/// assert_eq!(secret.threshold(), 0);
+/// assert_eq!(secret.identifier(), "test");
+/// assert_eq!(secret.payload(), [0x31, 0x8c, 0x63, 0x18, 0xc6, 0x31, 0x8c, 0x63,
+///                               0x18, 0xc6, 0x31, 0x8c, 0x63, 0x18, 0xc6, 0x31]);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct Codex32String {
+    /// The 5-bit values of the data part, including header and checksum.
+    data: Zeroizing<Vec<u8>>,
+}
+
+impl Codex32String {
+    /// Encodes a seed as an unshared codex32 secret (`k = 0`, index `s`).
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed entropy, 16 to 32 bytes
+    /// * `identifier` - Four bech32 characters naming the secret
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidEntropyLength`] - If the seed is shorter than 16 or longer than 32 bytes
+    /// * [`Error::InvalidShare`] - If the identifier is not four bech32 characters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::Codex32String;
+    ///
+    /// let seed = [0x31, 0x8c, 0x63, 0x18, 0xc6, 0x31, 0x8c, 0x63,
+    ///             0x18, 0xc6, 0x31, 0x8c, 0x63, 0x18, 0xc6, 0x31];
+    /// let secret = Codex32String::from_seed(&seed, "test")?;
+    /// assert!(secret.to_string().starts_with("ms10tests"));
+    /// assert_eq!(secret.payload(), seed);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn from_seed(seed: &[u8], identifier: &str) -> Result<Self> {
+        Self::from_parts(b'0', identifier, b's', seed)
+    }
+
+    /// Builds a codex32 string from its header fields and payload bytes.
+    fn from_parts(
+        threshold: u8,
+        identifier: &str,
+        share_index: u8,
+        payload: &[u8],
+    ) -> Result<Self> {
+        if !(16..=32).contains(&payload.len()) {
+            return Err(Error::InvalidEntropyLength {
+                length: payload.len(),
+            });
+        }
+
+        let identifier = identifier.to_lowercase();
+        if identifier.len() != 4 {
+            return Err(invalid_share(
+                "Identifier must be exactly 4 characters".to_string(),
+            ));
+        }
+
+        let mut data =
+            Vec::with_capacity(HEADER_LENGTH + payload.len() * 8 / 5 + 1 + CHECKSUM_LENGTH);
+        for c in core::iter::once(threshold)
+            .chain(identifier.bytes())
+            .chain(core::iter::once(share_index))
+        {
+            data.push(char_value(c).ok_or_else(|| {
+                invalid_share(format!("Invalid bech32 character '{}'", c as char))
+            })?);
+        }
+        data.extend(bytes_to_fes(payload));
+        data.extend(create_checksum(&data));
+        Ok(Self {
+            data: Zeroizing::new(data),
+        })
+    }
+
+    /// Returns the threshold `k`, or 0 for an unshared secret.
+    pub fn threshold(&self) -> usize {
+        match CHARSET[self.data[0] as usize] {
+            b'0' => 0,
+            c => (c - b'0') as usize,
+        }
+    }
+
+    /// Returns the four-character identifier in lowercase.
+    pub fn identifier(&self) -> String {
+        self.data[1..5]
+            .iter()
+            .map(|&fe| CHARSET[fe as usize] as char)
+            .collect()
+    }
+
+    /// Returns the share index, `'s'` for the secret itself.
+    pub fn share_index(&self) -> char {
+        CHARSET[self.data[5] as usize] as char
+    }
+
+    /// Returns whether this string is the secret rather than a share of it.
+    pub fn is_secret(&self) -> bool {
+        self.data[5] == SECRET_INDEX
+    }
+
+    /// Returns the payload bytes; for the secret this is the master seed.
+    ///
+    /// Trailing padding bits are discarded.
+    pub fn payload(&self) -> Vec<u8> {
+        let payload = &self.data[HEADER_LENGTH..self.data.len() - CHECKSUM_LENGTH];
+        let mut bytes = Vec::with_capacity(payload.len() * 5 / 8);
+        let mut acc: u32 = 0;
+        let mut bits = 0;
+        for &fe in payload {
+            acc = (acc << 5) | fe as u32;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((acc >> bits) as u8);
+                acc &= (1 << bits) - 1;
+            }
+        }
+        bytes
+    }
+}
+
+impl FromStr for Codex32String {
+    type Err = Error;
+
+    /// Parses a codex32 string and verifies its checksum.
+    ///
+    /// Upper- and lowercase strings are accepted, but not a mix of both.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let has_lower = s.bytes().any(|b| b.is_ascii_lowercase());
+        let has_upper = s.bytes().any(|b| b.is_ascii_uppercase());
+        if has_lower && has_upper {
+            return Err(invalid_share("Mixed-case codex32 string".to_string()));
+        }
+
+        let s = s.to_lowercase();
+        let data_part = s
+            .strip_prefix(HRP)
+            .and_then(|rest| rest.strip_prefix('1'))
+            .ok_or_else(|| invalid_share("Codex32 string must start with \"ms1\"".to_string()))?;
+
+        let min_length = HEADER_LENGTH + 16 * 8 / 5 + CHECKSUM_LENGTH;
+        if data_part.len() < min_length || data_part.len() > MAX_DATA_LENGTH {
+            return Err(invalid_share(format!(
+                "Invalid codex32 string length: {}",
+                s.len()
+            )));
+        }
+
+        let data = data_part
+            .bytes()
+            .enumerate()
+            .map(|(position, c)| {
+                char_value(c).ok_or_else(|| Error::InvalidWord {
+                    word: (c as char).to_string(),
+                    position: position + HRP.len() + 1,
+                })
+            })
+            .collect::<Result<Vec<u8>>>()
+            .map(Zeroizing::new)?;
+
+        if polymod(&data) != MS32_CONST {
+            return Err(invalid_share("Invalid codex32 checksum".to_string()));
+        }
+
+        let threshold = CHARSET[data[0] as usize];
+        if !(threshold == b'0' || (b'2'..=b'9').contains(&threshold)) {
+            return Err(invalid_share(format!(
+                "Invalid threshold '{}'",
+                threshold as char
+            )));
+        }
+        if threshold == b'0' && data[5] != SECRET_INDEX {
+            return Err(invalid_share(
+                "Threshold 0 requires share index 's'".to_string(),
+            ));
+        }
+
+        let payload_bits = (data.len() - HEADER_LENGTH - CHECKSUM_LENGTH) * 5;
+        if payload_bits % 8 > 4 {
+            return Err(invalid_share("Invalid codex32 padding length".to_string()));
+        }
+
+        Ok(Self { data })
+    }
+}
+
+impl fmt::Display for Codex32String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}1", HRP)?;
+        for &fe in self.data.iter() {
+            write!(f, "{}", CHARSET[fe as usize] as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the data parts in constant time.
+impl PartialEq for Codex32String {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(&self.data, &other.data)
+    }
+}
+
+impl Eq for Codex32String {}
+
+impl fmt::Debug for Codex32String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Codex32String")
+            .field("threshold", &self.threshold())
+            .field("identifier", &self.identifier())
+            .field("share_index", &self.share_index())
+            .field("payload", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Splits a seed into `share_count` codex32 shares, any `threshold` of which recover it.
+///
+/// Following BIP-93, `threshold - 1` shares are chosen at random and the
+/// remaining shares are derived by interpolation through those and the secret.
+///
+/// # Arguments
+///
+/// * `seed` - Seed entropy, 16 to 32 bytes
+/// * `identifier` - Four bech32 characters shared by all shares
+/// * `threshold` - Number of shares required for recovery, 2 to 9
+/// * `share_count` - Number of shares to generate, `threshold` to 31
+///
+/// # Errors
+///
+/// * [`Error::InvalidEntropyLength`] - If the seed length is unsupported
+/// * [`Error::InvalidShare`] - If the identifier, threshold or share count is invalid
+#[cfg(feature = "rand")]
+pub fn codex32_split(
+    seed: &[u8],
+    identifier: &str,
+    threshold: usize,
+    share_count: usize,
+) -> Result<Vec<Codex32String>> {
+    if !(2..=9).contains(&threshold) {
+        return Err(invalid_share(
+            "Threshold must be between 2 and 9".to_string(),
+        ));
+    }
+    if share_count < threshold || share_count > SHARE_INDICES.len() {
+        return Err(invalid_share(format!(
+            "Share count must be between the threshold and {}",
+            SHARE_INDICES.len()
+        )));
+    }
+
+    let k = b'0' + threshold as u8;
+    let secret = Codex32String::from_parts(k, identifier, b's', seed)?;
+
+    let mut base = Vec::with_capacity(threshold);
+    for &index in &SHARE_INDICES[..threshold - 1] {
+        let mut random = vec![0u8; seed.len()];
+        OsRng.fill_bytes(&mut random);
+        base.push(Codex32String::from_parts(k, identifier, index, &random)?);
+    }
+    base.push(secret);
+
+    let mut shares = base[..threshold - 1].to_vec();
+    for &index in &SHARE_INDICES[threshold - 1..share_count] {
+        shares.push(interpolate(&base, char_value(index).expect("valid index"))?);
+    }
+    Ok(shares)
+}
+
+/// Recovers the codex32 secret from at least `k` shares.
+///
+/// Shares are parsed with [`str::parse`], which verifies each checksum. If
+/// the secret itself is among them it is returned directly.
+///
+/// # Errors
+///
+/// * [`Error::InvalidShare`] - If the shares belong to different secrets or conflict
+/// * [`Error::InsufficientShares`] - If fewer than `k` distinct shares are given
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{codex32_combine, Codex32String};
+///
+/// let shares = [
+///     "MS12NAMEA320ZYXWVUTSRQPNMLKJHGFEDCAXRPP870HKKQRM".parse::<Codex32String>()?,
+///     "MS12NAMECACDEFGHJKLMNPQRSTUVWXYZ023FTR2GDZMPY6PN".parse::<Codex32String>()?,
+/// ];
+/// let secret = codex32_combine(&shares)?;
+/// assert_eq!(secret.to_string(), "ms12names6xqguzttxkeqnjsjzv4jv3nz5k3kwgsphuh6evw");
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+pub fn codex32_combine(shares: &[Codex32String]) -> Result<Codex32String> {
+    let first = shares.first().ok_or(Error::InsufficientShares {
+        required: 1,
+        provided: 0,
+    })?;
+
+    for share in shares {
+        if share.data[..5] != first.data[..5] || share.data.len() != first.data.len() {
+            return Err(invalid_share(
+                "Shares do not belong to the same secret".to_string(),
+            ));
+        }
+    }
+
+    if let Some(secret) = shares.iter().find(|share| share.is_secret()) {
+        return Ok(secret.clone());
+    }
+
+    let mut distinct: Vec<Codex32String> = Vec::new();
+    for share in shares.iter() {
+        match distinct.iter().find(|other| other.data[5] == share.data[5]) {
+            Some(other) if other != share => {
+                return Err(invalid_share(
+                    "Conflicting shares with the same index".to_string(),
+                ));
+            }
+            Some(_) => {}
+            None => distinct.push(share.clone()),
+        }
+    }
+
+    let threshold = first.threshold().max(1);
+    if distinct.len() < threshold {
+        return Err(Error::InsufficientShares {
+            required: threshold,
+            provided: distinct.len(),
+        });
+    }
+    distinct.truncate(threshold);
+
+    interpolate(&distinct, SECRET_INDEX)
+}
+
+impl Mnemonic {
+    /// Encodes this mnemonic's entropy as an unshared codex32 secret.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic};
+    ///
+    /// let mnemonic = Mnemonic::new(&[0u8; 16], Language::English)?;
+    /// let secret = mnemonic.to_codex32("test")?;
+    /// assert_eq!(secret.payload(), mnemonic.entropy());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn to_codex32(&self, identifier: &str) -> Result<Codex32String> {
+        Codex32String::from_seed(self.entropy(), identifier)
+    }
+
+    /// Creates a mnemonic from a codex32 secret of equal entropy.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidShare`] - If the string is a share rather than the secret
+    /// * [`Error::InvalidEntropyLength`] - If the payload is not a BIP39 entropy length
+    pub fn from_codex32(secret: &Codex32String, language: Language) -> Result<Self> {
+        if !secret.is_secret() {
+            return Err(invalid_share(format!(
+                "Expected the secret share 's', found share '{}'",
+                secret.share_index()
+            )));
+        }
+        Mnemonic::new(&secret.payload(), language)
+    }
+}
+
+/// Builds an [`Error::InvalidShare`] with the given reason.
+fn invalid_share(reason: String) -> Error {
+    Error::InvalidShare { reason }
+}
+
+/// Returns the 5-bit value of a lowercase bech32 character.
+fn char_value(c: u8) -> Option<u8> {
+    CHARSET.iter().position(|&x| x == c).map(|i| i as u8)
+}
+
+/// Packs bytes into 5-bit values, zero-padding the final value.
+fn bytes_to_fes(bytes: &[u8]) -> Vec<u8> {
+    let mut fes = Vec::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for &byte in bytes {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            fes.push(((acc >> bits) & 31) as u8);
+        }
+        acc &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        fes.push(((acc << (5 - bits)) & 31) as u8);
+    }
+    fes
+}
+
+/// Computes the BIP-93 short checksum residue over `values`.
+fn polymod(values: &[u8]) -> u128 {
+    const GEN: [u128; 5] = [
+        0x19dc500ce73fde210,
+        0x1bfae00def77fe529,
+        0x1fbd920fffe7bee52,
+        0x1739640bdeee3fdad,
+        0x07729a039cfc75f5a,
+    ];
+
+    let mut residue: u128 = 0x23181b3;
+    for &value in values {
+        let b = residue >> 60;
+        residue = ((residue & 0x0fff_ffff_ffff_ffff) << 5) ^ value as u128;
+        for (i, gen) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                residue ^= gen;
+            }
+        }
+    }
+    residue
+}
+
+/// Computes the 13 checksum values for `data`.
+fn create_checksum(data: &[u8]) -> Vec<u8> {
+    let mut values = data.to_vec();
+    values.extend([0; CHECKSUM_LENGTH]);
+    let residue = polymod(&values) ^ MS32_CONST;
+    (0..CHECKSUM_LENGTH)
+        .map(|i| ((residue >> (5 * (CHECKSUM_LENGTH - 1 - i))) & 31) as u8)
+        .collect()
+}
+
+/// Multiplies two elements of GF(32) as used by bech32.
+fn gf32_mul(mut a: u8, b: u8) -> u8 {
+    let mut result = 0;
+    for i in 0..5 {
+        if (b >> i) & 1 == 1 {
+            result ^= a;
+        }
+        a <<= 1;
+        if a >= 32 {
+            a ^= 41;
+        }
+    }
+    result
+}
+
+/// Returns the multiplicative inverse of a non-zero element of GF(32).
+fn gf32_inv(a: u8) -> u8 {
+    // a^30 = a^-1 in the multiplicative group of order 31.
+    let mut result = 1;
+    for _ in 0..30 {
+        result = gf32_mul(result, a);
+    }
+    result
+}
+
+/// Derives the share with index `x` from `shares` by Lagrange interpolation.
+fn interpolate(shares: &[Codex32String], x: u8) -> Result<Codex32String> {
+    let indices: Vec<u8> = shares.iter().map(|share| share.data[5]).collect();
+
+    let mut weights = Vec::with_capacity(shares.len());
+    let mut numerator = 1;
+    for (i, &xi) in indices.iter().enumerate() {
+        numerator = gf32_mul(numerator, xi ^ x);
+        let mut denominator = 1;
+        for (j, &xj) in indices.iter().enumerate() {
+            let term = if i == j { x } else { xi };
+            denominator = gf32_mul(denominator, term ^ xj);
+        }
+        weights.push(denominator);
+    }
+    if weights.contains(&0) {
+        return Err(invalid_share(
+            "Shares must have distinct indices".to_string(),
+        ));
+    }
+
+    let length = shares[0].data.len();
+    let data = (0..length)
+        .map(|position| {
+            shares
+                .iter()
+                .zip(&weights)
+                .fold(0, |acc, (share, &weight)| {
+                    let coefficient = gf32_mul(numerator, gf32_inv(weight));
+                    acc ^ gf32_mul(coefficient, share.data[position])
+                })
+        })
+        .collect();
+    Ok(Codex32String {
+        data: Zeroizing::new(data),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP-93 test vectors.
+    const VECTOR_1_SECRET: &str = "ms10testsxxxxxxxxxxxxxxxxxxxxxxxxxx4nzvca9cmczlw";
+    const VECTOR_1_SEED: &str = "318c6318c6318c6318c6318c6318c631";
+    const VECTOR_2_SHARES: [&str; 2] = [
+        "MS12NAMEA320ZYXWVUTSRQPNMLKJHGFEDCAXRPP870HKKQRM",
+        "MS12NAMECACDEFGHJKLMNPQRSTUVWXYZ023FTR2GDZMPY6PN",
+    ];
+    const VECTOR_2_SECRET: &str = "MS12NAMES6XQGUZTTXKEQNJSJZV4JV3NZ5K3KWGSPHUH6EVW";
+    const VECTOR_2_SEED: &str = "d1808e096b35b209ca12132b264662a5";
+    const VECTOR_3_SECRET: &str = "ms13cashsllhdmn9m42vcsamx24zrxgs3qqjzqud4m0d6nln";
+    const VECTOR_3_SHARES: [&str; 5] = [
+        "ms13casha320zyxwvutsrqpnmlkjhgfedca2a8d0zehn8a0t",
+        "ms13cashcacdefghjklmnpqrstuvwxyz023949xq35my48dr",
+        "ms13cashd0wsedstcdcts64cd7wvy4m90lm28w4ffupqs7rm",
+        "ms13casheekgpemxzshcrmqhaydlp6yhms3ws7320xyxsar9",
+        "ms13cashf8jh6sdrkpyrsp5ut94pj8ktehhw2hfvyrj48704",
+    ];
+    const VECTOR_3_SEED: &str = "ffeeddccbbaa99887766554433221100";
+    const VECTOR_4_SECRET: &str =
+        "ms10leetsllhdmn9m42vcsamx24zrxgs3qrl7ahwvhw4fnzrhve25gvezzyqqtum9pgv99ycma";
+    const VECTOR_4_SEED: &str = "ffeeddccbbaa99887766554433221100ffeeddccbbaa99887766554433221100";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn parse_all(strings: &[&str]) -> Vec<Codex32String> {
+        strings.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_vector_1_unshared_secret() {
+        let secret: Codex32String = VECTOR_1_SECRET.parse().unwrap();
+        assert_eq!(secret.threshold(), 0);
+        assert_eq!(secret.identifier(), "test");
+        assert_eq!(secret.share_index(), 's');
+        assert_eq!(hex(&secret.payload()), VECTOR_1_SEED);
+    }
+
+    #[test]
+    fn test_vector_2_recovery() {
+        let secret = codex32_combine(&parse_all(&VECTOR_2_SHARES)).unwrap();
+        assert_eq!(secret.to_string().to_uppercase(), VECTOR_2_SECRET);
+        assert_eq!(hex(&secret.payload()), VECTOR_2_SEED);
+    }
+
+    #[test]
+    fn test_vector_3_derived_shares() {
+        let base = [
+            VECTOR_3_SHARES[0].parse().unwrap(),
+            VECTOR_3_SHARES[1].parse().unwrap(),
+            VECTOR_3_SECRET.parse::<Codex32String>().unwrap(),
+        ];
+        for (share, index) in VECTOR_3_SHARES[2..].iter().zip([b'd', b'e', b'f']) {
+            let derived = interpolate(&base, char_value(index).unwrap()).unwrap();
+            assert_eq!(derived.to_string(), *share);
+        }
+
+        let secret = codex32_combine(&parse_all(&VECTOR_3_SHARES[2..])).unwrap();
+        assert_eq!(secret.to_string(), VECTOR_3_SECRET);
+        assert_eq!(hex(&secret.payload()), VECTOR_3_SEED);
+    }
+
+    #[test]
+    fn test_vector_4_256_bit_seed() {
+        let secret: Codex32String = VECTOR_4_SECRET.parse().unwrap();
+        assert_eq!(hex(&secret.payload()), VECTOR_4_SEED);
+
+        let encoded = Codex32String::from_seed(&unhex(VECTOR_4_SEED), "leet").unwrap();
+        assert_eq!(encoded.payload(), secret.payload());
+    }
+
+    #[test]
+    fn test_from_seed_uses_zero_padding() {
+        // The vector's final character carries non-zero padding bits, which
+        // decoding ignores and encoding always clears.
+        let secret = Codex32String::from_seed(&unhex(VECTOR_1_SEED), "test").unwrap();
+        let encoded = secret.to_string();
+        assert_eq!(encoded[..34], VECTOR_1_SECRET[..34]);
+        assert_eq!(&encoded[34..35], "y");
+        assert_eq!(encoded.parse::<Codex32String>().unwrap(), secret);
+        assert_eq!(hex(&secret.payload()), VECTOR_1_SEED);
+    }
+
+    #[test]
+    fn test_rejects_bad_checksum() {
+        let corrupted = VECTOR_1_SECRET.replacen("4nzv", "4nzw", 1);
+        assert_eq!(
+            corrupted.parse::<Codex32String>().unwrap_err(),
+            Error::InvalidShare {
+                reason: "Invalid codex32 checksum".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_rejects_mixed_case_and_bad_prefix() {
+        let mixed = format!("MS1{}", &VECTOR_1_SECRET[3..]);
+        assert!(mixed.parse::<Codex32String>().is_err());
+        let wrong_hrp = format!("mx1{}", &VECTOR_1_SECRET[3..]);
+        assert!(wrong_hrp.parse::<Codex32String>().is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_character() {
+        let invalid = VECTOR_1_SECRET.replacen('x', "b", 1);
+        assert!(matches!(
+            invalid.parse::<Codex32String>().unwrap_err(),
+            Error::InvalidWord { .. }
+        ));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_split_and_combine() {
+        let seed: Vec<u8> = (0..32).collect();
+        let shares = codex32_split(&seed, "seed", 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        for share in &shares {
+            let encoded = share.to_string();
+            assert!(encoded.starts_with("ms13seed"));
+            assert_eq!(encoded.parse::<Codex32String>().unwrap(), *share);
+        }
+
+        let selected = [shares[4].clone(), shares[0].clone(), shares[2].clone()];
+        let secret = codex32_combine(&selected).unwrap();
+        assert_eq!(secret.payload(), seed);
+
+        assert_eq!(
+            codex32_combine(&shares[..2]).unwrap_err(),
+            Error::InsufficientShares {
+                required: 3,
+                provided: 2
+            }
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_split_rejects_invalid_parameters() {
+        let seed = [0u8; 16];
+        assert!(codex32_split(&seed, "seed", 1, 3).is_err());
+        assert!(codex32_split(&seed, "seed", 3, 2).is_err());
+        assert!(codex32_split(&seed, "seed", 10, 12).is_err());
+        assert!(codex32_split(&seed, "sea", 2, 3).is_err());
+        assert!(codex32_split(&seed, "seeb", 2, 3).is_err());
+        assert!(codex32_split(&[0u8; 15], "seed", 2, 3).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_combine_rejects_mixed_secrets() {
+        let a = codex32_split(&[1u8; 16], "aaaa", 2, 3).unwrap();
+        let b = codex32_split(&[1u8; 16], "zzzz", 2, 3).unwrap();
+        let result = codex32_combine(&[a[0].clone(), b[1].clone()]);
+        assert!(matches!(result.unwrap_err(), Error::InvalidShare { .. }));
+    }
+
+    #[test]
+    fn test_mnemonic_codex32_roundtrip() {
+        for length in [16, 20, 24, 28, 32] {
+            let entropy = vec![0xabu8; length];
+            let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+            let secret = mnemonic.to_codex32("test").unwrap();
+            let parsed: Codex32String = secret.to_string().parse().unwrap();
+            let recovered = Mnemonic::from_codex32(&parsed, Language::English).unwrap();
+            assert_eq!(recovered, mnemonic);
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_from_codex32_rejects_share() {
+        let share: Codex32String = VECTOR_3_SHARES[0].parse().unwrap();
+        assert!(Mnemonic::from_codex32(&share, Language::English).is_err());
+    }
+
+    #[test]
+    fn test_debug_redacts_payload() {
+        let secret: Codex32String = VECTOR_1_SECRET.parse().unwrap();
+        let debug = format!("{secret:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains(&format!("{:?}", secret.payload())));
+        assert_eq!(secret, VECTOR_1_SECRET.parse().unwrap());
+    }
+}
@@ -0,0 +1,232 @@
+//! Detecting which wordlist a phrase was written in.
+//!
+//! Some phrases are checksum-valid in more than one language: English and
+//! French share 100 words, and the Simplified and Traditional Chinese lists
+//! share 1275 characters. [`Language::detect`] reports every language in
+//! which a phrase is a valid mnemonic, together with how many of its words
+//! each wordlist contains, so an import flow can ask the user instead of
+//! guessing.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::Language;
+//!
+//! let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let detection = Language::detect(phrase);
+//!
+//! assert_eq!(detection.language(), Some(Language::English));
+//! assert!(!detection.is_ambiguous());
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::codec;
+use crate::Language;
+
+/// How well a phrase matches one wordlist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageScore {
+    language: Language,
+    matched_words: usize,
+    word_count: usize,
+    is_valid: bool,
+}
+
+impl LanguageScore {
+    /// Returns the language this score is for.
+    pub fn language(&self) -> Language {
+        self.language.clone()
+    }
+
+    /// Returns the number of the phrase's words found in the wordlist.
+    pub fn matched_words(&self) -> usize {
+        self.matched_words
+    }
+
+    /// Returns the number of words in the phrase.
+    pub fn word_count(&self) -> usize {
+        self.word_count
+    }
+
+    /// Returns the fraction of the phrase's words found in the wordlist,
+    /// from 0.0 to 1.0.
+    pub fn confidence(&self) -> f64 {
+        if self.word_count == 0 {
+            return 0.0;
+        }
+        self.matched_words as f64 / self.word_count as f64
+    }
+
+    /// Returns `true` if the phrase is a checksum-valid mnemonic in this language.
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+}
+
+/// The result of [`Language::detect`].
+///
+/// Holds one [`LanguageScore`] per candidate language, with the languages in
+/// which the phrase is valid first and the rest ordered by how many words
+/// matched. The phrase itself is not stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageDetection {
+    scores: Vec<LanguageScore>,
+}
+
+impl LanguageDetection {
+    /// Returns every language in which the phrase is a checksum-valid mnemonic.
+    pub fn languages(&self) -> Vec<Language> {
+        self.scores
+            .iter()
+            .filter(|score| score.is_valid)
+            .map(|score| score.language.clone())
+            .collect()
+    }
+
+    /// Returns the language if the phrase is valid in exactly one of them.
+    pub fn language(&self) -> Option<Language> {
+        let mut languages = self.languages();
+        if languages.len() == 1 {
+            languages.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the phrase is valid in more than one language.
+    pub fn is_ambiguous(&self) -> bool {
+        self.scores.iter().filter(|score| score.is_valid).count() > 1
+    }
+
+    /// Returns the score of every candidate language, best match first.
+    pub fn scores(&self) -> &[LanguageScore] {
+        &self.scores
+    }
+}
+
+impl Language {
+    /// Detects the built-in languages in which `phrase` is a valid mnemonic.
+    ///
+    /// Words are matched exactly (ignoring case); unlike
+    /// [`Mnemonic::from_phrase`](crate::Mnemonic::from_phrase), four-letter
+    /// prefixes are not expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::Language;
+    ///
+    /// // Every character of this phrase is in both Chinese wordlists.
+    /// let phrase = "的 的 的 的 的 的 的 的 的 的 的 在";
+    /// let detection = Language::detect(phrase);
+    ///
+    /// assert!(detection.is_ambiguous());
+    /// assert_eq!(
+    ///     detection.languages(),
+    ///     [Language::SimplifiedChinese, Language::TraditionalChinese]
+    /// );
+    /// assert_eq!(detection.scores()[0].confidence(), 1.0);
+    /// ```
+    pub fn detect(phrase: &str) -> LanguageDetection {
+        Self::detect_among(phrase, Self::all_variants())
+    }
+
+    /// Detects which of `candidates` `phrase` is a valid mnemonic in.
+    ///
+    /// Use this to include [`Language::Custom`] wordlists in the detection.
+    pub fn detect_among(phrase: &str, candidates: &[Language]) -> LanguageDetection {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        let mut scores: Vec<LanguageScore> = candidates
+            .iter()
+            .map(|language| {
+                let matched_words = words
+                    .iter()
+                    .filter(|word| language.index_of(word).is_some())
+                    .count();
+                LanguageScore {
+                    language: language.clone(),
+                    matched_words,
+                    word_count: words.len(),
+                    is_valid: matched_words == words.len()
+                        && codec::phrase_to_entropy(phrase, language).is_ok(),
+                }
+            })
+            .collect();
+        // Stable sort keeps the candidate order among equal scores.
+        scores.sort_by_key(|score| (!score.is_valid, words.len() - score.matched_words));
+        LanguageDetection { scores }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mnemonic;
+
+    /// A 12-word phrase made of words in both the English and French lists.
+    fn english_french_phrase() -> &'static str {
+        "amateur amateur amateur amateur amateur amateur amateur amateur amateur amateur amateur angle"
+    }
+
+    #[test]
+    fn test_unique_language() {
+        for language in Language::all_variants() {
+            let mnemonic = Mnemonic::new(&[0x5a; 32], language.clone()).unwrap();
+            let detection = Language::detect(mnemonic.phrase());
+            assert_eq!(detection.language().as_ref(), Some(language));
+            assert_eq!(&detection.scores()[0].language(), language);
+            assert_eq!(detection.scores()[0].confidence(), 1.0);
+            assert_eq!(detection.scores().len(), Language::all_variants().len());
+        }
+    }
+
+    #[test]
+    fn test_english_french_overlap() {
+        let detection = Language::detect(english_french_phrase());
+        assert!(detection.is_ambiguous());
+        assert_eq!(detection.language(), None);
+        assert_eq!(detection.languages(), [Language::English, Language::French]);
+    }
+
+    #[test]
+    fn test_chinese_overlap() {
+        let detection = Language::detect("的 的 的 的 的 的 的 的 的 的 的 在");
+        assert_eq!(
+            detection.languages(),
+            [Language::SimplifiedChinese, Language::TraditionalChinese]
+        );
+    }
+
+    #[test]
+    fn test_partial_match_scores() {
+        // Valid in no language, but mostly English
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon bitcoin";
+        let detection = Language::detect(phrase);
+        assert!(detection.languages().is_empty());
+        assert_eq!(detection.language(), None);
+        let best = &detection.scores()[0];
+        assert_eq!(best.language(), Language::English);
+        assert_eq!(best.matched_words(), 11);
+        assert_eq!(best.word_count(), 12);
+        assert!(!best.is_valid());
+        assert!((best.confidence() - 11.0 / 12.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_empty_phrase() {
+        let detection = Language::detect("   ");
+        assert!(detection.languages().is_empty());
+        assert_eq!(detection.scores()[0].confidence(), 0.0);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_detect_among() {
+        let mnemonic = Mnemonic::generate(crate::WordCount::Twelve, Language::Italian).unwrap();
+        let detection =
+            Language::detect_among(mnemonic.phrase(), &[Language::English, Language::Italian]);
+        assert_eq!(detection.scores().len(), 2);
+        assert_eq!(detection.language(), Some(Language::Italian));
+    }
+}
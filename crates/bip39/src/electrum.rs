@@ -0,0 +1,446 @@
+//! Electrum "new" seed phrases.
+//!
+//! Electrum (since version 2.0) does not use BIP39. Its mnemonics carry no
+//! wordlist checksum; instead the seed type is encoded as a version prefix of
+//! `HMAC-SHA512(key = "Seed version", message = normalized phrase)`:
+//!
+//! | Seed type          | Version prefix | Root derivation paths |
+//! |--------------------|----------------|-----------------------|
+//! | Standard           | `01`           | `m`                   |
+//! | Segwit             | `100`          | `m/0'`                |
+//! | 2FA                | `101`          | `m/0'`, `m/1'`        |
+//! | 2FA segwit         | `102`          | `m/0'`, `m/1'`        |
+//!
+//! The seed is derived with PBKDF2-HMAC-SHA512 over the normalized phrase,
+//! salted with `"electrum"` followed by the normalized passphrase, for 2048
+//! iterations. The resulting 64-byte seed is used as a BIP32 master seed, with
+//! the wallet's keys below the root paths above.
+//!
+//! Pre-2.0 ("old") Electrum seeds are not supported.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{ElectrumMnemonic, ElectrumSeedType};
+//!
+//! let phrase = "wild father tree among universe such mobile favorite target dynamic credit identify";
+//! let mnemonic = ElectrumMnemonic::from_phrase(phrase)?;
+//!
+//! assert_eq!(mnemonic.seed_type(), ElectrumSeedType::Segwit);
+//! assert_eq!(mnemonic.seed_type().root_paths(), ["m/0'"]);
+//!
+//! let seed = mnemonic.to_seed("")?;
+//! assert_eq!(seed.as_bytes().len(), 64);
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use crate::utils::constant_time_eq;
+use crate::{Error, Result, Seed};
+
+/// HMAC key used to compute the seed version.
+const SEED_VERSION_KEY: &[u8] = b"Seed version";
+
+/// Salt prefix for seed derivation; the normalized passphrase is appended.
+const SALT_PREFIX: &str = "electrum";
+
+/// PBKDF2 iteration count for seed derivation.
+const PBKDF2_ROUNDS: u32 = 2048;
+
+/// Unicode blocks treated as CJK by Electrum's normalization.
+///
+/// Spaces between two CJK characters are removed, since those scripts are
+/// written without word separators.
+const CJK_INTERVALS: &[(u32, u32)] = &[
+    (0x4E00, 0x9FFF),   // CJK Unified Ideographs
+    (0x3400, 0x4DBF),   // CJK Unified Ideographs Extension A
+    (0x20000, 0x2A6DF), // CJK Unified Ideographs Extension B
+    (0x2A700, 0x2B73F), // CJK Unified Ideographs Extension C
+    (0x2B740, 0x2B81F), // CJK Unified Ideographs Extension D
+    (0xF900, 0xFAFF),   // CJK Compatibility Ideographs
+    (0x2F800, 0x2FA1D), // CJK Compatibility Ideographs Supplement
+    (0x3190, 0x319F),   // Kanbun
+    (0x2E80, 0x2EFF),   // CJK Radicals Supplement
+    (0x2F00, 0x2FDF),   // CJK Radicals
+    (0x31C0, 0x31EF),   // CJK Strokes
+    (0x2FF0, 0x2FFF),   // Ideographic Description Characters
+    (0xE0100, 0xE01EF), // Variation Selectors Supplement
+    (0x3100, 0x312F),   // Bopomofo
+    (0x31A0, 0x31BF),   // Bopomofo Extended
+    (0xFF00, 0xFFEF),   // Halfwidth and Fullwidth Forms
+    (0x3040, 0x309F),   // Hiragana
+    (0x30A0, 0x30FF),   // Katakana
+    (0x31F0, 0x31FF),   // Katakana Phonetic Extensions
+    (0x1B000, 0x1B0FF), // Kana Supplement
+    (0xAC00, 0xD7AF),   // Hangul Syllables
+    (0x1100, 0x11FF),   // Hangul Jamo
+    (0xA960, 0xA97F),   // Hangul Jamo Extended A
+    (0xD7B0, 0xD7FF),   // Hangul Jamo Extended B
+    (0x3130, 0x318F),   // Hangul Compatibility Jamo
+    (0xA4D0, 0xA4FF),   // Lisu
+    (0x16F00, 0x16F9F), // Miao
+    (0xA000, 0xA48F),   // Yi Syllables
+    (0xA490, 0xA4CF),   // Yi Radicals
+];
+
+/// The type of an Electrum seed, determined by its version prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElectrumSeedType {
+    /// Legacy P2PKH wallet (version prefix `01`).
+    Standard,
+    /// Native segwit P2WPKH wallet (version prefix `100`).
+    Segwit,
+    /// Two-factor (TrustedCoin) P2SH multisig wallet (version prefix `101`).
+    TwoFactor,
+    /// Two-factor (TrustedCoin) P2WSH multisig wallet (version prefix `102`).
+    TwoFactorSegwit,
+}
+
+impl ElectrumSeedType {
+    /// Returns all Electrum seed types.
+    pub const fn all_variants() -> &'static [ElectrumSeedType] {
+        &[
+            ElectrumSeedType::Standard,
+            ElectrumSeedType::Segwit,
+            ElectrumSeedType::TwoFactor,
+            ElectrumSeedType::TwoFactorSegwit,
+        ]
+    }
+
+    /// Returns the hex prefix of the seed version HMAC for this type.
+    pub const fn version_prefix(&self) -> &'static str {
+        match self {
+            ElectrumSeedType::Standard => "01",
+            ElectrumSeedType::Segwit => "100",
+            ElectrumSeedType::TwoFactor => "101",
+            ElectrumSeedType::TwoFactorSegwit => "102",
+        }
+    }
+
+    /// Returns the BIP32 paths of the wallet's root keys.
+    ///
+    /// Two-factor wallets have two keys held by the user: `m/0'` (x1) and
+    /// `m/1'` (x2). The paths can be parsed with `DerivationPath::from_str`
+    /// from `khodpay-bip32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::ElectrumSeedType;
+    /// assert_eq!(ElectrumSeedType::Standard.root_paths(), ["m"]);
+    /// assert_eq!(ElectrumSeedType::TwoFactor.root_paths(), ["m/0'", "m/1'"]);
+    /// ```
+    pub const fn root_paths(&self) -> &'static [&'static str] {
+        match self {
+            ElectrumSeedType::Standard => &["m"],
+            ElectrumSeedType::Segwit => &["m/0'"],
+            ElectrumSeedType::TwoFactor | ElectrumSeedType::TwoFactorSegwit => &["m/0'", "m/1'"],
+        }
+    }
+
+    /// Returns the seed type name used by Electrum.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ElectrumSeedType::Standard => "standard",
+            ElectrumSeedType::Segwit => "segwit",
+            ElectrumSeedType::TwoFactor => "2fa",
+            ElectrumSeedType::TwoFactorSegwit => "2fa_segwit",
+        }
+    }
+}
+
+/// A validated Electrum "new" seed phrase.
+///
+/// The phrase is stored in Electrum's normalized form. Any text whose seed
+/// version matches a known prefix is accepted, since Electrum seeds are not
+/// required to use a particular wordlist. Like [`Mnemonic`](crate::Mnemonic),
+/// the phrase is zeroized on drop and redacted from `Debug` output.
+#[derive(Clone)]
+pub struct ElectrumMnemonic {
+    phrase: Zeroizing<String>,
+    seed_type: ElectrumSeedType,
+}
+
+impl ElectrumMnemonic {
+    /// Parses an Electrum seed phrase and detects its seed type.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidMnemonic`] - If the phrase is empty or its seed
+    ///   version does not match any known Electrum seed type
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{ElectrumMnemonic, ElectrumSeedType};
+    ///
+    /// let phrase = "cycle rocket west magnet parrot shuffle foot correct salt library feed song";
+    /// let mnemonic = ElectrumMnemonic::from_phrase(phrase)?;
+    /// assert_eq!(mnemonic.seed_type(), ElectrumSeedType::Standard);
+    ///
+    /// // BIP39 phrases are (almost always) not valid Electrum seeds
+    /// let bip39 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// assert!(ElectrumMnemonic::from_phrase(bip39).is_err());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn from_phrase(phrase: &str) -> Result<Self> {
+        let phrase = Zeroizing::new(normalize_text(phrase));
+        if phrase.is_empty() {
+            return Err(Error::InvalidMnemonic {
+                reason: "Empty phrase".to_string(),
+            });
+        }
+        let seed_type = seed_type_of_normalized(&phrase).ok_or_else(|| Error::InvalidMnemonic {
+            reason: "Not an Electrum seed: unknown seed version".to_string(),
+        })?;
+        Ok(Self { phrase, seed_type })
+    }
+
+    /// Returns the normalized phrase.
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Returns the seed type encoded in the phrase.
+    pub fn seed_type(&self) -> ElectrumSeedType {
+        self.seed_type
+    }
+
+    /// Derives the 64-byte BIP32 master seed.
+    ///
+    /// The passphrase (Electrum's "seed extension") is normalized the same
+    /// way as the phrase. Use the result with `ExtendedPrivateKey::from_seed`
+    /// and derive the [`root_paths`](ElectrumSeedType::root_paths) of the seed type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::ElectrumMnemonic;
+    ///
+    /// let phrase = "wild father tree among universe such mobile favorite target dynamic credit identify";
+    /// let seed = ElectrumMnemonic::from_phrase(phrase)?.to_seed("")?;
+    /// assert_eq!(seed.as_bytes()[..4], [0xaa, 0xc2, 0xa6, 0x30]);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn to_seed(&self, passphrase: &str) -> Result<Seed> {
+        let salt = Zeroizing::new(format!("{SALT_PREFIX}{}", normalize_text(passphrase)));
+        let mut seed = Seed::zeroed();
+        pbkdf2::pbkdf2_hmac::<Sha512>(
+            self.phrase.as_bytes(),
+            salt.as_bytes(),
+            PBKDF2_ROUNDS,
+            seed.as_mut_bytes(),
+        );
+        Ok(seed)
+    }
+}
+
+/// Compares the phrases in constant time.
+impl PartialEq for ElectrumMnemonic {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(self.phrase.as_bytes(), other.phrase.as_bytes())
+    }
+}
+
+impl Eq for ElectrumMnemonic {}
+
+impl fmt::Debug for ElectrumMnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElectrumMnemonic")
+            .field("seed_type", &self.seed_type)
+            .field("phrase", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Returns the Electrum seed type of `phrase`, or `None` if it is not an Electrum seed.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{electrum_seed_type, ElectrumSeedType};
+///
+/// let phrase = "science dawn member doll dutch real can brick knife deny drive list";
+/// assert_eq!(electrum_seed_type(phrase), Some(ElectrumSeedType::TwoFactor));
+/// ```
+pub fn electrum_seed_type(phrase: &str) -> Option<ElectrumSeedType> {
+    seed_type_of_normalized(&normalize_text(phrase))
+}
+
+/// Matches the seed version of an already normalized phrase against the known prefixes.
+fn seed_type_of_normalized(phrase: &str) -> Option<ElectrumSeedType> {
+    let mut mac =
+        Hmac::<Sha512>::new_from_slice(SEED_VERSION_KEY).expect("HMAC accepts keys of any size");
+    mac.update(phrase.as_bytes());
+    let version = hex_prefix(&mac.finalize().into_bytes()[..2]);
+
+    ElectrumSeedType::all_variants()
+        .iter()
+        .copied()
+        .find(|seed_type| version.starts_with(seed_type.version_prefix()))
+}
+
+/// Formats bytes as lowercase hex.
+fn hex_prefix(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Normalizes text the way Electrum does before hashing.
+///
+/// NFKD, lowercase, strip combining marks (accents), collapse whitespace to
+/// single spaces and remove spaces between CJK characters.
+fn normalize_text(text: &str) -> String {
+    let text: String = text
+        .nfkd()
+        .collect::<String>()
+        .to_lowercase()
+        .chars()
+        .filter(|&c| canonical_combining_class(c) == 0)
+        .collect();
+    let chars: Vec<char> = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect();
+
+    chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, &c)| {
+            !(c == ' '
+                && i > 0
+                && i + 1 < chars.len()
+                && is_cjk(chars[i - 1])
+                && is_cjk(chars[i + 1]))
+        })
+        .map(|(_, &c)| c)
+        .collect()
+}
+
+/// Returns `true` if `c` lies in one of Electrum's CJK blocks.
+fn is_cjk(c: char) -> bool {
+    let c = c as u32;
+    CJK_INTERVALS
+        .iter()
+        .any(|&(start, end)| (start..=end).contains(&c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segwit_seed_vector() {
+        let phrase =
+            "wild father tree among universe such mobile favorite target dynamic credit identify";
+        let mnemonic = ElectrumMnemonic::from_phrase(phrase).unwrap();
+        assert_eq!(mnemonic.seed_type(), ElectrumSeedType::Segwit);
+        assert_eq!(
+            hex::encode(mnemonic.to_seed("").unwrap().as_bytes()),
+            "aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e30\
+             64342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756"
+        );
+    }
+
+    #[test]
+    fn test_seed_type_detection() {
+        let cases = [
+            (
+                "cycle rocket west magnet parrot shuffle foot correct salt library feed song",
+                ElectrumSeedType::Standard,
+            ),
+            (
+                "hint shock chair puzzle shock traffic drastic note dinosaur mention suggest sweet",
+                ElectrumSeedType::Standard,
+            ),
+            (
+                "bitter grass shiver impose acquire brush forget axis eager alone wine silver",
+                ElectrumSeedType::Segwit,
+            ),
+            (
+                "frost pig brisk excite novel report camera enlist axis nation novel desert",
+                ElectrumSeedType::Segwit,
+            ),
+            (
+                "science dawn member doll dutch real can brick knife deny drive list",
+                ElectrumSeedType::TwoFactor,
+            ),
+        ];
+        for (phrase, seed_type) in cases {
+            assert_eq!(electrum_seed_type(phrase), Some(seed_type), "{phrase}");
+        }
+        assert_eq!(
+            electrum_seed_type(
+                "heat possible enlist lady cable fiction sand orange taste cabin truly pelican"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_passphrase_salt() {
+        // Electrum's mnemonic_to_seed("foobar", "none") test case
+        let mnemonic = ElectrumMnemonic {
+            phrase: Zeroizing::new("foobar".to_string()),
+            seed_type: ElectrumSeedType::Standard,
+        };
+        assert_eq!(
+            hex::encode(mnemonic.to_seed("none").unwrap().as_bytes()),
+            "741b72fd15effece6bfe5a26a52184f66811bd2be363190e07a42cca442b1a5b\
+             b22b3ad0eb338197287e6d314866c7fba863ac65d3f156087a5052ebc7157fce"
+        );
+    }
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(
+            normalize_text("  Wild\tFATHER \n tree "),
+            "wild father tree"
+        );
+        assert_eq!(normalize_text("café crème"), "cafe creme");
+        assert_eq!(
+            normalize_text("あいこくしん あいこくしん"),
+            "あいこくしんあいこくしん"
+        );
+        assert_eq!(normalize_text("abc あい"), "abc あい");
+
+        let spaced = "  Wild father TREE among universe such mobile favorite target dynamic credit identify ";
+        let mnemonic = ElectrumMnemonic::from_phrase(spaced).unwrap();
+        assert_eq!(
+            mnemonic.phrase(),
+            "wild father tree among universe such mobile favorite target dynamic credit identify"
+        );
+    }
+
+    #[test]
+    fn test_invalid_phrases() {
+        assert!(ElectrumMnemonic::from_phrase("").is_err());
+        assert!(ElectrumMnemonic::from_phrase("   ").is_err());
+        assert!(ElectrumMnemonic::from_phrase(
+            "heat possible enlist lady cable fiction sand orange taste cabin truly pelican"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_root_paths() {
+        assert_eq!(ElectrumSeedType::Standard.root_paths(), ["m"]);
+        assert_eq!(ElectrumSeedType::Segwit.root_paths(), ["m/0'"]);
+        assert_eq!(ElectrumSeedType::TwoFactor.root_paths(), ["m/0'", "m/1'"]);
+        assert_eq!(
+            ElectrumSeedType::TwoFactorSegwit.root_paths(),
+            ["m/0'", "m/1'"]
+        );
+    }
+}
@@ -0,0 +1,515 @@
+//! Pluggable entropy sources for mnemonic generation.
+//!
+//! [`Mnemonic::generate`](crate::Mnemonic::generate) and
+//! [`generate_mnemonic`](crate::generate_mnemonic) draw their entropy from the
+//! operating system. The `*_with_source` variants accept any [`EntropySource`]
+//! instead, which makes it possible to:
+//!
+//! - Reproduce mnemonics in tests with [`DeterministicEntropy`]
+//! - Combine the OS generator with external devices using [`MixedEntropy`]
+//! - Report entropy-quality metrics with [`LoggingEntropy`]
+//! - Use any cryptographically secure `rand` generator via [`RngEntropy`]
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{DeterministicEntropy, Language, Mnemonic, WordCount};
+//!
+//! let mut source = DeterministicEntropy::new(b"test vector");
+//! let first = Mnemonic::generate_with_source(WordCount::Twelve, Language::English, &mut source)?;
+//!
+//! let mut source = DeterministicEntropy::new(b"test vector");
+//! let second = Mnemonic::generate_with_source(WordCount::Twelve, Language::English, &mut source)?;
+//!
+//! assert_eq!(first, second);
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "rand")]
+use rand::rngs::OsRng;
+#[cfg(feature = "rand")]
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::Result;
+
+/// A source of random bytes for mnemonic generation.
+///
+/// Implementations must fill the whole buffer or return an error; a source
+/// that cannot deliver (for example, a disconnected hardware device) should
+/// return [`Error::RandomGeneration`](crate::Error::RandomGeneration) rather
+/// than leaving bytes unset.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{EntropySource, Language, Mnemonic, Result, WordCount};
+///
+/// /// Reads entropy from a hardware device (simulated here).
+/// struct HardwareDevice;
+///
+/// impl EntropySource for HardwareDevice {
+///     fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+///         dest.fill(0x5a);
+///         Ok(())
+///     }
+/// }
+///
+/// let mnemonic = Mnemonic::generate_with_source(WordCount::Twelve, Language::English, &mut HardwareDevice)?;
+/// assert_eq!(mnemonic.entropy(), [0x5a; 16]);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+pub trait EntropySource {
+    /// Fills `dest` entirely with random bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot produce enough bytes.
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()>;
+}
+
+impl<S: EntropySource + ?Sized> EntropySource for &mut S {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+        (**self).fill_entropy(dest)
+    }
+}
+
+impl<S: EntropySource + ?Sized> EntropySource for Box<S> {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+        (**self).fill_entropy(dest)
+    }
+}
+
+/// The operating system's random number generator.
+///
+/// This is the default source used by [`Mnemonic::generate`](crate::Mnemonic::generate)
+/// and works reliably on mobile and static library targets. Requires the
+/// `rand` feature.
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OsEntropy;
+
+#[cfg(feature = "rand")]
+impl EntropySource for OsEntropy {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+        OsRng.try_fill_bytes(dest)?;
+        Ok(())
+    }
+}
+
+/// Adapts a cryptographically secure `rand` generator into an [`EntropySource`].
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{Language, Mnemonic, RngEntropy, WordCount};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut source = RngEntropy(StdRng::seed_from_u64(7));
+/// let mnemonic = Mnemonic::generate_with_source(WordCount::Fifteen, Language::English, &mut source)?;
+/// assert_eq!(mnemonic.word_count().word_count(), 15);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone)]
+pub struct RngEntropy<R>(pub R);
+
+#[cfg(feature = "rand")]
+impl<R: RngCore + CryptoRng> EntropySource for RngEntropy<R> {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+        self.0.try_fill_bytes(dest)?;
+        Ok(())
+    }
+}
+
+/// A reproducible byte stream derived from a seed, for tests and fixtures.
+///
+/// Block `i` of the stream is `SHA-256(seed || i)` with `i` as a big-endian
+/// `u64`; consecutive calls continue the stream.
+///
+/// # Security
+///
+/// ⚠️ Anyone who knows the seed can recreate every mnemonic generated from
+/// it. Never use this source for real funds.
+#[derive(Clone)]
+pub struct DeterministicEntropy {
+    seed: Vec<u8>,
+    counter: u64,
+    block: [u8; 32],
+    position: usize,
+}
+
+impl DeterministicEntropy {
+    /// Creates a stream from `seed`.
+    pub fn new(seed: &[u8]) -> Self {
+        Self {
+            seed: seed.to_vec(),
+            counter: 0,
+            block: [0u8; 32],
+            position: 32,
+        }
+    }
+}
+
+impl fmt::Debug for DeterministicEntropy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeterministicEntropy")
+            .field("counter", &self.counter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EntropySource for DeterministicEntropy {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+        for byte in dest.iter_mut() {
+            if self.position == self.block.len() {
+                let mut hasher = Sha256::new();
+                hasher.update(&self.seed);
+                hasher.update(self.counter.to_be_bytes());
+                self.block = hasher.finalize().into();
+                self.counter += 1;
+                self.position = 0;
+            }
+            *byte = self.block[self.position];
+            self.position += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Combines several sources by hashing their output.
+///
+/// Each request reads `dest.len()` bytes from every source and fills `dest`
+/// with `SHA-256(len || output_1 || ... || len || output_n || counter)` for
+/// counter values 0, 1, ..., each output prefixed with its 64-bit length.
+/// The result is unpredictable as long as at least one source is, even if
+/// another source is malicious and sees the others' output, so an untrusted
+/// device can be added without weakening the OS generator. All sources must
+/// succeed; on error `dest` is cleared and the first error is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{DeterministicEntropy, Language, Mnemonic, MixedEntropy, OsEntropy, WordCount};
+///
+/// let mut source = MixedEntropy::new(OsEntropy).with(DeterministicEntropy::new(b"dice"));
+/// assert_eq!(source.len(), 2);
+///
+/// let mnemonic = Mnemonic::generate_with_source(WordCount::TwentyFour, Language::English, &mut source)?;
+/// assert_eq!(mnemonic.entropy().len(), 32);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+pub struct MixedEntropy {
+    sources: Vec<Box<dyn EntropySource>>,
+}
+
+impl MixedEntropy {
+    /// Creates a mix starting with `source`.
+    pub fn new<S: EntropySource + 'static>(source: S) -> Self {
+        Self {
+            sources: vec![Box::new(source)],
+        }
+    }
+
+    /// Adds another source to the mix.
+    pub fn with<S: EntropySource + 'static>(mut self, source: S) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    /// Returns the number of mixed sources.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Always `false`: a mix holds at least one source.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+impl fmt::Debug for MixedEntropy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MixedEntropy")
+            .field("sources", &self.sources.len())
+            .finish()
+    }
+}
+
+impl EntropySource for MixedEntropy {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+        let mut buffer = Zeroizing::new(vec![0u8; dest.len()]);
+        let mut hasher = Sha256::new();
+        for source in &mut self.sources {
+            if let Err(err) = source.fill_entropy(&mut buffer) {
+                dest.zeroize();
+                return Err(err);
+            }
+            hasher.update((buffer.len() as u64).to_be_bytes());
+            hasher.update(&*buffer);
+        }
+
+        for (counter, chunk) in dest.chunks_mut(32).enumerate() {
+            let mut block = hasher.clone();
+            block.update((counter as u64).to_be_bytes());
+            let block = Zeroizing::new(<[u8; 32]>::from(block.finalize()));
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+/// Quality metrics of a block of entropy, as reported by [`LoggingEntropy`].
+///
+/// The metrics are coarse statistics meant for monitoring a source over time
+/// (for example, spotting a device that starts returning constant bytes).
+/// They never contain the entropy itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntropyMetrics {
+    /// Number of bytes produced.
+    pub bytes: usize,
+    /// Fraction of bits set to one; close to 0.5 for a good source.
+    pub ones_ratio: f64,
+    /// Empirical Shannon entropy of the byte distribution, in bits per byte.
+    ///
+    /// Bounded by log₂ of the block length, so short blocks score below 8.
+    pub shannon_entropy: f64,
+    /// Length of the longest run of identical consecutive bytes.
+    pub longest_run: usize,
+}
+
+impl EntropyMetrics {
+    /// Computes the metrics of `bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::EntropyMetrics;
+    /// let metrics = EntropyMetrics::measure(&[0u8; 32]);
+    /// assert_eq!(metrics.ones_ratio, 0.0);
+    /// assert_eq!(metrics.shannon_entropy, 0.0);
+    /// assert_eq!(metrics.longest_run, 32);
+    /// ```
+    pub fn measure(bytes: &[u8]) -> Self {
+        if bytes.is_empty() {
+            return Self {
+                bytes: 0,
+                ones_ratio: 0.0,
+                shannon_entropy: 0.0,
+                longest_run: 0,
+            };
+        }
+
+        let ones: u32 = bytes.iter().map(|b| b.count_ones()).sum();
+        let mut counts = [0usize; 256];
+        for &byte in bytes {
+            counts[byte as usize] += 1;
+        }
+        let total = bytes.len() as f64;
+        let shannon_entropy = counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * libm::log2(p)
+            })
+            .sum::<f64>()
+            .max(0.0);
+        let longest_run = bytes
+            .chunk_by(|a, b| a == b)
+            .map(<[u8]>::len)
+            .max()
+            .unwrap_or(0);
+
+        Self {
+            bytes: bytes.len(),
+            ones_ratio: f64::from(ones) / (total * 8.0),
+            shannon_entropy,
+            longest_run,
+        }
+    }
+}
+
+/// Wraps a source and reports [`EntropyMetrics`] for every block it produces.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{Language, LoggingEntropy, Mnemonic, OsEntropy, WordCount};
+///
+/// let mut reports = Vec::new();
+/// let mut source = LoggingEntropy::new(OsEntropy, |metrics| reports.push(*metrics));
+/// Mnemonic::generate_with_source(WordCount::Twelve, Language::English, &mut source)?;
+/// drop(source);
+///
+/// assert_eq!(reports.len(), 1);
+/// assert_eq!(reports[0].bytes, 16);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+pub struct LoggingEntropy<S, F> {
+    source: S,
+    report: F,
+}
+
+impl<S, F> LoggingEntropy<S, F>
+where
+    S: EntropySource,
+    F: FnMut(&EntropyMetrics),
+{
+    /// Wraps `source`, calling `report` after each successful fill.
+    pub fn new(source: S, report: F) -> Self {
+        Self { source, report }
+    }
+
+    /// Consumes the wrapper and returns the inner source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for LoggingEntropy<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggingEntropy")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, F> EntropySource for LoggingEntropy<S, F>
+where
+    S: EntropySource,
+    F: FnMut(&EntropyMetrics),
+{
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<()> {
+        self.source.fill_entropy(dest)?;
+        (self.report)(&EntropyMetrics::measure(dest));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "rand")]
+    struct FailingSource;
+
+    #[cfg(feature = "rand")]
+    impl EntropySource for FailingSource {
+        fn fill_entropy(&mut self, _dest: &mut [u8]) -> Result<()> {
+            Err(crate::Error::RandomGeneration)
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_os_entropy_fills_buffer() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        OsEntropy.fill_entropy(&mut a).unwrap();
+        OsEntropy.fill_entropy(&mut b).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_deterministic_entropy_stream() {
+        let mut source = DeterministicEntropy::new(b"seed");
+        let mut first = [0u8; 40];
+        source.fill_entropy(&mut first).unwrap();
+
+        let mut expected = Sha256::digest(b"seed\0\0\0\0\0\0\0\0").to_vec();
+        expected.extend_from_slice(&Sha256::digest(b"seed\0\0\0\0\0\0\0\x01")[..8]);
+        assert_eq!(first.to_vec(), expected);
+
+        // Split reads continue the same stream
+        let mut source = DeterministicEntropy::new(b"seed");
+        let mut head = [0u8; 25];
+        let mut tail = [0u8; 15];
+        source.fill_entropy(&mut head).unwrap();
+        source.fill_entropy(&mut tail).unwrap();
+        assert_eq!([head.as_slice(), tail.as_slice()].concat(), first);
+    }
+
+    #[test]
+    fn test_mixed_entropy_hashes_sources() {
+        let mut a = [0u8; 40];
+        let mut b = [0u8; 40];
+        DeterministicEntropy::new(b"a")
+            .fill_entropy(&mut a)
+            .unwrap();
+        DeterministicEntropy::new(b"b")
+            .fill_entropy(&mut b)
+            .unwrap();
+
+        let mut mixed = MixedEntropy::new(DeterministicEntropy::new(b"a"))
+            .with(DeterministicEntropy::new(b"b"));
+        let mut out = [0u8; 40];
+        mixed.fill_entropy(&mut out).unwrap();
+
+        let block = |counter: u64| {
+            let mut hasher = Sha256::new();
+            hasher.update(40u64.to_be_bytes());
+            hasher.update(a);
+            hasher.update(40u64.to_be_bytes());
+            hasher.update(b);
+            hasher.update(counter.to_be_bytes());
+            hasher.finalize()
+        };
+        let mut expected = block(0).to_vec();
+        expected.extend_from_slice(&block(1)[..8]);
+        assert_eq!(out.to_vec(), expected);
+
+        // A source repeating another's output does not cancel it out
+        let mut mixed = MixedEntropy::new(DeterministicEntropy::new(b"a"))
+            .with(DeterministicEntropy::new(b"a"));
+        mixed.fill_entropy(&mut out).unwrap();
+        assert_ne!(out, [0u8; 40]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_mixed_entropy_propagates_errors() {
+        let mut mixed = MixedEntropy::new(OsEntropy).with(FailingSource);
+        let mut out = [0xffu8; 16];
+        assert_eq!(
+            mixed.fill_entropy(&mut out).unwrap_err(),
+            crate::Error::RandomGeneration
+        );
+        assert_eq!(out, [0u8; 16]);
+    }
+
+    #[test]
+    fn test_logging_entropy_reports_metrics() {
+        let mut reports = Vec::new();
+        let mut source = LoggingEntropy::new(DeterministicEntropy::new(b"log"), |metrics| {
+            reports.push(*metrics)
+        });
+        let mut out = [0u8; 32];
+        source.fill_entropy(&mut out).unwrap();
+        assert!(source.fill_entropy(&mut [0u8; 0]).is_ok());
+        drop(source);
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0], EntropyMetrics::measure(&out));
+        assert_eq!(reports[1].bytes, 0);
+    }
+
+    #[test]
+    fn test_entropy_metrics() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let metrics = EntropyMetrics::measure(&bytes);
+        assert_eq!(metrics.bytes, 256);
+        assert_eq!(metrics.ones_ratio, 0.5);
+        assert!((metrics.shannon_entropy - 8.0).abs() < 1e-9);
+        assert_eq!(metrics.longest_run, 1);
+    }
+}
@@ -30,8 +30,13 @@
 //! assert_eq!(error1, error2);
 //! ```
 
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use thiserror::Error;
 
+use crate::Language;
+
 /// Comprehensive error types for BIP39 mnemonic operations.
 ///
 /// This enum represents all possible errors that can occur when working with
@@ -40,16 +45,38 @@ use thiserror::Error;
 /// # Error Categories
 ///
 /// - **Input Validation**: [`InvalidEntropyLength`], [`InvalidWordCount`], [`InvalidMnemonic`]
-/// - **Mnemonic Validation**: [`InvalidWord`], [`InvalidChecksum`]  
-/// - **External Dependencies**: [`RandomGeneration`], [`Bip39Error`]
+/// - **Mnemonic Validation**: [`InvalidWord`], [`InvalidChecksum`], [`AmbiguousLanguage`]
+/// - **External Dependencies**: [`RandomGeneration`]
+/// - **Secret Sharing**: [`InvalidShare`], [`InsufficientShares`], [`InvalidPassphrase`]
+/// - **Recovery**: [`SearchSpaceTooLarge`]
+/// - **Physical Entropy**: [`InvalidEntropyInput`], [`InsufficientEntropy`]
+/// - **QR Codes**: [`InvalidSeedQr`], [`InvalidQrData`]
+/// - **Vaults**: [`InvalidVault`], [`WrongPassword`], [`VaultTampered`]
+/// - **Wordlists**: [`InvalidWordlist`]
+/// - **Backup Quiz**: [`InvalidQuiz`]
+/// - **Aezeed**: [`InvalidAezeed`]
 ///
 /// [`InvalidEntropyLength`]: Error::InvalidEntropyLength
 /// [`InvalidWordCount`]: Error::InvalidWordCount
 /// [`InvalidMnemonic`]: Error::InvalidMnemonic
 /// [`InvalidWord`]: Error::InvalidWord
 /// [`InvalidChecksum`]: Error::InvalidChecksum
+/// [`AmbiguousLanguage`]: Error::AmbiguousLanguage
 /// [`RandomGeneration`]: Error::RandomGeneration
-/// [`Bip39Error`]: Error::Bip39Error
+/// [`InvalidShare`]: Error::InvalidShare
+/// [`InsufficientShares`]: Error::InsufficientShares
+/// [`InvalidPassphrase`]: Error::InvalidPassphrase
+/// [`SearchSpaceTooLarge`]: Error::SearchSpaceTooLarge
+/// [`InvalidEntropyInput`]: Error::InvalidEntropyInput
+/// [`InsufficientEntropy`]: Error::InsufficientEntropy
+/// [`InvalidSeedQr`]: Error::InvalidSeedQr
+/// [`InvalidQrData`]: Error::InvalidQrData
+/// [`InvalidVault`]: Error::InvalidVault
+/// [`WrongPassword`]: Error::WrongPassword
+/// [`VaultTampered`]: Error::VaultTampered
+/// [`InvalidWordlist`]: Error::InvalidWordlist
+/// [`InvalidQuiz`]: Error::InvalidQuiz
+/// [`InvalidAezeed`]: Error::InvalidAezeed
 #[derive(Debug, Error)]
 pub enum Error {
     /// The provided entropy has an invalid length.
@@ -135,31 +162,212 @@ pub enum Error {
     #[error("Invalid checksum for mnemonic phrase")]
     InvalidChecksum,
 
+    /// The phrase is a valid mnemonic in more than one built-in language.
+    ///
+    /// Returned by [`Mnemonic::from_phrase`](crate::Mnemonic::from_phrase)
+    /// instead of silently picking one. Ask the user which language the
+    /// backup was written in, then parse it with
+    /// [`Mnemonic::from_phrase_confirmed`](crate::Mnemonic::from_phrase_confirmed).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip39::{Error, Language};
+    /// let error = Error::AmbiguousLanguage {
+    ///     languages: vec![Language::SimplifiedChinese, Language::TraditionalChinese],
+    /// };
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Phrase is valid in several languages: Simplified Chinese, Traditional Chinese"
+    /// );
+    /// ```
+    #[error("Phrase is valid in several languages: {}", language_names(languages))]
+    AmbiguousLanguage {
+        /// Every built-in language in which the phrase is valid
+        languages: Vec<Language>,
+    },
+
     /// Error occurred during random number generation.
     ///
-    /// This error is automatically converted from [`rand::Error`] and
-    /// indicates a failure in the random number generator used for
-    /// entropy generation.
+    /// This error is automatically converted from `rand::Error` (with the
+    /// `rand` feature) and indicates a failure in the random number generator
+    /// or [`EntropySource`](crate::EntropySource) used for entropy generation.
     #[error("Random number generation failed")]
     RandomGeneration,
 
-    /// Error from the underlying BIP39 crate.
+    /// A secret share is malformed or inconsistent with the other shares.
+    ///
+    /// Raised for bad share checksums, unknown share words, mismatched
+    /// share parameters, or a failed digest check when recombining shares.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip39::Error;
+    /// let error = Error::InvalidShare {
+    ///     reason: "Invalid share checksum".to_string(),
+    /// };
+    /// println!("{}", error); // "Invalid share: Invalid share checksum"
+    /// ```
+    #[error("Invalid share: {reason}")]
+    InvalidShare {
+        /// Detailed reason why the share is invalid
+        reason: String,
+    },
+
+    /// Not enough shares were provided to recover the secret.
+    #[error("Insufficient shares: {required} required, {provided} provided")]
+    InsufficientShares {
+        /// The number of shares (or groups) required by the threshold
+        required: usize,
+        /// The number of usable shares (or groups) that were provided
+        provided: usize,
+    },
+
+    /// The passphrase cannot be used with the requested scheme.
+    ///
+    /// SLIP-39, for example, only accepts printable ASCII passphrases.
+    #[error("Invalid passphrase: {reason}")]
+    InvalidPassphrase {
+        /// Detailed reason why the passphrase is invalid
+        reason: String,
+    },
+
+    /// A phrase recovery search would check more combinations than allowed.
     ///
-    /// This error wraps errors from the external `bip39` crate that
-    /// we use for some low-level operations.
-    #[error("BIP39 error: {message}")]
-    Bip39Error {
-        /// Error message from the underlying BIP39 crate
-        message: String,
+    /// Mark fewer words as unknown, lower the edit distance, or raise the
+    /// limit with [`PhraseRecovery::max_combinations`](crate::PhraseRecovery::max_combinations).
+    #[error(
+        "Recovery search space too large: {combinations} combinations exceed the limit of {limit}"
+    )]
+    SearchSpaceTooLarge {
+        /// The number of phrases the search would have to check
+        combinations: u64,
+        /// The configured combination limit
+        limit: u64,
+    },
+
+    /// Dice rolls, coin flips or cards could not be used as entropy.
+    ///
+    /// Raised for malformed or repeated values and for input that fails
+    /// the bias check.
+    #[error("Invalid entropy input: {reason}")]
+    InvalidEntropyInput {
+        /// Detailed reason why the input was rejected
+        reason: String,
+    },
+
+    /// Physical entropy input carries fewer bits than the mnemonic needs.
+    #[error("Insufficient entropy: {required_bits} bits required, {provided_bits} bits provided")]
+    InsufficientEntropy {
+        /// The entropy size of the requested mnemonic in bits
+        required_bits: usize,
+        /// The estimated entropy of the input in bits
+        provided_bits: usize,
+    },
+
+    /// A scanned payload is not a valid SeedQR or CompactSeedQR.
+    #[error("Invalid SeedQR payload: {reason}")]
+    InvalidSeedQr {
+        /// Detailed reason why the payload was rejected
+        reason: String,
+    },
+
+    /// Data cannot be encoded as a QR code.
+    ///
+    /// Raised for characters outside the chosen mode and for data that
+    /// exceeds the capacity of the supported QR versions.
+    #[error("Invalid QR code data: {reason}")]
+    InvalidQrData {
+        /// Detailed reason why the data was rejected
+        reason: String,
+    },
+
+    /// Data is not a well-formed mnemonic vault of a supported version.
+    #[error("Invalid vault: {reason}")]
+    InvalidVault {
+        /// Detailed reason why the vault was rejected
+        reason: String,
+    },
+
+    /// The password does not unlock the vault.
+    ///
+    /// Detected with the key check value stored in the vault header, before
+    /// any decryption is attempted.
+    #[error("Wrong vault password")]
+    WrongPassword,
+
+    /// The vault was modified after it was sealed.
+    ///
+    /// The password is correct, but the ciphertext or the authenticated
+    /// header (hint, metadata, nonce) failed verification.
+    #[error("Vault failed authentication: the data has been modified or corrupted")]
+    VaultTampered,
+
+    /// A custom wordlist breaks the BIP39 wordlist rules.
+    ///
+    /// Raised for a wrong word count, duplicate words, words that are not
+    /// lowercase NFKD without whitespace, or words whose first four letters
+    /// are not unique.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip39::Error;
+    /// let error = Error::InvalidWordlist {
+    ///     reason: "Duplicate word 'abandon'".to_string(),
+    /// };
+    /// println!("{}", error); // "Invalid wordlist: Duplicate word 'abandon'"
+    /// ```
+    #[error("Invalid wordlist: {reason}")]
+    InvalidWordlist {
+        /// Detailed reason why the wordlist was rejected
+        reason: String,
+    },
+
+    /// A backup quiz was created or answered incorrectly.
+    ///
+    /// Raised for a question count of zero or more than the number of words,
+    /// an answer for a position that is not part of the quiz, or the wrong
+    /// number of answers. A wrong word is not an error; it is reported by
+    /// [`BackupQuiz::check`](crate::BackupQuiz::check) returning `false`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip39::Error;
+    /// let error = Error::InvalidQuiz {
+    ///     reason: "Word 5 is not part of the quiz".to_string(),
+    /// };
+    /// println!("{}", error); // "Invalid quiz: Word 5 is not part of the quiz"
+    /// ```
+    #[error("Invalid quiz: {reason}")]
+    InvalidQuiz {
+        /// Detailed reason why the quiz operation failed
+        reason: String,
+    },
+
+    /// An LND aezeed phrase could not be decoded.
+    ///
+    /// Raised when the phrase does not have 24 words, its CRC-32C checksum
+    /// does not match or its version byte is not supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip39::Error;
+    /// let error = Error::InvalidAezeed {
+    ///     reason: "Checksum mismatch".to_string(),
+    /// };
+    /// println!("{}", error); // "Invalid aezeed: Checksum mismatch"
+    /// ```
+    #[error("Invalid aezeed: {reason}")]
+    InvalidAezeed {
+        /// Detailed reason why the aezeed phrase was rejected
+        reason: String,
     },
 }
 
 /// Custom equality implementation for [`enum@Error`].
 ///
 /// This implementation allows comparing errors for equality, which is useful
-/// in tests and error matching. For errors containing external types
-/// (like [`rand::Error`] or [`bip39_upstream::Error`]), we compare only
-/// by error type since the wrapped errors may not implement [`PartialEq`].
+/// in tests and error matching. Errors converted from external types (like
+/// `rand::Error`) keep no payload, so they compare equal by variant.
 ///
 /// # Examples
 ///
@@ -196,8 +404,59 @@ impl PartialEq for Error {
                 },
             ) => w1 == w2 && p1 == p2,
             (Error::InvalidChecksum, Error::InvalidChecksum) => true,
+            (
+                Error::AmbiguousLanguage { languages: l1 },
+                Error::AmbiguousLanguage { languages: l2 },
+            ) => l1 == l2,
             (Error::RandomGeneration, Error::RandomGeneration) => true,
-            (Error::Bip39Error { message: m1 }, Error::Bip39Error { message: m2 }) => m1 == m2,
+            (Error::InvalidShare { reason: r1 }, Error::InvalidShare { reason: r2 }) => r1 == r2,
+            (
+                Error::InsufficientShares {
+                    required: r1,
+                    provided: p1,
+                },
+                Error::InsufficientShares {
+                    required: r2,
+                    provided: p2,
+                },
+            ) => r1 == r2 && p1 == p2,
+            (Error::InvalidPassphrase { reason: r1 }, Error::InvalidPassphrase { reason: r2 }) => {
+                r1 == r2
+            }
+            (
+                Error::SearchSpaceTooLarge {
+                    combinations: c1,
+                    limit: l1,
+                },
+                Error::SearchSpaceTooLarge {
+                    combinations: c2,
+                    limit: l2,
+                },
+            ) => c1 == c2 && l1 == l2,
+            (
+                Error::InvalidEntropyInput { reason: r1 },
+                Error::InvalidEntropyInput { reason: r2 },
+            ) => r1 == r2,
+            (
+                Error::InsufficientEntropy {
+                    required_bits: r1,
+                    provided_bits: p1,
+                },
+                Error::InsufficientEntropy {
+                    required_bits: r2,
+                    provided_bits: p2,
+                },
+            ) => r1 == r2 && p1 == p2,
+            (Error::InvalidSeedQr { reason: r1 }, Error::InvalidSeedQr { reason: r2 }) => r1 == r2,
+            (Error::InvalidQrData { reason: r1 }, Error::InvalidQrData { reason: r2 }) => r1 == r2,
+            (Error::InvalidVault { reason: r1 }, Error::InvalidVault { reason: r2 }) => r1 == r2,
+            (Error::WrongPassword, Error::WrongPassword) => true,
+            (Error::VaultTampered, Error::VaultTampered) => true,
+            (Error::InvalidWordlist { reason: r1 }, Error::InvalidWordlist { reason: r2 }) => {
+                r1 == r2
+            }
+            (Error::InvalidQuiz { reason: r1 }, Error::InvalidQuiz { reason: r2 }) => r1 == r2,
+            (Error::InvalidAezeed { reason: r1 }, Error::InvalidAezeed { reason: r2 }) => r1 == r2,
             _ => false,
         }
     }
@@ -209,22 +468,23 @@ impl PartialEq for Error {
 impl Eq for Error {}
 
 /// Convert from `rand::Error` to our `Error` type.
+#[cfg(feature = "rand")]
 impl From<rand::Error> for Error {
     fn from(_error: rand::Error) -> Self {
         Error::RandomGeneration
     }
 }
 
-/// Convert from `bip39_upstream::Error` to our `Error` type.
-impl From<bip39_upstream::Error> for Error {
-    fn from(error: bip39_upstream::Error) -> Self {
-        Error::Bip39Error {
-            message: error.to_string(),
-        }
-    }
+/// Joins language names for display, e.g. `English, French`.
+fn language_names(languages: &[Language]) -> String {
+    languages
+        .iter()
+        .map(Language::name)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
-/// Convenient type alias for [`std::result::Result`] with our [`enum@Error`] type.
+/// Convenient type alias for [`core::result::Result`] with our [`enum@Error`] type.
 ///
 /// This allows using `Result<T>` instead of `Result<T, Error>` throughout
 /// the codebase, making function signatures cleaner and more readable.
@@ -243,7 +503,7 @@ impl From<bip39_upstream::Error> for Error {
 ///     Ok(())
 /// }
 /// ```
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[cfg(test)]
 mod tests {
@@ -293,4 +553,16 @@ mod tests {
         let error = Error::InvalidChecksum;
         assert_eq!(error.to_string(), "Invalid checksum for mnemonic phrase");
     }
+
+    #[test]
+    fn test_insufficient_shares_error() {
+        let error = Error::InsufficientShares {
+            required: 3,
+            provided: 2,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Insufficient shares: 3 required, 2 provided"
+        );
+    }
 }
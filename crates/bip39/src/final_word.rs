@@ -0,0 +1,300 @@
+//! Calculating the final word of a hand-picked phrase.
+//!
+//! In an offline seed ceremony the words are often chosen by drawing from a
+//! hat or by rolling dice, but the last word also carries the checksum and
+//! cannot be picked freely. Only [`WordCount::final_word_entropy_bits`] bits
+//! of it are entropy, so for 11 chosen words there are 128 valid final
+//! words and for 23 chosen words there are 8.
+//!
+//! [`Mnemonic::final_words`] lists every valid final word;
+//! [`Mnemonic::from_partial_phrase`] picks one from entropy bits the user
+//! supplies, such as a few coin flips.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Language, Mnemonic};
+//!
+//! let words = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+//! let candidates = Mnemonic::final_words(words, Language::English)?;
+//! assert_eq!(candidates.len(), 128);
+//! assert_eq!(candidates[0], "about");
+//!
+//! // Seven coin flips: heads, tails, tails, heads, heads, heads, tails
+//! let mnemonic = Mnemonic::from_partial_phrase(words, 0b1001110, Language::English)?;
+//! assert_eq!(mnemonic.phrase().split(' ').last(), Some(candidates[0b1001110]));
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use zeroize::Zeroizing;
+
+use crate::codec::{self, BITS_PER_WORD};
+use crate::{Error, Language, Mnemonic, Result, WordCount};
+
+impl Mnemonic {
+    /// Returns every final word that completes `partial_phrase` to a valid
+    /// mnemonic.
+    ///
+    /// The words are returned in wordlist order, which is also the order of
+    /// the entropy bits they carry: the word at position `n` is the one
+    /// [`from_partial_phrase`](Self::from_partial_phrase) picks for
+    /// `final_bits = n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial_phrase` - 11, 14, 17, 20 or 23 words; unique four-letter
+    ///   prefixes are expanded as in [`from_phrase`](Self::from_phrase)
+    /// * `language` - The language of the words
+    ///
+    /// # Returns
+    ///
+    /// 2^[`final_word_entropy_bits`](WordCount::final_word_entropy_bits)
+    /// words, from 128 for a 12-word phrase to 8 for a 24-word phrase
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidMnemonic`] - If the number of words is not one less
+    ///   than a valid word count
+    /// * [`Error::InvalidWord`] - If a word is not in the wordlist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic};
+    ///
+    /// let words = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo";
+    /// let candidates = Mnemonic::final_words(words, Language::English)?;
+    /// assert_eq!(candidates.len(), 8);
+    /// assert!(candidates.contains(&"vote"));
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn final_words(partial_phrase: &str, language: Language) -> Result<Vec<&'static str>> {
+        let (indices, word_count) = parse_partial_phrase(partial_phrase, &language)?;
+        let word_list = language.word_list();
+        (0..1u16 << word_count.final_word_entropy_bits())
+            .map(|final_bits| {
+                let entropy = entropy_with_final_bits(&indices, word_count, final_bits);
+                let final_index = *codec::entropy_to_indices(&entropy)?
+                    .last()
+                    .expect("phrases have at least 12 words");
+                Ok(word_list[usize::from(final_index)])
+            })
+            .collect()
+    }
+
+    /// Completes `partial_phrase` with the final word selected by
+    /// `final_bits`.
+    ///
+    /// `final_bits` supplies the entropy bits of the final word, most
+    /// significant bit first, so recording
+    /// [`final_word_entropy_bits`](WordCount::final_word_entropy_bits) coin
+    /// flips as ones and zeros picks the word without trusting a computer's
+    /// random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial_phrase` - 11, 14, 17, 20 or 23 words; unique four-letter
+    ///   prefixes are expanded as in [`from_phrase`](Self::from_phrase)
+    /// * `final_bits` - The final word's entropy bits, below
+    ///   2^[`final_word_entropy_bits`](WordCount::final_word_entropy_bits)
+    /// * `language` - The language of the words
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidMnemonic`] - If the number of words is not one less
+    ///   than a valid word count
+    /// * [`Error::InvalidWord`] - If a word is not in the wordlist
+    /// * [`Error::InvalidEntropyInput`] - If `final_bits` has more bits than
+    ///   the final word carries
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic, WordCount};
+    ///
+    /// let words = "legal winner thank year wave sausage worth useful legal winner thank";
+    /// let mnemonic = Mnemonic::from_partial_phrase(words, 0b1111111, Language::English)?;
+    /// assert_eq!(mnemonic.word_count(), WordCount::Twelve);
+    /// assert!(mnemonic.phrase().ends_with("yellow"));
+    ///
+    /// assert!(Mnemonic::from_partial_phrase(words, 128, Language::English).is_err());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn from_partial_phrase(
+        partial_phrase: &str,
+        final_bits: u16,
+        language: Language,
+    ) -> Result<Self> {
+        let (indices, word_count) = parse_partial_phrase(partial_phrase, &language)?;
+        let available_bits = word_count.final_word_entropy_bits();
+        if final_bits >= 1 << available_bits {
+            return Err(Error::InvalidEntropyInput {
+                reason: format!(
+                    "The final word of a {}-word phrase carries {available_bits} bits; \
+                     {final_bits} does not fit",
+                    word_count.word_count()
+                ),
+            });
+        }
+        let entropy = entropy_with_final_bits(&indices, word_count, final_bits);
+        Mnemonic::new(&entropy, language)
+    }
+}
+
+/// Looks up the words of a phrase that is missing its final word.
+///
+/// Returns their wordlist indices and the word count of the completed phrase.
+fn parse_partial_phrase(
+    partial_phrase: &str,
+    language: &Language,
+) -> Result<(Zeroizing<Vec<u16>>, WordCount)> {
+    let words: Vec<&str> = partial_phrase.split_whitespace().collect();
+    let word_count =
+        WordCount::from_word_count(words.len() + 1).map_err(|_| Error::InvalidMnemonic {
+            reason: format!(
+                "Expected 11, 14, 17, 20 or 23 words before the final word, found {}",
+                words.len()
+            ),
+        })?;
+
+    let indices = words
+        .iter()
+        .enumerate()
+        .map(|(position, &word)| {
+            let word = language.expand_prefix(word).unwrap_or(word);
+            language.index_of(word).ok_or_else(|| Error::InvalidWord {
+                word: word.into(),
+                position,
+            })
+        })
+        .collect::<Result<Vec<u16>>>()?;
+    Ok((Zeroizing::new(indices), word_count))
+}
+
+/// Packs the leading word indices followed by the final word's entropy bits.
+fn entropy_with_final_bits(
+    indices: &[u16],
+    word_count: WordCount,
+    final_bits: u16,
+) -> Zeroizing<Vec<u8>> {
+    let mut entropy = Zeroizing::new(vec![0u8; word_count.entropy_length()]);
+    let fields = indices
+        .iter()
+        .map(|&index| (index, BITS_PER_WORD))
+        .chain([(final_bits, word_count.final_word_entropy_bits())]);
+    let mut offset = 0;
+    for (value, width) in fields {
+        for bit in (0..width).rev() {
+            if (value >> bit) & 1 == 1 {
+                entropy[offset / 8] |= 0x80 >> (offset % 8);
+            }
+            offset += 1;
+        }
+    }
+    entropy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drops the final word of a phrase.
+    fn leading_words(phrase: &str) -> String {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        words[..words.len() - 1].join(" ")
+    }
+
+    #[test]
+    fn test_candidate_counts() {
+        for &word_count in WordCount::all_variants() {
+            let entropy = vec![0x35; word_count.entropy_length()];
+            let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+            let candidates =
+                Mnemonic::final_words(&leading_words(mnemonic.phrase()), Language::English)
+                    .unwrap();
+            assert_eq!(candidates.len(), 1 << word_count.final_word_entropy_bits());
+        }
+    }
+
+    #[test]
+    fn test_every_candidate_is_valid() {
+        let mnemonic = Mnemonic::new(&[0xa1; 20], Language::Italian).unwrap();
+        let words = leading_words(mnemonic.phrase());
+        let candidates = Mnemonic::final_words(&words, Language::Italian).unwrap();
+
+        // The original final word is among them
+        let last = mnemonic.phrase().split(' ').next_back().unwrap();
+        assert!(candidates.contains(&last));
+
+        for (final_bits, candidate) in candidates.iter().enumerate() {
+            let phrase = format!("{words} {candidate}");
+            let parsed = Mnemonic::from_phrase_confirmed(&phrase, Language::Italian).unwrap();
+            let completed =
+                Mnemonic::from_partial_phrase(&words, final_bits as u16, Language::Italian)
+                    .unwrap();
+            assert_eq!(completed, parsed);
+        }
+    }
+
+    #[test]
+    fn test_final_bits_are_the_last_entropy_bits() {
+        let entropy = hex::decode("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f").unwrap();
+        let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+        let completed = Mnemonic::from_partial_phrase(
+            &leading_words(mnemonic.phrase()),
+            0x7f,
+            Language::English,
+        )
+        .unwrap();
+        assert_eq!(completed.entropy(), &entropy[..]);
+    }
+
+    #[test]
+    fn test_prefixes_and_japanese() {
+        let mnemonic = Mnemonic::new(&[0x0f; 32], Language::Japanese).unwrap();
+        let words = leading_words(mnemonic.phrase());
+        let candidates = Mnemonic::final_words(&words, Language::Japanese).unwrap();
+        assert_eq!(candidates.len(), 8);
+
+        let abbreviated = "aban abil abou abov abse abso abst absu abus acce acci";
+        assert_eq!(
+            Mnemonic::final_words(abbreviated, Language::English).unwrap(),
+            Mnemonic::final_words(
+                "abandon ability about above absent absorb abstract absurd abuse access accident",
+                Language::English
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(
+            Mnemonic::final_words("abandon abandon", Language::English),
+            Err(Error::InvalidMnemonic { .. })
+        ));
+        assert!(matches!(
+            Mnemonic::final_words("", Language::English),
+            Err(Error::InvalidMnemonic { .. })
+        ));
+        let unknown = "abandon abandon abandon abandon bitcoin abandon abandon abandon abandon abandon abandon";
+        assert_eq!(
+            Mnemonic::final_words(unknown, Language::English),
+            Err(Error::InvalidWord {
+                word: "bitcoin".into(),
+                position: 4
+            })
+        );
+
+        let words = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo";
+        assert!(Mnemonic::from_partial_phrase(words, 7, Language::English).is_ok());
+        assert!(matches!(
+            Mnemonic::from_partial_phrase(words, 8, Language::English),
+            Err(Error::InvalidEntropyInput { .. })
+        ));
+    }
+}
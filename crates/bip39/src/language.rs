@@ -7,7 +7,9 @@
 //!
 //! Currently supported languages match the BIP39 specification:
 //! - English (default and most widely used)
-//! - Japanese, Korean, French, Italian, Spanish, etc. (via upstream crate)
+//! - Japanese, Korean, French, Italian, Spanish, Chinese (Simplified and
+//!   Traditional), Czech and Portuguese
+//! - User-supplied wordlists via [`Language::Custom`]
 //!
 //! # Examples
 //!
@@ -22,6 +24,15 @@
 //! // assert!(validate_phrase_in_language(japanese_phrase, Language::Japanese).is_ok());
 //! ```
 
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::utils::edit_distance;
+use crate::wordlists::{self, Wordlist};
+
 /// Supported languages for BIP39 mnemonic phrases.
 ///
 /// This enum represents all languages supported by the BIP39 specification.
@@ -33,8 +44,12 @@
 /// [`English`] is the default and most widely supported language across
 /// cryptocurrency applications and hardware wallets.
 ///
+/// New languages may be added in minor releases, so matches on `Language`
+/// need a wildcard arm.
+///
 /// [`English`]: Language::English
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Language {
     /// English language word list.
     ///
@@ -93,9 +108,28 @@ pub enum Language {
     /// Uses Czech words with proper diacritics and Czech spelling.
     /// Words chosen to be unambiguous in Czech language context.
     Czech,
+
+    /// Portuguese language word list.
+    ///
+    /// Uses Portuguese words without accents, chosen so that the first
+    /// four letters identify each word.
+    Portuguese,
+
+    /// A user-supplied wordlist.
+    ///
+    /// The [`Wordlist`] is validated against the BIP39 wordlist rules when it
+    /// is created and shared through an [`Arc`], so cloning the language is
+    /// cheap. Custom languages are not listed by
+    /// [`all_variants`](Language::all_variants).
+    Custom(Arc<Wordlist>),
 }
 
 impl Language {
+    /// The number of leading characters that uniquely identify a word.
+    ///
+    /// See [`expand_prefix`](Language::expand_prefix).
+    pub const UNIQUE_PREFIX_LENGTH: usize = 4;
+
     /// Returns the default language (English).
     ///
     /// # Examples
@@ -108,9 +142,10 @@ impl Language {
         Language::English
     }
 
-    /// Returns all supported language variants.
+    /// Returns all built-in language variants.
     ///
-    /// This is useful for iteration or UI language selection.
+    /// This is useful for iteration or UI language selection. Custom
+    /// wordlists are not included.
     ///
     /// # Examples
     ///
@@ -119,7 +154,7 @@ impl Language {
     /// let languages = Language::all_variants();
     /// assert!(languages.contains(&Language::English));
     /// assert!(languages.contains(&Language::Japanese));
-    /// assert_eq!(languages.len(), 9);
+    /// assert_eq!(languages.len(), 10);
     /// ```
     pub const fn all_variants() -> &'static [Language] {
         &[
@@ -132,6 +167,7 @@ impl Language {
             Language::SimplifiedChinese,
             Language::TraditionalChinese,
             Language::Czech,
+            Language::Portuguese,
         ]
     }
 
@@ -143,7 +179,7 @@ impl Language {
     /// # use khodpay_bip39::Language;
     /// assert_eq!(Language::English.name(), "English");
     /// ```
-    pub const fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'static str {
         match self {
             Language::English => "English",
             Language::Japanese => "Japanese",
@@ -154,17 +190,196 @@ impl Language {
             Language::SimplifiedChinese => "Simplified Chinese",
             Language::TraditionalChinese => "Traditional Chinese",
             Language::Czech => "Czech",
+            Language::Portuguese => "Portuguese",
+            Language::Custom(wordlist) => wordlist.name(),
+        }
+    }
+
+    /// Returns the separator placed between words when formatting a phrase.
+    ///
+    /// Japanese phrases use the ideographic space (U+3000), as in the BIP39
+    /// Japanese test vectors; every other language uses an ASCII space.
+    /// Parsing accepts any whitespace between words, and the separator is
+    /// normalized to an ASCII space before seed derivation, so it never
+    /// changes the seed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::Language;
+    /// assert_eq!(Language::English.word_separator(), " ");
+    /// assert_eq!(Language::Japanese.word_separator(), "\u{3000}");
+    /// ```
+    pub const fn word_separator(&self) -> &'static str {
+        match self {
+            Language::Japanese => "\u{3000}",
+            _ => " ",
         }
     }
 
-    /// Converts our Language enum to the upstream crate's Language type.
+    /// Returns the 2048-word BIP39 wordlist for this language.
+    ///
+    /// The position of a word in the list is its 11-bit index.
     ///
-    /// This is an internal conversion method used to interface with the
-    /// upstream BIP39 crate while maintaining our own API.
+    /// # Examples
     ///
-    /// With the `all-languages` feature enabled, all BIP39 standard languages
-    /// are now properly supported and mapped to their upstream variants.
-    pub(crate) const fn to_upstream(self) -> bip39_upstream::Language {
+    /// ```rust
+    /// # use khodpay_bip39::Language;
+    /// let words = Language::English.word_list();
+    /// assert_eq!(words.len(), 2048);
+    /// assert_eq!(words[0], "abandon");
+    /// assert_eq!(words[2047], "zoo");
+    /// ```
+    pub fn word_list(&self) -> &[&'static str; 2048] {
+        match self {
+            Language::English => &wordlists::english::WORDS,
+            Language::Japanese => &wordlists::japanese::WORDS,
+            Language::Korean => &wordlists::korean::WORDS,
+            Language::French => &wordlists::french::WORDS,
+            Language::Italian => &wordlists::italian::WORDS,
+            Language::Spanish => &wordlists::spanish::WORDS,
+            Language::SimplifiedChinese => &wordlists::chinese_simplified::WORDS,
+            Language::TraditionalChinese => &wordlists::chinese_traditional::WORDS,
+            Language::Czech => &wordlists::czech::WORDS,
+            Language::Portuguese => &wordlists::portuguese::WORDS,
+            Language::Custom(wordlist) => wordlist.words(),
+        }
+    }
+
+    /// Returns the word at `index`, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::Language;
+    /// assert_eq!(Language::English.word_at(3), Some("about"));
+    /// assert_eq!(Language::English.word_at(2048), None);
+    /// ```
+    pub fn word_at(&self, index: u16) -> Option<&'static str> {
+        self.word_list().get(index as usize).copied()
+    }
+
+    /// Returns the index of `word` in the wordlist, or `None` if it is not a BIP39 word.
+    ///
+    /// The lookup is case-insensitive and NFKD-normalizes `word` first, so
+    /// composed input such as `"économie"` matches the decomposed wordlist
+    /// entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::Language;
+    /// assert_eq!(Language::English.index_of("about"), Some(3));
+    /// assert_eq!(Language::English.index_of("ZOO"), Some(2047));
+    /// assert_eq!(Language::English.index_of("bitcoin"), None);
+    /// assert_eq!(Language::French.index_of("\u{e9}conomie"), Some(617));
+    /// ```
+    pub fn index_of(&self, word: &str) -> Option<u16> {
+        let word = normalize_word(word);
+        self.word_list()
+            .iter()
+            .position(|&w| w == word)
+            .map(|index| index as u16)
+    }
+
+    /// Returns all words starting with `prefix`, in wordlist order.
+    ///
+    /// This is intended for autocompletion while a user types a word.
+    /// The lookup is case-insensitive and NFKD-normalized like
+    /// [`index_of`](Language::index_of); an empty prefix matches every word.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::Language;
+    /// assert_eq!(
+    ///     Language::English.words_with_prefix("zo"),
+    ///     vec!["zone", "zoo"]
+    /// );
+    /// assert!(Language::English.words_with_prefix("xyz").is_empty());
+    /// ```
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<&'static str> {
+        let prefix = normalize_word(prefix);
+        self.word_list()
+            .iter()
+            .filter(|w| w.starts_with(&prefix))
+            .copied()
+            .collect()
+    }
+
+    /// Expands a word or unique prefix to the full wordlist entry.
+    ///
+    /// Returns the word itself if it is in the wordlist. Otherwise, a prefix of
+    /// at least [`UNIQUE_PREFIX_LENGTH`](Language::UNIQUE_PREFIX_LENGTH)
+    /// characters is expanded when exactly one word starts with it. BIP39
+    /// wordlists are chosen so that the first four letters identify a word,
+    /// which is why many backups only record four letters per word. Input is
+    /// normalized like [`index_of`](Language::index_of).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::Language;
+    /// assert_eq!(Language::English.expand_prefix("aban"), Some("abandon"));
+    /// assert_eq!(Language::English.expand_prefix("zoo"), Some("zoo"));
+    ///
+    /// // Too short to be unambiguous
+    /// assert_eq!(Language::English.expand_prefix("ab"), None);
+    /// ```
+    pub fn expand_prefix(&self, prefix: &str) -> Option<&'static str> {
+        let prefix = normalize_word(prefix);
+        let word_list = self.word_list();
+        if let Some(word) = word_list.iter().find(|&&w| w == prefix) {
+            return Some(word);
+        }
+        if prefix.chars().count() < Self::UNIQUE_PREFIX_LENGTH {
+            return None;
+        }
+
+        let mut matches = word_list.iter().filter(|w| w.starts_with(&prefix));
+        match (matches.next(), matches.next()) {
+            (Some(word), None) => Some(word),
+            _ => None,
+        }
+    }
+
+    /// Returns up to `limit` wordlist entries closest to `word`, nearest first.
+    ///
+    /// Words are ranked by Levenshtein edit distance, with ties broken by
+    /// wordlist order. This is useful for "did you mean" hints when a typed
+    /// word is not in the wordlist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::Language;
+    /// let suggestions = Language::English.suggestions("abandn", 3);
+    /// assert_eq!(suggestions[0], "abandon");
+    /// assert_eq!(suggestions.len(), 3);
+    /// ```
+    pub fn suggestions(&self, word: &str, limit: usize) -> Vec<&'static str> {
+        let word = normalize_word(word);
+        let mut ranked: Vec<(usize, &'static str)> = self
+            .word_list()
+            .iter()
+            .map(|&w| (edit_distance(&word, w), w))
+            .collect();
+        // Stable sort keeps wordlist order among equally distant words.
+        ranked.sort_by_key(|&(distance, _)| distance);
+        ranked.into_iter().take(limit).map(|(_, w)| w).collect()
+    }
+}
+
+/// Lowercases and NFKD-normalizes user input to match the wordlists.
+pub(crate) fn normalize_word(word: &str) -> String {
+    word.to_lowercase().nfkd().collect()
+}
+
+#[cfg(test)]
+impl Language {
+    /// Converts to the upstream `bip39` crate's language, for cross-checking
+    /// this implementation against it in tests.
+    pub(crate) fn to_upstream(&self) -> bip39_upstream::Language {
         match self {
             Language::English => bip39_upstream::Language::English,
             Language::Japanese => bip39_upstream::Language::Japanese,
@@ -175,6 +390,8 @@ impl Language {
             Language::SimplifiedChinese => bip39_upstream::Language::SimplifiedChinese,
             Language::TraditionalChinese => bip39_upstream::Language::TraditionalChinese,
             Language::Czech => bip39_upstream::Language::Czech,
+            Language::Portuguese => bip39_upstream::Language::Portuguese,
+            Language::Custom(_) => panic!("custom wordlists have no upstream equivalent"),
         }
     }
 }
@@ -207,12 +424,13 @@ mod tests {
         assert_eq!(Language::SimplifiedChinese.name(), "Simplified Chinese");
         assert_eq!(Language::TraditionalChinese.name(), "Traditional Chinese");
         assert_eq!(Language::Czech.name(), "Czech");
+        assert_eq!(Language::Portuguese.name(), "Portuguese");
     }
 
     #[test]
     fn test_all_variants() {
         let variants = Language::all_variants();
-        assert_eq!(variants.len(), 9);
+        assert_eq!(variants.len(), 10);
         assert!(variants.contains(&Language::English));
         assert!(variants.contains(&Language::Japanese));
         assert!(variants.contains(&Language::Korean));
@@ -222,56 +440,16 @@ mod tests {
         assert!(variants.contains(&Language::SimplifiedChinese));
         assert!(variants.contains(&Language::TraditionalChinese));
         assert!(variants.contains(&Language::Czech));
-    }
-
-    #[test]
-    fn test_to_upstream_conversion() {
-        // Test that our enum values convert correctly to upstream types
-        assert_eq!(
-            Language::English.to_upstream(),
-            bip39_upstream::Language::English
-        );
-        assert_eq!(
-            Language::Japanese.to_upstream(),
-            bip39_upstream::Language::Japanese
-        );
-        assert_eq!(
-            Language::Korean.to_upstream(),
-            bip39_upstream::Language::Korean
-        );
-        assert_eq!(
-            Language::French.to_upstream(),
-            bip39_upstream::Language::French
-        );
-        assert_eq!(
-            Language::Italian.to_upstream(),
-            bip39_upstream::Language::Italian
-        );
-        assert_eq!(
-            Language::Spanish.to_upstream(),
-            bip39_upstream::Language::Spanish
-        );
-        assert_eq!(
-            Language::SimplifiedChinese.to_upstream(),
-            bip39_upstream::Language::SimplifiedChinese
-        );
-        assert_eq!(
-            Language::TraditionalChinese.to_upstream(),
-            bip39_upstream::Language::TraditionalChinese
-        );
-        assert_eq!(
-            Language::Czech.to_upstream(),
-            bip39_upstream::Language::Czech
-        );
+        assert!(variants.contains(&Language::Portuguese));
     }
 
     #[test]
     fn test_language_equality() {
         assert_eq!(Language::English, Language::English);
 
-        // Test that copies are equal
+        // Test that clones are equal
         let lang1 = Language::English;
-        let lang2 = lang1;
+        let lang2 = lang1.clone();
         assert_eq!(lang1, lang2);
     }
 
@@ -281,4 +459,50 @@ mod tests {
         let debug_output = format!("{:?}", Language::English);
         assert!(debug_output.contains("English"));
     }
+
+    #[test]
+    fn test_word_list_and_index_mapping() {
+        for language in Language::all_variants() {
+            let words = language.word_list();
+            for (index, &word) in words.iter().enumerate() {
+                assert_eq!(language.word_at(index as u16), Some(word));
+                assert_eq!(language.index_of(word), Some(index as u16));
+            }
+            assert_eq!(language.word_at(2048), None);
+        }
+    }
+
+    #[test]
+    fn test_words_with_prefix() {
+        let words = Language::English.words_with_prefix("aba");
+        assert_eq!(words, vec!["abandon"]);
+
+        let words = Language::English.words_with_prefix("AC");
+        assert!(words.len() > 1);
+        assert!(words.iter().all(|w| w.starts_with("ac")));
+
+        assert_eq!(Language::English.words_with_prefix("").len(), 2048);
+    }
+
+    #[test]
+    fn test_expand_prefix_english() {
+        // Every English word is identified by its first four letters.
+        for &word in Language::English.word_list() {
+            let prefix: String = word.chars().take(4).collect();
+            assert_eq!(Language::English.expand_prefix(&prefix), Some(word));
+        }
+        assert_eq!(Language::English.expand_prefix("ACTI"), Some("action"));
+        assert_eq!(Language::English.expand_prefix("act"), Some("act"));
+        assert_eq!(Language::English.expand_prefix("acti"), Some("action"));
+        assert_eq!(Language::English.expand_prefix("acx"), None);
+        assert_eq!(Language::English.expand_prefix("xxxx"), None);
+    }
+
+    #[test]
+    fn test_suggestions() {
+        assert_eq!(Language::English.suggestions("abuot", 1), vec!["about"]);
+        assert_eq!(Language::English.suggestions("zoo", 1), vec!["zoo"]);
+        assert!(Language::English.suggestions("zoo", 0).is_empty());
+        assert_eq!(Language::English.suggestions("anything", 5).len(), 5);
+    }
 }
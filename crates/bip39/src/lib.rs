@@ -12,7 +12,7 @@
 //! ## Features
 //!
 //! - **Full BIP39 Compliance** - Implements the complete BIP39 specification
-//! - **Multi-Language Support** - 9 languages supported
+//! - **Multi-Language Support** - 10 languages supported, plus custom wordlists
 //! - **Type-Safe API** - Leverages Rust's type system for safety
 //! - **Comprehensive Testing** - 184+ tests including unit, doc, and integration tests
 //! - **Cryptographically Secure** - Uses system CSPRNG for entropy generation
@@ -57,12 +57,130 @@
 //!
 //! ### [`Language`]
 //!
-//! Enum for supported languages (English, Japanese, Korean, Spanish, French, Italian, Czech, Portuguese, Chinese Simplified).
+//! Enum for supported languages (English, Japanese, Korean, Spanish, French, Italian, Czech, Portuguese,
+//! Chinese Simplified and Traditional), plus [`Language::Custom`] for a user-supplied [`Wordlist`]
+//! that is validated against the BIP39 wordlist rules.
+//! Each language exposes its wordlist for autocompletion: prefix search,
+//! unique-prefix expansion, nearest-word suggestions and index↔word mapping.
 //!
 //! ### [`Error`]
 //!
 //! Comprehensive error type for all BIP39 operations.
 //!
+//! ## Unicode Normalization
+//!
+//! Word lookups and seed derivation NFKD-normalize their input, so phrases and
+//! passphrases typed in composed or decomposed form behave the same and derive
+//! the standard BIP39 seed. Japanese phrases are formatted with the ideographic
+//! space (U+3000) between words ([`Language::word_separator`]); any whitespace
+//! is accepted when parsing. The official Japanese test vectors, which use
+//! non-ASCII passphrases, are part of the test suite.
+//!
+//! ## Language Detection
+//!
+//! [`Language::detect`] returns every language in which a phrase is a valid
+//! mnemonic, with per-language word match counts ([`LanguageDetection`]).
+//! [`Mnemonic::from_phrase`] rejects phrases that are valid in several
+//! built-in languages with [`Error::AmbiguousLanguage`]; parse them with
+//! [`Mnemonic::from_phrase_confirmed`] once the user has picked the language.
+//!
+//! ## Backup Schemes
+//!
+//! - **SLIP-39** - [`Mnemonic::to_slip39_shares`] / [`Mnemonic::from_slip39_shares`] split
+//!   the entropy into Shamir share groups ([`Slip39Group`], [`Slip39Share`])
+//! - **Codex32 (BIP-93)** - [`Mnemonic::to_codex32`] / [`Mnemonic::from_codex32`] convert to
+//!   hand-verifiable [`Codex32String`] secrets, split with [`codex32_split`]
+//! - **Seed XOR** - [`Mnemonic::to_seed_xor_parts`] / [`Mnemonic::from_seed_xor_parts`] split
+//!   a mnemonic into valid decoy mnemonics whose entropies XOR to the original
+//! - **SeedQR** - [`Mnemonic::to_seed_qr`] / [`Mnemonic::to_compact_seed_qr`] produce the
+//!   SeedSigner QR payloads, rendered as a [`QrCode`] with text or SVG output, and
+//!   [`Mnemonic::from_seed_qr_payload`] decodes a scanned payload in either format
+//!
+//! ## Entropy Sources
+//!
+//! [`Mnemonic::generate`] uses the OS random number generator ([`OsEntropy`]).
+//! [`Mnemonic::generate_with_source`] and [`generate_mnemonic_with_source`] accept
+//! any [`EntropySource`], such as [`DeterministicEntropy`] for reproducible tests,
+//! [`MixedEntropy`] to combine generators, or [`LoggingEntropy`] for quality metrics.
+//!
+//! ## Physical Entropy
+//!
+//! [`Mnemonic::from_physical_entropy`] builds a mnemonic from d6/d20 dice rolls,
+//! coin flips or a shuffled card deck ([`PhysicalEntropy`]), following the
+//! Coldcard/SeedSigner dice convention, with optional OS randomness mixing.
+//!
+//! For phrases whose words were picked by hand, [`Mnemonic::final_words`] lists
+//! every checksum-valid final word and [`Mnemonic::from_partial_phrase`] selects
+//! one from the final word's entropy bits (e.g. coin flips).
+//!
+//! ## Electrum Seeds
+//!
+//! [`ElectrumMnemonic`] parses Electrum "new" seed phrases, which are not BIP39,
+//! detects their [`ElectrumSeedType`] (standard, segwit, 2FA, 2FA segwit) and
+//! derives the BIP32 master seed with Electrum's salt and normalization.
+//!
+//! ## LND Aezeed
+//!
+//! [`AezeedCipherSeed`] recognizes LND's 24-word aezeed backups: it decodes the
+//! words, verifies the CRC-32C checksum and exposes the version, scrypt salt
+//! and AEZ ciphertext. [`AezeedCipherSeed::decipher`] decrypts it with the
+//! passphrase into an [`AezeedEntropy`] holding the wallet birthday and entropy.
+//!
+//! ## Secret Handling
+//!
+//! [`Mnemonic::to_seed`] returns a [`Seed`], which is zeroized on drop, redacts
+//! its `Debug` output and compares in constant time. [`Mnemonic::seed_deriver`]
+//! returns a [`SeedDeriver`] that derives seeds for many passphrases while
+//! normalizing the phrase and keying HMAC-SHA512 only once. [`Mnemonic`] likewise
+//! zeroizes its phrase and entropy and never prints them with `Debug`.
+//!
+//! ## Backup Quiz
+//!
+//! [`Mnemonic::backup_quiz`] creates a [`BackupQuiz`] that asks for randomly
+//! chosen words, checks answers in constant time and tracks which questions
+//! have been answered correctly.
+//!
+//! ## Weak Mnemonics
+//!
+//! [`Mnemonic::risk_report`] flags phrases that are valid but unsafe: published
+//! test vectors and development-tool phrases, repeated or sequential words,
+//! and repeating or biased entropy. The [`RiskReport`] lists each
+//! [`MnemonicRisk`] found.
+//!
+//! ## Vaults
+//!
+//! [`Mnemonic::seal`] encrypts a mnemonic into a versioned vault file
+//! (Argon2id or scrypt key derivation, XChaCha20-Poly1305) with an optional
+//! passphrase hint and metadata, readable through [`SealedMnemonic`] before
+//! [`Mnemonic::unseal`] decrypts it. KDF costs are checked against
+//! [`KdfLimits`] so a crafted vault cannot exhaust memory or CPU.
+//!
+//! ## Feature Flags
+//!
+//! The BIP39 engine (wordlists, checksum and PBKDF2-HMAC-SHA512 seed
+//! stretching) is implemented in this crate and builds under `no_std` with
+//! `alloc`, so the same API runs on embedded signing firmware:
+//!
+//! - `std` *(default)* - Implements `std::error::Error` and enables the
+//!   standard library in dependencies
+//! - `rand` *(default)* - Operating-system randomness: [`OsEntropy`],
+//!   [`Mnemonic::generate`], [`generate_mnemonic`], and the randomized splits
+//!   and vault sealing that use it
+//!
+//! Without `rand`, mnemonics are generated with
+//! [`Mnemonic::generate_with_source`] from any [`EntropySource`], and vaults
+//! are sealed with [`Mnemonic::seal_with_source`].
+//!
+//! ```toml
+//! [dependencies]
+//! khodpay-bip39 = { version = "0.4", default-features = false }
+//! ```
+//!
+//! ## Recovery
+//!
+//! [`PhraseRecovery`] searches for the phrases a damaged backup could have been,
+//! filling in unknown (`?`) words and correcting misspellings, ranked by edit distance.
+//!
 //! ## Usage Examples
 //!
 //! ### Creating a New Wallet
@@ -133,7 +251,7 @@
 //!
 //! ### ⚠️ Important Security Notes
 //!
-//! 1. **Entropy Generation**: Uses system CSPRNG (`rand::thread_rng()`)
+//! 1. **Entropy Generation**: Uses the operating system CSPRNG ([`OsEntropy`])
 //! 2. **Mnemonic Storage**: Never store mnemonics in plain text
 //! 3. **Passphrase Security**: Passphrases add a "25th word" for additional security
 //! 4. **Memory Safety**: Consider zeroing sensitive data after use
@@ -172,19 +290,67 @@
 //! - [BIP39 Specification](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki)
 //! - [BIP32 HD Wallets](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki)
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 // Module declarations
+mod aez;
+mod aezeed;
+mod codec;
+mod codex32;
+mod detection;
+mod electrum;
+mod entropy;
 mod error;
+mod final_word;
 mod language;
 mod mnemonic;
+mod pbkdf2_lanes;
+mod physical_entropy;
+mod qr;
+mod quiz;
+mod recovery;
+mod risk;
+mod seed;
+mod seed_qr;
+mod seed_xor;
+mod slip39;
 mod utils;
+mod vault;
 mod word_count;
+mod wordlists;
 
 // Public re-exports
+pub use aezeed::{AezeedCipherSeed, AezeedEntropy};
+#[cfg(feature = "rand")]
+pub use codex32::codex32_split;
+pub use codex32::{codex32_combine, Codex32String};
+pub use detection::{LanguageDetection, LanguageScore};
+pub use electrum::{electrum_seed_type, ElectrumMnemonic, ElectrumSeedType};
+pub use entropy::{
+    DeterministicEntropy, EntropyMetrics, EntropySource, LoggingEntropy, MixedEntropy,
+};
+#[cfg(feature = "rand")]
+pub use entropy::{OsEntropy, RngEntropy};
 pub use error::{Error, Result};
 pub use language::Language;
 pub use mnemonic::Mnemonic;
+pub use physical_entropy::{PhysicalEntropy, PhysicalEntropyKind};
+pub use qr::QrCode;
+pub use quiz::BackupQuiz;
+pub use recovery::{PhraseRecovery, RecoveryCandidate, UNKNOWN_WORD};
+pub use risk::{MnemonicRisk, RiskReport};
+pub use seed::{Seed, SeedDeriver};
+#[cfg(feature = "rand")]
+pub use slip39::slip39_split;
+pub use slip39::{slip39_combine, Slip39Group, Slip39Share};
+#[cfg(feature = "rand")]
+pub use utils::{generate_mnemonic, generate_mnemonic_in_language};
 pub use utils::{
-    generate_mnemonic, generate_mnemonic_in_language, phrase_to_seed, phrase_to_seed_in_language,
-    validate_phrase, validate_phrase_in_language,
+    generate_mnemonic_with_source, phrase_to_seed, phrase_to_seed_in_language, validate_phrase,
+    validate_phrase_in_language,
 };
+pub use vault::{Kdf, KdfLimits, SealedMnemonic, VaultOptions};
 pub use word_count::WordCount;
+pub use wordlists::Wordlist;
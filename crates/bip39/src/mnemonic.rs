@@ -24,7 +24,18 @@
 //! // let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
 //! ```
 
-use crate::{Language, WordCount};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+use crate::codec;
+use crate::utils::constant_time_eq;
+#[cfg(feature = "rand")]
+use crate::OsEntropy;
+use crate::{EntropySource, Language, Seed, SeedDeriver, WordCount};
 
 /// A BIP39 mnemonic phrase with associated metadata.
 ///
@@ -47,6 +58,12 @@ use crate::{Language, WordCount};
 /// - The word count corresponds to the entropy length
 /// - All words are from the specified language's wordlist
 ///
+/// # Security
+///
+/// The phrase and entropy are zeroized when the mnemonic is dropped, the
+/// `Debug` output shows only the language and word count, and equality
+/// compares the entropy in constant time.
+///
 /// # Construction
 ///
 /// Mnemonics can be created through several constructors:
@@ -64,11 +81,11 @@ use crate::{Language, WordCount};
 /// // let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
 /// // assert_eq!(mnemonic.word_count(), WordCount::Twelve);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct Mnemonic {
     /// The mnemonic phrase as a space-separated string.
     /// Contains 12, 15, 18, 21, or 24 words from the specified language's wordlist.
-    phrase: String,
+    phrase: Zeroizing<String>,
 
     /// The language of the mnemonic phrase.
     /// Determines which BIP39 wordlist is used for validation and word selection.
@@ -77,7 +94,7 @@ pub struct Mnemonic {
     /// The raw entropy bytes used to generate this mnemonic.
     /// Length must be 16, 20, 24, 28, or 32 bytes (128, 160, 192, 224, or 256 bits).
     /// The mnemonic is derived from this entropy plus a checksum.
-    entropy: Vec<u8>,
+    entropy: Zeroizing<Vec<u8>>,
 
     /// The number of words in the mnemonic phrase.
     /// Valid values are 12, 15, 18, 21, or 24 words.
@@ -121,34 +138,16 @@ impl Mnemonic {
     /// assert_eq!(mnemonic.word_count().word_count(), 12);
     /// ```
     pub fn new(entropy: &[u8], language: Language) -> crate::Result<Self> {
-        use crate::Error;
-
         // Step 1: Validate entropy length and determine word count
         let word_count = WordCount::from_entropy_length(entropy.len())?;
 
-        // Step 2: Convert language to upstream format
-        let upstream_language = language.to_upstream();
-
-        // Step 3: Create mnemonic from entropy using upstream crate
-        // The upstream crate handles:
-        // - Entropy validation
-        // - Checksum calculation (appends checksum bits to entropy)
-        // - Word selection from language-specific wordlist
-        // - Proper formatting with spaces between words
-        let upstream_mnemonic =
-            bip39_upstream::Mnemonic::from_entropy_in(upstream_language, entropy).map_err(
-                |_| Error::InvalidEntropyLength {
-                    length: entropy.len(),
-                },
-            )?;
-
-        // Step 4: Extract the phrase string
-        let phrase = upstream_mnemonic.to_string();
-
-        // Step 5: Store entropy as Vec for owned data
-        let entropy = entropy.to_vec();
-
-        // Step 6: Construct and return the Mnemonic
+        // Step 2: Append the checksum and map each 11-bit group to a word
+        let phrase = codec::entropy_to_phrase(entropy, &language)?;
+
+        // Step 3: Store entropy as Vec for owned data
+        let entropy = Zeroizing::new(entropy.to_vec());
+
+        // Step 4: Construct and return the Mnemonic
         Ok(Self {
             phrase,
             language,
@@ -172,6 +171,20 @@ impl Mnemonic {
         self.word_count
     }
 
+    /// Returns the language of this mnemonic's wordlist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::new(&[0u8; 16], Language::French).unwrap();
+    /// assert_eq!(mnemonic.language(), Language::French);
+    /// ```
+    pub fn language(&self) -> Language {
+        self.language.clone()
+    }
+
     /// Returns the mnemonic phrase as a string slice.
     ///
     /// # Examples
@@ -215,9 +228,17 @@ impl Mnemonic {
     ///
     /// # Returns
     ///
-    /// * `Ok([u8; 64])` - A 64-byte cryptographic seed
+    /// * `Ok(Seed)` - A 64-byte cryptographic seed, zeroized on drop
     /// * `Err(Error)` - If seed generation fails
     ///
+    /// # Normalization
+    ///
+    /// As BIP39 requires, both the phrase and the passphrase are converted to
+    /// Unicode NFKD before hashing, and the salt is `"mnemonic"` followed by
+    /// the normalized passphrase. A passphrase typed in composed (NFC) or
+    /// decomposed form therefore derives the same seed, and the ideographic
+    /// space between Japanese words becomes an ASCII space.
+    ///
     /// # Security Note
     ///
     /// The passphrase adds an extra layer of security but must be remembered.
@@ -233,33 +254,34 @@ impl Mnemonic {
     ///
     /// // Without passphrase
     /// let seed = mnemonic.to_seed("").unwrap();
-    /// assert_eq!(seed.len(), 64);
+    /// assert_eq!(seed.as_bytes().len(), 64);
     ///
     /// // With passphrase
     /// let seed_with_pass = mnemonic.to_seed("my secret passphrase").unwrap();
-    /// assert_eq!(seed_with_pass.len(), 64);
+    /// assert_ne!(seed, seed_with_pass);
     /// ```
-    pub fn to_seed(&self, passphrase: &str) -> crate::Result<[u8; 64]> {
-        // Convert language to upstream format
-        let upstream_language = self.language.to_upstream();
-
-        // Parse the mnemonic phrase (already validated in constructor)
-        // Using parse_in_normalized for consistent normalization
-        let upstream_mnemonic =
-            bip39_upstream::Mnemonic::parse_in_normalized(upstream_language, &self.phrase)
-                .map_err(|_| crate::Error::InvalidMnemonic {
-                    reason: "Failed to parse mnemonic phrase for seed generation".to_string(),
-                })?;
-
-        // Generate the seed using PBKDF2-HMAC-SHA512
-        // The upstream crate handles:
-        // - Unicode NFKD normalization of phrase and passphrase
-        // - Salt = "mnemonic" + passphrase
-        // - 2048 iterations of PBKDF2-HMAC-SHA512
-        // - 512-bit (64-byte) output
-        let seed = upstream_mnemonic.to_seed(passphrase);
+    pub fn to_seed(&self, passphrase: &str) -> crate::Result<Seed> {
+        Ok(self.seed_deriver().derive(passphrase))
+    }
 
-        Ok(seed)
+    /// Returns a [`SeedDeriver`] for deriving seeds for many passphrases.
+    ///
+    /// [`to_seed`](Self::to_seed) normalizes the phrase and keys HMAC-SHA512
+    /// on every call; the deriver does so once. Use it when the same mnemonic
+    /// is tried with many passphrases, as in a passphrase search.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic};
+    ///
+    /// let mnemonic = Mnemonic::new(&[0x42; 16], Language::English)?;
+    /// let deriver = mnemonic.seed_deriver();
+    /// assert_eq!(deriver.derive("TREZOR"), mnemonic.to_seed("TREZOR")?);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn seed_deriver(&self) -> SeedDeriver {
+        SeedDeriver::new(&self.phrase)
     }
 
     /// Creates a `Mnemonic` by parsing an existing mnemonic phrase.
@@ -284,6 +306,10 @@ impl Mnemonic {
     /// - All words exist in the specified language's BIP39 wordlist
     /// - Valid checksum (last word encodes a checksum of the entropy)
     ///
+    /// Words may also be given as unique prefixes of at least four characters,
+    /// as written on many metal backups; they are expanded with
+    /// [`Language::expand_prefix`] and the stored phrase uses the full words.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -293,39 +319,88 @@ impl Mnemonic {
     /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
     /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
     /// assert_eq!(mnemonic.word_count().word_count(), 12);
+    ///
+    /// // The same phrase typed as four-letter prefixes
+    /// let short = "aban aban aban aban aban aban aban aban aban aban aban abou";
+    /// assert_eq!(Mnemonic::from_phrase(short, Language::English).unwrap(), mnemonic);
+    /// ```
+    ///
+    /// # Ambiguous Phrases
+    ///
+    /// A few phrases are valid in more than one built-in language, most often
+    /// the two Chinese wordlists. Rather than trusting the given language,
+    /// such phrases are rejected with [`Error::AmbiguousLanguage`], which
+    /// lists every matching language; once the user has confirmed one, parse
+    /// the phrase with [`from_phrase_confirmed`](Self::from_phrase_confirmed).
+    /// Custom wordlists are not checked for ambiguity.
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Error, Language, Mnemonic};
+    ///
+    /// let phrase = "的 的 的 的 的 的 的 的 的 的 的 在";
+    /// assert!(matches!(
+    ///     Mnemonic::from_phrase(phrase, Language::TraditionalChinese),
+    ///     Err(Error::AmbiguousLanguage { .. })
+    /// ));
+    ///
+    /// let mnemonic = Mnemonic::from_phrase_confirmed(phrase, Language::TraditionalChinese)?;
+    /// assert_eq!(mnemonic.language(), Language::TraditionalChinese);
+    /// # Ok::<(), khodpay_bip39::Error>(())
     /// ```
+    ///
+    /// [`Error::AmbiguousLanguage`]: crate::Error::AmbiguousLanguage
     pub fn from_phrase(phrase: &str, language: Language) -> crate::Result<Self> {
-        use crate::validate_phrase_in_language;
-
-        // Step 1: Validate the phrase first using our validation function
-        // This ensures the phrase has correct word count, valid words, and valid checksum
-        validate_phrase_in_language(phrase, language)?;
-
-        // Step 2: Convert language to upstream format
-        let upstream_language = language.to_upstream();
-
-        // Step 3: Parse the mnemonic using the upstream crate
-        // We've already validated it, so this should succeed
-        // Using parse_in_normalized to handle whitespace and case normalization
-        let upstream_mnemonic =
-            bip39_upstream::Mnemonic::parse_in_normalized(upstream_language, phrase).map_err(
-                |_| crate::Error::InvalidMnemonic {
-                    reason: "Failed to parse validated phrase".to_string(),
-                },
-            )?;
-
-        // Step 4: Extract the entropy from the parsed mnemonic
-        // The upstream crate stores the entropy internally
-        let entropy = upstream_mnemonic.to_entropy().to_vec();
+        let mnemonic = Self::from_phrase_confirmed(phrase, language.clone())?;
+
+        // Refuse to pick one of several built-in languages silently
+        if Language::all_variants().contains(&language) {
+            let detection = Language::detect(&mnemonic.phrase);
+            if detection.is_ambiguous() {
+                return Err(crate::Error::AmbiguousLanguage {
+                    languages: detection.languages(),
+                });
+            }
+        }
+        Ok(mnemonic)
+    }
 
-        // Step 5: Determine word count from entropy length
+    /// Parses a phrase in a language the user has confirmed.
+    ///
+    /// This is [`from_phrase`](Self::from_phrase) without the ambiguity
+    /// check: a phrase that is also valid in another language is parsed in
+    /// `language`. Use it after [`Language::detect`] or an
+    /// [`Error::AmbiguousLanguage`] error, once the user has chosen.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`from_phrase`](Self::from_phrase), except that
+    /// [`Error::AmbiguousLanguage`] is never returned.
+    ///
+    /// [`Error::AmbiguousLanguage`]: crate::Error::AmbiguousLanguage
+    pub fn from_phrase_confirmed(phrase: &str, language: Language) -> crate::Result<Self> {
+        // Step 0: Expand unique prefixes (e.g. "aban" -> "abandon")
+        // Words that don't expand are kept as typed so validation reports them
+        let expanded = Zeroizing::new(
+            phrase
+                .split_whitespace()
+                .map(|word| language.expand_prefix(word).unwrap_or(word))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        let phrase = expanded.as_str();
+
+        // Step 1: Decode the words to entropy
+        // This checks the word count, every word, and the checksum
+        let entropy = codec::phrase_to_entropy(phrase, &language)?;
+
+        // Step 2: Determine word count from entropy length
         let word_count = WordCount::from_entropy_length(entropy.len())?;
 
-        // Step 6: Get the normalized phrase string
-        // This ensures consistent formatting (lowercase, single spaces)
-        let phrase = upstream_mnemonic.to_string();
+        // Step 3: Re-encode the entropy to get the normalized phrase
+        // This ensures consistent formatting (lowercase, NFKD, one separator)
+        let phrase = codec::entropy_to_phrase(&entropy, &language)?;
 
-        // Step 7: Construct and return the Mnemonic
+        // Step 4: Construct and return the Mnemonic
         Ok(Self {
             phrase,
             language,
@@ -352,7 +427,8 @@ impl Mnemonic {
     /// # Security Note
     ///
     /// This function uses the system's cryptographically secure random number generator
-    /// (`rand::rngs::OsRng`). The generated mnemonic should be stored securely and
+    /// ([`OsEntropy`]). Use [`generate_with_source`](Self::generate_with_source) to supply
+    /// a different [`EntropySource`]. The generated mnemonic should be stored securely and
     /// backed up properly. Loss of the mnemonic means permanent loss of wallet access.
     ///
     /// # Examples
@@ -368,17 +444,49 @@ impl Mnemonic {
     /// let mnemonic_24 = Mnemonic::generate(WordCount::TwentyFour, Language::Japanese).unwrap();
     /// assert_eq!(mnemonic_24.word_count(), WordCount::TwentyFour);
     /// ```
+    #[cfg(feature = "rand")]
     pub fn generate(word_count: WordCount, language: Language) -> crate::Result<Self> {
-        use rand::rngs::OsRng;
-        use rand::RngCore;
+        // Uses OsRng for reliable entropy on mobile/static library targets
+        Self::generate_with_source(word_count, language, &mut OsEntropy)
+    }
 
+    /// Generates a new `Mnemonic` with entropy drawn from `source`.
+    ///
+    /// This is the same as [`generate`](Self::generate), which uses
+    /// [`OsEntropy`], but lets the caller choose where the entropy comes from.
+    ///
+    /// # Arguments
+    ///
+    /// * `word_count` - The number of words (12, 15, 18, 21, or 24)
+    /// * `language` - The language for the mnemonic phrase
+    /// * `source` - The source of random bytes
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Mnemonic)` - A new mnemonic built from the source's entropy
+    /// * `Err(Error)` - If the source fails to produce entropy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{DeterministicEntropy, Language, Mnemonic, WordCount};
+    ///
+    /// let mut source = DeterministicEntropy::new(b"fixture");
+    /// let mnemonic = Mnemonic::generate_with_source(WordCount::Eighteen, Language::English, &mut source)?;
+    /// assert_eq!(mnemonic.word_count(), WordCount::Eighteen);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn generate_with_source<S: EntropySource + ?Sized>(
+        word_count: WordCount,
+        language: Language,
+        source: &mut S,
+    ) -> crate::Result<Self> {
         // Step 1: Calculate the required entropy length
         let entropy_length = word_count.entropy_length();
 
-        // Step 2: Generate cryptographically secure random entropy
-        // Uses OsRng for reliable entropy on mobile/static library targets
-        let mut entropy = vec![0u8; entropy_length];
-        OsRng.fill_bytes(&mut entropy);
+        // Step 2: Fill the entropy from the source
+        let mut entropy = Zeroizing::new(vec![0u8; entropy_length]);
+        source.fill_entropy(&mut entropy)?;
 
         // Step 3: Use the `new()` constructor to create the Mnemonic
         // This handles entropy validation, checksum calculation, and phrase generation
@@ -386,6 +494,28 @@ impl Mnemonic {
     }
 }
 
+/// Compares the language and entropy, the latter in constant time.
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Self) -> bool {
+        self.language == other.language && constant_time_eq(&self.entropy, &other.entropy)
+    }
+}
+
+impl Eq for Mnemonic {}
+
+/// Shows only the language and word count; the phrase and entropy are redacted.
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("language", &self.language)
+            .field("word_count", &self.word_count)
+            .field("phrase", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl ZeroizeOnDrop for Mnemonic {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -589,9 +719,9 @@ mod tests {
         let entropy = [0u8; 16];
         let languages = Language::all_variants();
 
-        for &language in languages {
-            let mnemonic = Mnemonic::new(&entropy, language).unwrap();
-            assert_eq!(mnemonic.language, language);
+        for language in languages {
+            let mnemonic = Mnemonic::new(&entropy, language.clone()).unwrap();
+            assert_eq!(&mnemonic.language, language);
             assert_eq!(mnemonic.word_count(), WordCount::Twelve);
         }
     }
@@ -623,7 +753,7 @@ mod tests {
 
         assert_eq!(mnemonic.word_count(), WordCount::Twelve);
         assert_eq!(mnemonic.language, Language::English);
-        assert_eq!(mnemonic.phrase, VALID_12_WORD_PHRASE);
+        assert_eq!(mnemonic.phrase.as_str(), VALID_12_WORD_PHRASE);
 
         // Verify entropy is 16 bytes for 12 words
         assert_eq!(mnemonic.entropy.len(), 16);
@@ -636,7 +766,7 @@ mod tests {
 
         assert_eq!(mnemonic.word_count(), WordCount::TwentyFour);
         assert_eq!(mnemonic.language, Language::English);
-        assert_eq!(mnemonic.phrase, VALID_24_WORD_PHRASE);
+        assert_eq!(mnemonic.phrase.as_str(), VALID_24_WORD_PHRASE);
 
         // Verify entropy is 32 bytes for 24 words
         assert_eq!(mnemonic.entropy.len(), 32);
@@ -698,19 +828,17 @@ mod tests {
         let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
 
         // Should normalize to standard format
-        assert_eq!(mnemonic.phrase, VALID_12_WORD_PHRASE);
+        assert_eq!(mnemonic.phrase.as_str(), VALID_12_WORD_PHRASE);
     }
 
     #[test]
     fn test_from_phrase_mixed_case() {
         // BIP39 words should be case-insensitive
-        // Note: validate_phrase_in_language accepts mixed case,
-        // but upstream parse_in_normalized may be stricter
         let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
         let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
 
         // Should be normalized to lowercase
-        assert_eq!(mnemonic.phrase, VALID_12_WORD_PHRASE);
+        assert_eq!(mnemonic.phrase.as_str(), VALID_12_WORD_PHRASE);
 
         // Also test that validation layer handles mixed case correctly
         use crate::validate_phrase_in_language;
@@ -719,6 +847,30 @@ mod tests {
         assert!(validate_phrase_in_language(mixed_case, Language::English).is_ok());
     }
 
+    #[test]
+    fn test_from_phrase_with_four_letter_prefixes() {
+        let mnemonic = Mnemonic::new(&[0x7fu8; 32], Language::English).unwrap();
+        let prefixes = mnemonic
+            .phrase
+            .split_whitespace()
+            .map(|word| word.chars().take(4).collect::<String>())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let parsed = Mnemonic::from_phrase(&prefixes, Language::English).unwrap();
+        assert_eq!(parsed, mnemonic);
+
+        // Ambiguous short prefixes are still rejected
+        let short = VALID_12_WORD_PHRASE.replacen("abandon", "ab", 1);
+        assert_eq!(
+            Mnemonic::from_phrase(&short, Language::English).unwrap_err(),
+            Error::InvalidWord {
+                word: "ab".to_string(),
+                position: 0
+            }
+        );
+    }
+
     #[test]
     fn test_from_phrase_roundtrip_with_new() {
         // Create mnemonic from entropy, then parse it back
@@ -745,7 +897,7 @@ mod tests {
         let mnemonic2 = Mnemonic::from_phrase(phrase, Language::English).unwrap();
 
         // Entropy should match
-        assert_eq!(mnemonic2.entropy, entropy);
+        assert_eq!(mnemonic2.entropy(), entropy);
     }
 
     #[test]
@@ -758,7 +910,7 @@ mod tests {
         let mnemonic2 = Mnemonic::from_phrase(&mnemonic1.phrase, Language::Japanese).unwrap();
 
         assert_eq!(mnemonic2.language, Language::Japanese);
-        assert_eq!(mnemonic2.entropy, entropy);
+        assert_eq!(mnemonic2.entropy(), entropy);
         assert_eq!(mnemonic2, mnemonic1);
     }
 
@@ -807,16 +959,16 @@ mod tests {
             Language::French,
         ];
 
-        for (i, &language) in test_languages.iter().enumerate() {
+        for (i, language) in test_languages.into_iter().enumerate() {
             // Use different entropy for each language to avoid any potential ambiguity
             let mut entropy = [42u8; 16];
             entropy[0] = i as u8; // Make each entropy unique
 
-            let mnemonic1 = Mnemonic::new(&entropy, language).unwrap();
-            let mnemonic2 = Mnemonic::from_phrase(&mnemonic1.phrase, language).unwrap();
+            let mnemonic1 = Mnemonic::new(&entropy, language.clone()).unwrap();
+            let mnemonic2 = Mnemonic::from_phrase(&mnemonic1.phrase, language.clone()).unwrap();
 
             assert_eq!(mnemonic2.language, language);
-            assert_eq!(mnemonic2.entropy, entropy);
+            assert_eq!(mnemonic2.entropy(), entropy);
         }
     }
 
@@ -835,6 +987,7 @@ mod tests {
     // Tests for Mnemonic::generate() constructor (Task 17)
     // ============================================================================
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_12_words() {
         // Generate a 12-word mnemonic
@@ -849,6 +1002,7 @@ mod tests {
         assert_eq!(word_count, 12);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_24_words() {
         // Generate a 24-word mnemonic
@@ -861,6 +1015,7 @@ mod tests {
         assert_eq!(word_count, 24);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_all_word_counts() {
         // Test generating all valid word counts
@@ -872,6 +1027,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_randomness() {
         // Generate multiple mnemonics and verify they're different
@@ -888,6 +1044,7 @@ mod tests {
         assert_ne!(mnemonic2.entropy, mnemonic3.entropy);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_is_valid() {
         // Generated mnemonic should pass validation
@@ -897,6 +1054,7 @@ mod tests {
         assert!(validate_phrase_in_language(&mnemonic.phrase, Language::English).is_ok());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_can_create_seed() {
         // Generated mnemonic should be able to create a seed
@@ -905,9 +1063,10 @@ mod tests {
         let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
         let seed = phrase_to_seed_in_language(&mnemonic.phrase, "", Language::English).unwrap();
 
-        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.as_bytes().len(), 64);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_roundtrip_with_from_phrase() {
         // Generate mnemonic, then parse it back
@@ -920,6 +1079,7 @@ mod tests {
         assert_eq!(mnemonic1.phrase, mnemonic2.phrase);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_with_japanese() {
         // Generate Japanese mnemonic
@@ -933,6 +1093,7 @@ mod tests {
         assert!(validate_phrase_in_language(&mnemonic.phrase, Language::Japanese).is_ok());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_all_languages() {
         // Test generating mnemonics in different languages
@@ -944,11 +1105,12 @@ mod tests {
         ];
 
         for language in test_languages {
-            let mnemonic = Mnemonic::generate(WordCount::Twelve, language).unwrap();
+            let mnemonic = Mnemonic::generate(WordCount::Twelve, language.clone()).unwrap();
             assert_eq!(mnemonic.language, language);
         }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_entropy_is_random() {
         // Verify entropy is actually random
@@ -959,10 +1121,11 @@ mod tests {
         assert_ne!(mnemonic1.entropy, mnemonic2.entropy);
 
         // Neither should be all zeros (extremely unlikely)
-        assert_ne!(mnemonic1.entropy, vec![0u8; 16]);
-        assert_ne!(mnemonic2.entropy, vec![0u8; 16]);
+        assert_ne!(mnemonic1.entropy(), vec![0u8; 16]);
+        assert_ne!(mnemonic2.entropy(), vec![0u8; 16]);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_correct_entropy_length() {
         // Verify entropy length matches word count
@@ -980,6 +1143,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_produces_lowercase_words() {
         // Generated mnemonics should use lowercase words
@@ -990,6 +1154,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_clone() {
         // Test that generated mnemonics can be cloned
@@ -1001,6 +1166,7 @@ mod tests {
         assert_eq!(mnemonic1.phrase, mnemonic2.phrase);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_integration() {
         // Full workflow: generate → validate → create seed
@@ -1014,7 +1180,7 @@ mod tests {
         use crate::phrase_to_seed_in_language;
         let seed =
             phrase_to_seed_in_language(&mnemonic.phrase, "password", Language::English).unwrap();
-        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.as_bytes().len(), 64);
 
         // Roundtrip
         let mnemonic2 = Mnemonic::from_phrase(&mnemonic.phrase, Language::English).unwrap();
@@ -1040,7 +1206,7 @@ mod tests {
         let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
 
         // phrase() should return the same value as the internal field
-        assert_eq!(mnemonic.phrase(), mnemonic.phrase);
+        assert_eq!(mnemonic.phrase(), mnemonic.phrase.as_str());
     }
 
     #[test]
@@ -1074,7 +1240,7 @@ mod tests {
         let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
 
         let seed = mnemonic.to_seed("").unwrap();
-        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.as_bytes().len(), 64);
     }
 
     #[test]
@@ -1083,9 +1249,10 @@ mod tests {
         let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
 
         let seed = mnemonic.to_seed("TREZOR").unwrap();
-        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.as_bytes().len(), 64);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_to_seed_passphrase_affects_output() {
         let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
@@ -1099,6 +1266,7 @@ mod tests {
         assert_ne!(seed1, seed3);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_to_seed_deterministic() {
         let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
@@ -1109,6 +1277,7 @@ mod tests {
         assert_eq!(seed1, seed2);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_to_seed_matches_utility_function() {
         use crate::phrase_to_seed_in_language;
@@ -1141,15 +1310,17 @@ mod tests {
         assert_eq!(mnemonic.word_count(), WordCount::Twelve);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_to_seed_with_unicode_passphrase() {
         let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
 
         // Should handle Unicode passphrases correctly
         let seed = mnemonic.to_seed("test 日本語 🔑").unwrap();
-        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.as_bytes().len(), 64);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_phrase_from_all_constructors() {
         // phrase() should work for mnemonics created via different constructors
@@ -1171,6 +1342,7 @@ mod tests {
         assert_eq!(phrase3.split_whitespace().count(), 12);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_entropy_from_all_constructors() {
         let original_entropy = [42u8; 16];
@@ -1189,6 +1361,7 @@ mod tests {
         assert_ne!(mnemonic3.entropy(), &[0u8; 16]); // Very unlikely to be all zeros
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_to_seed_from_all_constructors() {
         let entropy = [0u8; 16];
@@ -1196,7 +1369,7 @@ mod tests {
         // All constructors should produce mnemonics that can create seeds
         let mnemonic1 = Mnemonic::new(&entropy, Language::English).unwrap();
         let seed1 = mnemonic1.to_seed("").unwrap();
-        assert_eq!(seed1.len(), 64);
+        assert_eq!(seed1.as_bytes().len(), 64);
 
         let mnemonic2 = Mnemonic::from_phrase(mnemonic1.phrase(), Language::English).unwrap();
         let seed2 = mnemonic2.to_seed("").unwrap();
@@ -1204,9 +1377,10 @@ mod tests {
 
         let mnemonic3 = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
         let seed3 = mnemonic3.to_seed("").unwrap();
-        assert_eq!(seed3.len(), 64);
+        assert_eq!(seed3.as_bytes().len(), 64);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_to_seed_case_sensitive_passphrase() {
         let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
@@ -1220,6 +1394,7 @@ mod tests {
         assert_ne!(seed1, seed3);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_to_seed_whitespace_in_passphrase() {
         let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
@@ -1233,4 +1408,53 @@ mod tests {
         assert_ne!(seed1, seed3);
         assert_ne!(seed2, seed3);
     }
+
+    #[test]
+    fn test_to_seed_trezor_vector() {
+        let mnemonic = Mnemonic::new(&[0u8; 16], Language::English).unwrap();
+        let seed = mnemonic.to_seed("TREZOR").unwrap();
+        assert_eq!(
+            hex::encode(seed.as_bytes()),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553\
+             1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_to_seed_matches_upstream_in_all_languages() {
+        // Composed and decomposed forms must normalize to the same seed
+        let passphrases = ["", "TREZOR", "caf\u{e9}", "㍍ガバヴァぱばぐゞちぢ十人十色"];
+        for language in Language::all_variants() {
+            let mnemonic = Mnemonic::generate(WordCount::Eighteen, language.clone()).unwrap();
+            let upstream =
+                bip39_upstream::Mnemonic::parse_in(language.to_upstream(), mnemonic.phrase())
+                    .unwrap();
+            for passphrase in passphrases {
+                assert_eq!(
+                    mnemonic.to_seed(passphrase).unwrap().as_bytes(),
+                    &upstream.to_seed(passphrase)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let mnemonic = Mnemonic::new(&[0u8; 16], Language::English).unwrap();
+        let debug = format!("{mnemonic:?}");
+        assert!(debug.contains("English"));
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("abandon"));
+        assert!(!debug.contains("[0, 0"));
+    }
+
+    #[test]
+    fn test_equality_compares_language_and_entropy() {
+        let a = Mnemonic::new(&[1u8; 16], Language::English).unwrap();
+        let b = Mnemonic::from_phrase(a.phrase(), Language::English).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, Mnemonic::new(&[1u8; 16], Language::French).unwrap());
+        assert_ne!(a, Mnemonic::new(&[2u8; 16], Language::English).unwrap());
+    }
 }
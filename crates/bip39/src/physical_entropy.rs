@@ -0,0 +1,640 @@
+//! Mnemonic generation from physical entropy.
+//!
+//! Cold-storage seeds are often generated on air-gapped machines where the
+//! operator does not want to trust the computer's random number generator.
+//! This module turns dice rolls, coin flips or the order of a shuffled card
+//! deck into BIP39 entropy.
+//!
+//! The recorded values are written out as a canonical string and hashed with
+//! SHA-256; the first bytes of the digest become the entropy. For six-sided
+//! dice this is the convention used by Coldcard and SeedSigner, so the same
+//! rolls produce the same mnemonic on those devices:
+//!
+//! | Words | Entropy | d6 rolls | d20 rolls | Coin flips | Cards |
+//! |-------|---------|----------|-----------|------------|-------|
+//! | 12    | 128 bit | 50       | 30        | 128        | 24    |
+//! | 24    | 256 bit | 99       | 60        | 256        | -     |
+//!
+//! Before hashing, the input is checked for enough entropy and for obvious
+//! bias (such as a loaded die or a stuck key). Optionally, the result can be
+//! mixed with OS randomness so that it is at least as strong as either source.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Language, Mnemonic, PhysicalEntropy, WordCount};
+//!
+//! let rolls = "16345236215634162534612345236142351624315263412563\
+//!              21456342165324615234156243516234512634152634152634";
+//! let source = PhysicalEntropy::d6(rolls)?;
+//!
+//! let mnemonic = Mnemonic::from_physical_entropy(&source, WordCount::Twelve, Language::English)?;
+//! assert_eq!(mnemonic.word_count(), WordCount::Twelve);
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+#[cfg(feature = "rand")]
+use crate::{EntropySource, OsEntropy};
+use crate::{Error, Language, Mnemonic, Result, WordCount};
+
+/// Card ranks in canonical order; `T` stands for ten.
+const CARD_RANKS: &[u8; 13] = b"A23456789TJQK";
+
+/// Card suits in canonical order: spades, hearts, diamonds, clubs.
+const CARD_SUITS: &[u8; 4] = b"SHDC";
+
+/// Number of cards in a standard deck.
+const DECK_SIZE: usize = 52;
+
+/// Probability below which an observed run of identical values is treated as bias.
+const RUN_PROBABILITY_THRESHOLD: f64 = 1e-6;
+
+/// The kind of physical process that produced the entropy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhysicalEntropyKind {
+    /// Rolls of a six-sided die, each 1-6.
+    D6,
+    /// Rolls of a twenty-sided die, each 1-20.
+    D20,
+    /// Coin flips, each heads or tails.
+    CoinFlips,
+    /// The order of distinct cards drawn from a shuffled 52-card deck.
+    Cards,
+}
+
+impl PhysicalEntropyKind {
+    /// Returns the number of equally likely outcomes of one observation.
+    const fn outcomes(&self) -> usize {
+        match self {
+            PhysicalEntropyKind::D6 => 6,
+            PhysicalEntropyKind::D20 => 20,
+            PhysicalEntropyKind::CoinFlips => 2,
+            PhysicalEntropyKind::Cards => DECK_SIZE,
+        }
+    }
+
+    /// Returns the χ² critical value for `outcomes() - 1` degrees of freedom at p = 0.0001.
+    const fn chi_square_critical_value(&self) -> f64 {
+        match self {
+            PhysicalEntropyKind::D6 => 25.74,
+            PhysicalEntropyKind::D20 => 51.18,
+            PhysicalEntropyKind::CoinFlips => 15.14,
+            // Cards are drawn without replacement and are never tested.
+            PhysicalEntropyKind::Cards => f64::INFINITY,
+        }
+    }
+}
+
+/// Entropy recorded from dice, coins or cards.
+///
+/// Values are stored zero-based: a d6 roll of 1 is stored as 0, heads as 1,
+/// and cards as their position in a deck ordered by suit (`S`, `H`, `D`, `C`)
+/// and rank (`A` to `K`).
+#[derive(Clone, PartialEq, Eq)]
+pub struct PhysicalEntropy {
+    kind: PhysicalEntropyKind,
+    values: Vec<u8>,
+}
+
+impl PhysicalEntropy {
+    /// Parses six-sided die rolls written as digits `1`-`6`.
+    ///
+    /// Whitespace is ignored, so rolls may be grouped for readability.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidEntropyInput`] - If a character is not a digit from 1 to 6
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::PhysicalEntropy;
+    /// let rolls = PhysicalEntropy::d6("3141 5262")?;
+    /// assert_eq!(rolls.len(), 8);
+    /// assert!(PhysicalEntropy::d6("1237").is_err());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn d6(rolls: &str) -> Result<Self> {
+        let values = rolls
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                '1'..='6' => Ok(c as u8 - b'1'),
+                _ => Err(invalid_input(format!("Invalid d6 roll '{c}'"))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            kind: PhysicalEntropyKind::D6,
+            values,
+        })
+    }
+
+    /// Parses twenty-sided die rolls written as numbers `1`-`20`.
+    ///
+    /// Rolls are separated by whitespace or commas.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidEntropyInput`] - If a roll is not a number from 1 to 20
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::PhysicalEntropy;
+    /// let rolls = PhysicalEntropy::d20("17, 3, 20, 11")?;
+    /// assert_eq!(rolls.len(), 4);
+    /// assert!(PhysicalEntropy::d20("0 21").is_err());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn d20(rolls: &str) -> Result<Self> {
+        let values = rolls
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|roll| !roll.is_empty())
+            .map(|roll| match roll.parse::<u8>() {
+                Ok(value @ 1..=20) => Ok(value - 1),
+                _ => Err(invalid_input(format!("Invalid d20 roll '{roll}'"))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            kind: PhysicalEntropyKind::D20,
+            values,
+        })
+    }
+
+    /// Parses coin flips written as `H`/`T` or `1`/`0`.
+    ///
+    /// Letters are case-insensitive and whitespace is ignored.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidEntropyInput`] - If a character is not a coin flip
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::PhysicalEntropy;
+    /// assert_eq!(PhysicalEntropy::coin_flips("HTTH")?, PhysicalEntropy::coin_flips("1001")?);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn coin_flips(flips: &str) -> Result<Self> {
+        let values = flips
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                'H' | 'h' | '1' => Ok(1),
+                'T' | 't' | '0' => Ok(0),
+                _ => Err(invalid_input(format!("Invalid coin flip '{c}'"))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            kind: PhysicalEntropyKind::CoinFlips,
+            values,
+        })
+    }
+
+    /// Parses the order of cards drawn from a shuffled deck.
+    ///
+    /// Cards are separated by whitespace or commas and written as rank
+    /// (`A`, `2`-`9`, `T` or `10`, `J`, `Q`, `K`) followed by suit
+    /// (`S`, `H`, `D`, `C`), case-insensitive. Each card may appear only once.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidEntropyInput`] - If a card is malformed or repeated
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::PhysicalEntropy;
+    /// let cards = PhysicalEntropy::cards("AS 10h qd 2C")?;
+    /// assert_eq!(cards.len(), 4);
+    /// assert!(PhysicalEntropy::cards("AS AS").is_err());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn cards(cards: &str) -> Result<Self> {
+        let mut seen = [false; DECK_SIZE];
+        let values = cards
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|card| !card.is_empty())
+            .map(|card| {
+                let value = parse_card(card)
+                    .ok_or_else(|| invalid_input(format!("Invalid card '{card}'")))?;
+                if core::mem::replace(&mut seen[value as usize], true) {
+                    return Err(invalid_input(format!("Card '{card}' appears twice")));
+                }
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            kind: PhysicalEntropyKind::Cards,
+            values,
+        })
+    }
+
+    /// Returns the kind of physical source.
+    pub fn kind(&self) -> PhysicalEntropyKind {
+        self.kind
+    }
+
+    /// Returns the number of recorded rolls, flips or cards.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the entropy of the recorded values in bits.
+    ///
+    /// Each die roll contributes log₂(sides) bits and each coin flip one bit.
+    /// A sequence of `n` distinct cards contributes log₂(52! / (52 - n)!) bits,
+    /// so a fully shuffled deck holds about 225 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::PhysicalEntropy;
+    /// let flips = PhysicalEntropy::coin_flips(&"HT".repeat(64))?;
+    /// assert_eq!(flips.entropy_bits(), 128.0);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn entropy_bits(&self) -> f64 {
+        match self.kind {
+            PhysicalEntropyKind::Cards => (0..self.values.len())
+                .map(|drawn| libm::log2((DECK_SIZE - drawn) as f64))
+                .sum(),
+            kind => self.values.len() as f64 * libm::log2(kind.outcomes() as f64),
+        }
+    }
+
+    /// Checks the recorded values for signs of bias.
+    ///
+    /// Two tests are applied to dice rolls and coin flips:
+    ///
+    /// - A χ² goodness-of-fit test against a fair distribution at p = 0.0001,
+    ///   once there are at least five expected observations per outcome
+    /// - A run test rejecting a streak of identical values that a fair source
+    ///   would produce with probability below one in a million
+    ///
+    /// Card orders are not tested because drawing without replacement cannot
+    /// repeat values. Passing these checks does not prove the source is fair;
+    /// it only catches gross mistakes such as a loaded die or a stuck key.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidEntropyInput`] - If the values look biased
+    pub fn check_bias(&self) -> Result<()> {
+        if self.kind == PhysicalEntropyKind::Cards || self.values.len() < 2 {
+            return Ok(());
+        }
+
+        let outcomes = self.kind.outcomes();
+        let n = self.values.len() as f64;
+        let expected = n / outcomes as f64;
+        if expected >= 5.0 {
+            let mut counts = vec![0usize; outcomes];
+            for &value in &self.values {
+                counts[value as usize] += 1;
+            }
+            let chi_square: f64 = counts
+                .iter()
+                .map(|&count| {
+                    let deviation = count as f64 - expected;
+                    deviation * deviation / expected
+                })
+                .sum();
+            if chi_square > self.kind.chi_square_critical_value() {
+                return Err(invalid_input(format!(
+                    "Values look biased (chi-square {chi_square:.1} over {} observations)",
+                    self.values.len()
+                )));
+            }
+        }
+
+        // A run of length r starts at a given position with probability p^(r-1).
+        let p = 1.0 / outcomes as f64;
+        let max_run =
+            1 + libm::ceil(libm::log(RUN_PROBABILITY_THRESHOLD / n) / libm::log(p)) as usize;
+        let longest_run = self
+            .values
+            .chunk_by(|a, b| a == b)
+            .map(<[u8]>::len)
+            .max()
+            .unwrap_or(0);
+        if longest_run > max_run {
+            return Err(invalid_input(format!(
+                "Values look biased ({longest_run} identical values in a row)"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Derives entropy for a mnemonic of `word_count` words.
+    ///
+    /// The recorded values must carry at least as many bits as the mnemonic
+    /// entropy (rounded to the nearest bit, so 99 d6 rolls are enough for 24
+    /// words, as on Coldcard) and must pass [`check_bias`](Self::check_bias).
+    /// The entropy is the leading bytes of SHA-256 over the canonical string:
+    ///
+    /// - d6: the rolls as digits `1`-`6` with no separators
+    /// - d20: the rolls as numbers `1`-`20` separated by single spaces
+    /// - Coin flips: `1` for heads and `0` for tails with no separators
+    /// - Cards: the cards as uppercase rank and suit (`T` for ten) separated by single spaces
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientEntropy`] - If too few values were recorded
+    /// * [`Error::InvalidEntropyInput`] - If the values look biased
+    pub fn to_entropy(&self, word_count: WordCount) -> Result<Zeroizing<Vec<u8>>> {
+        let required_bits = word_count.entropy_length() * 8;
+        let provided_bits = libm::round(self.entropy_bits()) as usize;
+        if provided_bits < required_bits {
+            return Err(Error::InsufficientEntropy {
+                required_bits,
+                provided_bits,
+            });
+        }
+        self.check_bias()?;
+
+        let digest: Zeroizing<[u8; 32]> =
+            Zeroizing::new(Sha256::digest(self.canonical_string().as_bytes()).into());
+        Ok(Zeroizing::new(
+            digest[..word_count.entropy_length()].to_vec(),
+        ))
+    }
+
+    /// Writes the values in the canonical form that is hashed into entropy.
+    fn canonical_string(&self) -> Zeroizing<String> {
+        Zeroizing::new(match self.kind {
+            PhysicalEntropyKind::D6 | PhysicalEntropyKind::CoinFlips => {
+                let offset = if self.kind == PhysicalEntropyKind::D6 {
+                    b'1'
+                } else {
+                    b'0'
+                };
+                self.values
+                    .iter()
+                    .map(|&value| (value + offset) as char)
+                    .collect()
+            }
+            PhysicalEntropyKind::D20 => self
+                .values
+                .iter()
+                .map(|value| (value + 1).to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+            PhysicalEntropyKind::Cards => self
+                .values
+                .iter()
+                .map(|&value| {
+                    let rank = CARD_RANKS[value as usize % CARD_RANKS.len()] as char;
+                    let suit = CARD_SUITS[value as usize / CARD_RANKS.len()] as char;
+                    format!("{rank}{suit}")
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        })
+    }
+}
+
+impl fmt::Debug for PhysicalEntropy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PhysicalEntropy")
+            .field("kind", &self.kind)
+            .field("len", &self.values.len())
+            .field("values", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl Mnemonic {
+    /// Creates a mnemonic from dice rolls, coin flips or a card order.
+    ///
+    /// The result depends only on the recorded values, so it can be
+    /// reproduced and checked on another device. See
+    /// [`PhysicalEntropy::to_entropy`] for the derivation.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientEntropy`] - If too few values were recorded
+    /// * [`Error::InvalidEntropyInput`] - If the values look biased
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic, PhysicalEntropy, WordCount};
+    ///
+    /// let flips = PhysicalEntropy::coin_flips(&"HHTHTTHT".repeat(16))?;
+    /// let mnemonic = Mnemonic::from_physical_entropy(&flips, WordCount::Twelve, Language::English)?;
+    /// assert_eq!(mnemonic.entropy().len(), 16);
+    ///
+    /// // Not enough flips for 24 words
+    /// assert!(Mnemonic::from_physical_entropy(&flips, WordCount::TwentyFour, Language::English).is_err());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn from_physical_entropy(
+        source: &PhysicalEntropy,
+        word_count: WordCount,
+        language: Language,
+    ) -> Result<Self> {
+        Mnemonic::new(&source.to_entropy(word_count)?, language)
+    }
+
+    /// Creates a mnemonic from physical entropy mixed with OS randomness.
+    ///
+    /// The physical entropy is XORed with bytes from the operating system's
+    /// random number generator, so the mnemonic is secure as long as either
+    /// source is. Unlike [`from_physical_entropy`](Self::from_physical_entropy)
+    /// the result cannot be reproduced from the recorded values.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientEntropy`] - If too few values were recorded
+    /// * [`Error::InvalidEntropyInput`] - If the values look biased
+    /// * [`Error::RandomGeneration`] - If the OS random number generator fails
+    #[cfg(feature = "rand")]
+    pub fn from_physical_entropy_with_os_mixing(
+        source: &PhysicalEntropy,
+        word_count: WordCount,
+        language: Language,
+    ) -> Result<Self> {
+        let mut entropy = source.to_entropy(word_count)?;
+        let mut os_entropy = Zeroizing::new(vec![0u8; entropy.len()]);
+        OsEntropy.fill_entropy(&mut os_entropy)?;
+        for (byte, os_byte) in entropy.iter_mut().zip(os_entropy.iter()) {
+            *byte ^= os_byte;
+        }
+        Mnemonic::new(&entropy, language)
+    }
+}
+
+/// Builds an [`Error::InvalidEntropyInput`] with the given reason.
+fn invalid_input(reason: String) -> Error {
+    Error::InvalidEntropyInput { reason }
+}
+
+/// Parses a card such as `AS`, `10h` or `TD` into its deck position.
+fn parse_card(card: &str) -> Option<u8> {
+    if !card.is_ascii() {
+        return None;
+    }
+    let card = card.to_ascii_uppercase();
+    let (rank, suit) = card.split_at(card.len().checked_sub(1)?);
+    let rank = match rank {
+        "10" => b'T',
+        _ if rank.len() == 1 => rank.as_bytes()[0],
+        _ => return None,
+    };
+    let rank = CARD_RANKS.iter().position(|&r| r == rank)?;
+    let suit = CARD_SUITS.iter().position(|&s| suit.as_bytes() == [s])?;
+    Some((suit * CARD_RANKS.len() + rank) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 100 d6 rolls with roughly even face counts.
+    const D6_ROLLS_50: &str = "16345236215634162534612345236142351624315263412563\
+                               21456342165324615234156243516234512634152634152634";
+
+    const FULL_DECK: &str = "AS 2S 3S 4S 5S 6S 7S 8S 9S TS JS QS KS \
+                             AH 2H 3H 4H 5H 6H 7H 8H 9H TH JH QH KH \
+                             AD 2D 3D 4D 5D 6D 7D 8D 9D TD JD QD KD \
+                             AC 2C 3C 4C 5C 6C 7C 8C 9C TC JC QC KC";
+
+    #[test]
+    fn test_d6_entropy_is_sha256_of_rolls() {
+        let rolls = &D6_ROLLS_50[..50];
+        let source = PhysicalEntropy::d6(rolls).unwrap();
+        let entropy = source.to_entropy(WordCount::Twelve).unwrap();
+        assert_eq!(*entropy, Sha256::digest(rolls.as_bytes())[..16]);
+    }
+
+    #[test]
+    fn test_debug_redacts_values() {
+        let source = PhysicalEntropy::d6("123456").unwrap();
+        let debug = format!("{source:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("[0, 1, 2"));
+    }
+
+    #[test]
+    fn test_d6_roll_counts() {
+        let rolls = D6_ROLLS_50.repeat(2);
+
+        let source = PhysicalEntropy::d6(&rolls[..49]).unwrap();
+        assert_eq!(
+            source.to_entropy(WordCount::Twelve).unwrap_err(),
+            Error::InsufficientEntropy {
+                required_bits: 128,
+                provided_bits: 127
+            }
+        );
+
+        let source = PhysicalEntropy::d6(&rolls[..99]).unwrap();
+        let mnemonic =
+            Mnemonic::from_physical_entropy(&source, WordCount::TwentyFour, Language::English)
+                .unwrap();
+        assert_eq!(
+            mnemonic.entropy(),
+            &Sha256::digest(&rolls.as_bytes()[..99])[..]
+        );
+    }
+
+    #[test]
+    fn test_d20_rolls() {
+        let rolls: Vec<String> = (0..30).map(|i| ((i * 7) % 20 + 1).to_string()).collect();
+        let source = PhysicalEntropy::d20(&rolls.join(",")).unwrap();
+        assert_eq!(source.len(), 30);
+
+        let entropy = source.to_entropy(WordCount::Twelve).unwrap();
+        assert_eq!(*entropy, Sha256::digest(rolls.join(" ").as_bytes())[..16]);
+        assert!(source.to_entropy(WordCount::Fifteen).is_err());
+    }
+
+    #[test]
+    fn test_coin_flips() {
+        let flips = PhysicalEntropy::coin_flips(&"HTTHHTHT".repeat(16)).unwrap();
+        let entropy = flips.to_entropy(WordCount::Twelve).unwrap();
+        assert_eq!(
+            *entropy,
+            Sha256::digest("10011010".repeat(16).as_bytes())[..16]
+        );
+    }
+
+    #[test]
+    fn test_cards() {
+        let deck = PhysicalEntropy::cards(FULL_DECK).unwrap();
+        assert_eq!(deck.len(), 52);
+        assert!((deck.entropy_bits() - 225.58).abs() < 0.01);
+
+        assert!(deck.to_entropy(WordCount::TwentyOne).is_ok());
+        assert!(matches!(
+            deck.to_entropy(WordCount::TwentyFour),
+            Err(Error::InsufficientEntropy { .. })
+        ));
+
+        let ten = PhysicalEntropy::cards("10h").unwrap();
+        assert_eq!(ten, PhysicalEntropy::cards("TH").unwrap());
+        assert_eq!(*ten.canonical_string(), "TH");
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(PhysicalEntropy::d6("1234560").is_err());
+        assert!(PhysicalEntropy::d20("5 twenty").is_err());
+        assert!(PhysicalEntropy::coin_flips("HTX").is_err());
+        assert!(PhysicalEntropy::cards("1S").is_err());
+        assert!(PhysicalEntropy::cards("AX").is_err());
+        assert!(PhysicalEntropy::cards("A♠").is_err());
+        assert!(PhysicalEntropy::cards("QH qh").is_err());
+    }
+
+    #[test]
+    fn test_biased_rolls_are_rejected() {
+        // A die that never rolls a five or six
+        let rolls = "1234".repeat(25);
+        let source = PhysicalEntropy::d6(&rolls).unwrap();
+        assert!(matches!(
+            source.to_entropy(WordCount::Twelve),
+            Err(Error::InvalidEntropyInput { .. })
+        ));
+
+        // A stuck key
+        let flips = format!("{}{}", "HT".repeat(50), "H".repeat(40));
+        let source = PhysicalEntropy::coin_flips(&flips).unwrap();
+        assert!(matches!(
+            source.check_bias(),
+            Err(Error::InvalidEntropyInput { .. })
+        ));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_os_mixing() {
+        let source = PhysicalEntropy::d6(&D6_ROLLS_50[..50]).unwrap();
+        let plain =
+            Mnemonic::from_physical_entropy(&source, WordCount::Twelve, Language::English).unwrap();
+        let mixed = Mnemonic::from_physical_entropy_with_os_mixing(
+            &source,
+            WordCount::Twelve,
+            Language::English,
+        )
+        .unwrap();
+        assert_eq!(mixed.word_count(), WordCount::Twelve);
+        assert_ne!(plain, mixed);
+    }
+}
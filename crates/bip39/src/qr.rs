@@ -0,0 +1,733 @@
+//! A minimal QR code encoder for seed exchange.
+//!
+//! Renders small payloads (QR versions 1 to 3, error correction level L) as
+//! a module matrix with text or SVG output. This covers every SeedQR and
+//! CompactSeedQR size without pulling a general-purpose QR dependency into
+//! code that handles secrets.
+//!
+//! The encoder follows ISO/IEC 18004: numeric or byte mode segments,
+//! Reed-Solomon error correction over GF(2⁸), and automatic selection of the
+//! mask pattern with the lowest penalty score.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::QrCode;
+//!
+//! let qr = QrCode::encode_numeric("0123456789")?;
+//! assert_eq!(qr.version(), 1);
+//! assert_eq!(qr.size(), 21);
+//!
+//! let svg = qr.to_svg(4);
+//! assert!(svg.starts_with("<svg"));
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::{Error, Result};
+
+/// Highest QR version this encoder supports.
+const MAX_VERSION: u8 = 3;
+
+/// Data codewords per version (index 0 unused) at error correction level L.
+const DATA_CODEWORDS: [usize; 4] = [0, 19, 34, 55];
+
+/// Error correction codewords per version (index 0 unused) at level L.
+const ECC_CODEWORDS: [usize; 4] = [0, 7, 10, 15];
+
+/// Format information bits for error correction level L.
+const ECC_LEVEL_L_BITS: u32 = 0b01;
+
+/// Mask penalty weights from the QR specification.
+const PENALTY_N1: u32 = 3;
+const PENALTY_N2: u32 = 3;
+const PENALTY_N3: u32 = 40;
+const PENALTY_N4: u32 = 10;
+
+/// A QR code symbol as a square matrix of dark and light modules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCode {
+    version: u8,
+    size: usize,
+    modules: Vec<bool>,
+}
+
+/// The encoding mode of a QR data segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Numeric,
+    Byte,
+}
+
+impl QrCode {
+    /// Encodes a string of ASCII digits in numeric mode.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQrData`] - If the text contains a non-digit or does
+    ///   not fit in a version 3 symbol (127 digits)
+    pub fn encode_numeric(digits: &str) -> Result<Self> {
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidQrData {
+                reason: "Numeric mode accepts only the digits 0-9".to_string(),
+            });
+        }
+
+        let mut bits = BitBuffer::default();
+        for chunk in digits.as_bytes().chunks(3) {
+            let value = chunk
+                .iter()
+                .fold(0u32, |acc, &digit| acc * 10 + u32::from(digit - b'0'));
+            bits.push(value, chunk.len() * 3 + 1);
+        }
+        Self::encode_segment(Mode::Numeric, digits.len(), bits)
+    }
+
+    /// Encodes binary data in byte mode.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQrData`] - If the data does not fit in a version 3
+    ///   symbol (53 bytes)
+    pub fn encode_bytes(data: &[u8]) -> Result<Self> {
+        let mut bits = BitBuffer::default();
+        for &byte in data {
+            bits.push(u32::from(byte), 8);
+        }
+        Self::encode_segment(Mode::Byte, data.len(), bits)
+    }
+
+    /// Returns the QR version (1 to 3).
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the width and height of the matrix in modules.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the module at column `x` and row `y` is dark.
+    ///
+    /// Coordinates outside the matrix are light, like the quiet zone.
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    /// Renders the symbol as text, two characters per module.
+    ///
+    /// Dark modules are drawn as `██` and light modules as two spaces, with
+    /// `border` light modules of quiet zone on every side (4 is standard).
+    /// The output scans best on a light background.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::QrCode;
+    ///
+    /// let qr = QrCode::encode_bytes(b"seed")?;
+    /// let text = qr.to_text(0);
+    /// assert_eq!(text.lines().count(), qr.size());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn to_text(&self, border: usize) -> String {
+        let span = self.size + 2 * border;
+        let mut text = String::with_capacity(span * (span * 6 + 1));
+        for y in 0..span {
+            for x in 0..span {
+                let dark = self.is_dark_with_border(x, y, border);
+                text.push_str(if dark { "██" } else { "  " });
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Renders the symbol as a standalone SVG document.
+    ///
+    /// Each module is one SVG unit, with `border` light modules of quiet zone
+    /// on every side (4 is standard); scale the image with CSS or the
+    /// `width`/`height` attributes as needed.
+    pub fn to_svg(&self, border: usize) -> String {
+        let span = self.size + 2 * border;
+        let mut path = String::new();
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if self.is_dark(x, y) {
+                    if !path.is_empty() {
+                        path.push(' ');
+                    }
+                    let _ = write!(path, "M{},{}h1v1h-1z", x + border, y + border);
+                }
+            }
+        }
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" \
+             viewBox=\"0 0 {span} {span}\" stroke=\"none\">\n\
+             \t<rect width=\"100%\" height=\"100%\" fill=\"#FFFFFF\"/>\n\
+             \t<path d=\"{path}\" fill=\"#000000\"/>\n\
+             </svg>\n"
+        )
+    }
+
+    /// Returns whether the module at (x, y) of the bordered image is dark.
+    fn is_dark_with_border(&self, x: usize, y: usize, border: usize) -> bool {
+        x >= border && y >= border && self.is_dark(x - border, y - border)
+    }
+
+    /// Builds the smallest symbol that holds one segment.
+    fn encode_segment(mode: Mode, char_count: usize, data: BitBuffer) -> Result<Self> {
+        let (mode_bits, count_bits) = match mode {
+            Mode::Numeric => (0b0001, 10),
+            Mode::Byte => (0b0100, 8),
+        };
+        let used_bits = 4 + count_bits + data.len();
+        let version = (1..=MAX_VERSION)
+            .find(|&v| used_bits <= DATA_CODEWORDS[v as usize] * 8 && char_count < 1 << count_bits)
+            .ok_or_else(|| Error::InvalidQrData {
+                reason: format!(
+                    "{used_bits} bits exceed the {} bit capacity of a version {MAX_VERSION} QR code",
+                    DATA_CODEWORDS[MAX_VERSION as usize] * 8
+                ),
+            })?;
+
+        let capacity_bits = DATA_CODEWORDS[version as usize] * 8;
+        let mut bits = BitBuffer::default();
+        bits.push(mode_bits, 4);
+        bits.push(char_count as u32, count_bits);
+        bits.append(&data);
+
+        // Terminator, byte alignment and alternating pad bytes
+        bits.push(0, (capacity_bits - bits.len()).min(4));
+        bits.push(0, bits.len().wrapping_neg() & 7);
+        for &pad in [0xEC, 0x11].iter().cycle() {
+            if bits.len() >= capacity_bits {
+                break;
+            }
+            bits.push(pad, 8);
+        }
+
+        let mut codewords = bits.to_bytes();
+        let ecc = reed_solomon_remainder(&codewords, ECC_CODEWORDS[version as usize]);
+        codewords.extend_from_slice(&ecc);
+        Ok(Matrix::new(version).build(&codewords))
+    }
+}
+
+/// A growable sequence of bits, most significant bit first.
+#[derive(Debug, Default)]
+struct BitBuffer(Vec<bool>);
+
+impl BitBuffer {
+    /// Appends the lowest `length` bits of `value`.
+    fn push(&mut self, value: u32, length: usize) {
+        self.0
+            .extend((0..length).rev().map(|i| (value >> i) & 1 == 1));
+    }
+
+    /// Appends all bits of `other`.
+    fn append(&mut self, other: &BitBuffer) {
+        self.0.extend_from_slice(&other.0);
+    }
+
+    /// Returns the number of bits.
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Packs the bits into bytes; the length must be a multiple of 8.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0
+            .chunks(8)
+            .map(|byte| {
+                byte.iter()
+                    .fold(0u8, |acc, &bit| (acc << 1) | u8::from(bit))
+            })
+            .collect()
+    }
+}
+
+/// A symbol under construction, tracking which modules are function patterns.
+struct Matrix {
+    version: u8,
+    size: usize,
+    modules: Vec<bool>,
+    is_function: Vec<bool>,
+}
+
+impl Matrix {
+    /// Creates an empty matrix with all function patterns drawn.
+    fn new(version: u8) -> Self {
+        let size = version as usize * 4 + 17;
+        let mut matrix = Self {
+            version,
+            size,
+            modules: vec![false; size * size],
+            is_function: vec![false; size * size],
+        };
+
+        // Timing patterns
+        for i in 0..size {
+            matrix.set_function(6, i, i % 2 == 0);
+            matrix.set_function(i, 6, i % 2 == 0);
+        }
+
+        // Finder patterns with separators in three corners
+        matrix.draw_finder(3, 3);
+        matrix.draw_finder(size - 4, 3);
+        matrix.draw_finder(3, size - 4);
+
+        // Versions 2 and 3 have a single alignment pattern near the bottom right
+        if version >= 2 {
+            matrix.draw_alignment(size - 7, size - 7);
+        }
+
+        // Reserve the format information areas
+        matrix.draw_format_bits(0);
+        matrix
+    }
+
+    /// Places the codewords, picks the best mask and returns the finished symbol.
+    fn build(mut self, codewords: &[u8]) -> QrCode {
+        self.draw_codewords(codewords);
+
+        let mut best_mask = 0;
+        let mut best_penalty = u32::MAX;
+        for mask in 0..8 {
+            self.apply_mask(mask);
+            self.draw_format_bits(mask);
+            let penalty = self.penalty_score();
+            if penalty < best_penalty {
+                best_mask = mask;
+                best_penalty = penalty;
+            }
+            // Masking is an XOR, so applying it again undoes it
+            self.apply_mask(mask);
+        }
+        self.apply_mask(best_mask);
+        self.draw_format_bits(best_mask);
+
+        QrCode {
+            version: self.version,
+            size: self.size,
+            modules: self.modules,
+        }
+    }
+
+    fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.is_function[y * self.size + x] = true;
+    }
+
+    /// Draws a 7x7 finder pattern and its separator centred on (x, y).
+    fn draw_finder(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let (xx, yy) = (x as i32 + dx, y as i32 + dy);
+                if (0..self.size as i32).contains(&xx) && (0..self.size as i32).contains(&yy) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function(xx as usize, yy as usize, distance != 2 && distance != 4);
+                }
+            }
+        }
+    }
+
+    /// Draws a 5x5 alignment pattern centred on (x, y).
+    fn draw_alignment(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let dark = dx.abs().max(dy.abs()) != 1;
+                self.set_function((x as i32 + dx) as usize, (y as i32 + dy) as usize, dark);
+            }
+        }
+    }
+
+    /// Draws both copies of the 15-bit format information for `mask`.
+    fn draw_format_bits(&mut self, mask: u32) {
+        let data = (ECC_LEVEL_L_BITS << 3) | mask;
+        let mut remainder = data;
+        for _ in 0..10 {
+            remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | remainder) ^ 0x5412;
+        let bit = |i: usize| (bits >> i) & 1 == 1;
+
+        // First copy, around the top-left finder
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        // Second copy, split between the other two finders
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    /// Places codeword bits in the zigzag order over the non-function modules.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let total_bits = codewords.len() * 8;
+        let mut i = 0;
+        let mut right = self.size as i32 - 1;
+        while right >= 1 {
+            // Skip the vertical timing pattern
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vertical in 0..self.size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let y = if upward {
+                        self.size - 1 - vertical
+                    } else {
+                        vertical
+                    };
+                    if !self.is_function[y * self.size + x] && i < total_bits {
+                        self.modules[y * self.size + x] =
+                            (codewords[i >> 3] >> (7 - (i & 7))) & 1 == 1;
+                        i += 1;
+                    }
+                    // Remainder bits stay light
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    /// XORs the data modules with mask pattern `mask`.
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                self.modules[index] ^= invert && !self.is_function[index];
+            }
+        }
+    }
+
+    /// Scores the current modules with the four penalty rules of the specification.
+    fn penalty_score(&self) -> u32 {
+        let size = self.size;
+        let mut penalty = 0;
+
+        // Runs of same-coloured modules and finder-like patterns, in rows and columns
+        for line in 0..size {
+            penalty += line_penalty(size, |i| self.get(i, line));
+            penalty += line_penalty(size, |i| self.get(line, i));
+        }
+
+        // 2x2 blocks of the same colour
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = self.get(x, y);
+                if color == self.get(x + 1, y)
+                    && color == self.get(x, y + 1)
+                    && color == self.get(x + 1, y + 1)
+                {
+                    penalty += PENALTY_N2;
+                }
+            }
+        }
+
+        // Balance of dark and light modules, in 5% steps away from 50%
+        let dark = self.modules.iter().filter(|&&m| m).count() as i64;
+        let total = (size * size) as i64;
+        let k = ((dark * 20 - total * 10).abs() + total - 1) / total - 1;
+        penalty + k as u32 * PENALTY_N4
+    }
+}
+
+/// Computes the run-length and finder-pattern penalties of one row or column.
+fn line_penalty(size: usize, module: impl Fn(usize) -> bool) -> u32 {
+    let mut penalty = 0;
+    let mut run_color = false;
+    let mut run_length = 0;
+    let mut history = RunHistory::new(size);
+    for i in 0..size {
+        if module(i) == run_color {
+            run_length += 1;
+            if run_length == 5 {
+                penalty += PENALTY_N1;
+            } else if run_length > 5 {
+                penalty += 1;
+            }
+        } else {
+            history.push(run_length);
+            if !run_color {
+                penalty += history.count_finder_patterns() * PENALTY_N3;
+            }
+            run_color = module(i);
+            run_length = 1;
+        }
+    }
+    penalty + history.finish(run_color, run_length) * PENALTY_N3
+}
+
+/// The last seven run lengths of a line, for detecting 1:1:3:1:1 finder-like patterns.
+struct RunHistory {
+    size: usize,
+    runs: [usize; 7],
+}
+
+impl RunHistory {
+    fn new(size: usize) -> Self {
+        Self { size, runs: [0; 7] }
+    }
+
+    /// Pushes a run length; the first run includes the light quiet zone.
+    fn push(&mut self, mut run_length: usize) {
+        if self.runs[0] == 0 {
+            run_length += self.size;
+        }
+        self.runs.copy_within(0..6, 1);
+        self.runs[0] = run_length;
+    }
+
+    /// Counts finder-like patterns ending at the most recent light run (0, 1 or 2).
+    fn count_finder_patterns(&self) -> u32 {
+        let r = &self.runs;
+        let n = r[1];
+        let core = n > 0 && r[2] == n && r[3] == n * 3 && r[4] == n && r[5] == n;
+        u32::from(core && r[0] >= n * 4 && r[6] >= n)
+            + u32::from(core && r[6] >= n * 4 && r[0] >= n)
+    }
+
+    /// Closes the line with the light quiet zone and counts the final patterns.
+    fn finish(mut self, run_color: bool, mut run_length: usize) -> u32 {
+        if run_color {
+            self.push(run_length);
+            run_length = 0;
+        }
+        self.push(run_length + self.size);
+        self.count_finder_patterns()
+    }
+}
+
+/// Computes `degree` Reed-Solomon error correction codewords for `data`.
+fn reed_solomon_remainder(data: &[u8], degree: usize) -> Vec<u8> {
+    // Generator polynomial (x - α⁰)(x - α¹)…(x - α^(degree-1)), leading term omitted
+    let mut divisor = vec![0u8; degree];
+    divisor[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            divisor[j] = gf_multiply(divisor[j], root);
+            if j + 1 < degree {
+                divisor[j] ^= divisor[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+
+    let mut remainder = vec![0u8; degree];
+    for &byte in data {
+        let factor = byte ^ remainder.remove(0);
+        remainder.push(0);
+        for (r, &d) in remainder.iter_mut().zip(&divisor) {
+            *r ^= gf_multiply(d, factor);
+        }
+    }
+    remainder
+}
+
+/// Multiplies two elements of GF(2⁸) modulo x⁸ + x⁴ + x³ + x² + 1.
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z = 0u8;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x1D);
+        z ^= ((y >> i) & 1) * x;
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qrcodegen::{QrCodeEcc, QrSegment, Version};
+
+    /// Encodes a segment with the qrcodegen reference encoder at the same
+    /// versions and error correction level, letting it choose the mask.
+    fn reference(segment: QrSegment) -> qrcodegen::QrCode {
+        qrcodegen::QrCode::encode_segments_advanced(
+            &[segment],
+            QrCodeEcc::Low,
+            Version::new(1),
+            Version::new(MAX_VERSION),
+            None,
+            false,
+        )
+        .unwrap()
+    }
+
+    /// Asserts that every module matches the reference symbol.
+    fn assert_same_matrix(qr: &QrCode, expected: &qrcodegen::QrCode) {
+        assert_eq!(qr.version(), expected.version().value());
+        assert_eq!(qr.size() as i32, expected.size());
+        for y in 0..qr.size() {
+            for x in 0..qr.size() {
+                assert_eq!(
+                    qr.is_dark(x, y),
+                    expected.get_module(x as i32, y as i32),
+                    "module ({x}, {y}) of version {}",
+                    qr.version()
+                );
+            }
+        }
+    }
+
+    /// Reads back the 15 format bits from the first copy.
+    fn read_format_bits(qr: &QrCode) -> u32 {
+        let mut bits = 0;
+        let positions = (0..6)
+            .map(|i| (8, i))
+            .chain([(8, 7), (8, 8), (7, 8)])
+            .chain((9..15).map(|i| (14 - i, 8)));
+        for (i, (x, y)) in positions.enumerate() {
+            bits |= u32::from(qr.is_dark(x, y)) << i;
+        }
+        bits
+    }
+
+    #[test]
+    fn test_version_selection() {
+        assert_eq!(QrCode::encode_bytes(&[0u8; 16]).unwrap().version(), 1);
+        assert_eq!(QrCode::encode_bytes(&[0u8; 17]).unwrap().version(), 1);
+        assert_eq!(QrCode::encode_bytes(&[0u8; 18]).unwrap().version(), 2);
+        assert_eq!(QrCode::encode_bytes(&[0u8; 32]).unwrap().version(), 2);
+        assert_eq!(QrCode::encode_bytes(&[0u8; 53]).unwrap().version(), 3);
+        assert!(QrCode::encode_bytes(&[0u8; 54]).is_err());
+
+        assert_eq!(
+            QrCode::encode_numeric(&"1".repeat(41)).unwrap().version(),
+            1
+        );
+        assert_eq!(
+            QrCode::encode_numeric(&"1".repeat(48)).unwrap().version(),
+            2
+        );
+        assert_eq!(
+            QrCode::encode_numeric(&"1".repeat(96)).unwrap().version(),
+            3
+        );
+        assert_eq!(
+            QrCode::encode_numeric(&"1".repeat(127)).unwrap().version(),
+            3
+        );
+        assert!(QrCode::encode_numeric(&"1".repeat(128)).is_err());
+        assert!(QrCode::encode_numeric("12a4").is_err());
+    }
+
+    #[test]
+    fn test_matches_reference_encoder() {
+        // Every payload length of both modes, crossing the version boundaries
+        for length in 0..=127 {
+            let digits: String = (0..length)
+                .map(|i| char::from(b'0' + ((i * 7 + length) % 10) as u8))
+                .collect();
+            assert_same_matrix(
+                &QrCode::encode_numeric(&digits).unwrap(),
+                &reference(QrSegment::make_numeric(&digits)),
+            );
+        }
+        for length in 0..=53 {
+            let data: Vec<u8> = (0..length).map(|i| (i * 37 + length * 11) as u8).collect();
+            assert_same_matrix(
+                &QrCode::encode_bytes(&data).unwrap(),
+                &reference(QrSegment::make_bytes(&data)),
+            );
+        }
+    }
+
+    #[test]
+    fn test_function_patterns() {
+        let qr = QrCode::encode_bytes(b"khodpay").unwrap();
+        assert_eq!(qr.size(), 21);
+
+        // Finder pattern centres and rings
+        for (cx, cy) in [(3, 3), (17, 3), (3, 17)] {
+            assert!(qr.is_dark(cx, cy));
+            assert!(!qr.is_dark(cx + 2, cy));
+            assert!(qr.is_dark(cx + 3, cy));
+        }
+
+        // Timing pattern and dark module
+        for i in 8..13 {
+            assert_eq!(qr.is_dark(i, 6), i % 2 == 0);
+            assert_eq!(qr.is_dark(6, i), i % 2 == 0);
+        }
+        assert!(qr.is_dark(8, 13));
+
+        // Alignment pattern in version 2
+        let qr = QrCode::encode_bytes(&[0xA5; 20]).unwrap();
+        assert!(qr.is_dark(18, 18));
+        assert!(!qr.is_dark(17, 18));
+        assert!(qr.is_dark(16, 18));
+    }
+
+    #[test]
+    fn test_format_bits_are_level_l() {
+        let qr = QrCode::encode_numeric("0123456789").unwrap();
+        let format = read_format_bits(&qr) ^ 0x5412;
+        assert_eq!(format >> 13, ECC_LEVEL_L_BITS);
+    }
+
+    #[test]
+    fn test_reed_solomon() {
+        // "HELLO WORLD" 1-M example from the QR specification (Thonky tutorial)
+        let data = [
+            0x20, 0x5B, 0x0B, 0x78, 0xD1, 0x72, 0xDC, 0x4D, 0x43, 0x40, 0xEC, 0x11, 0xEC, 0x11,
+            0xEC, 0x11,
+        ];
+        assert_eq!(
+            reed_solomon_remainder(&data, 10),
+            [0xC4, 0x23, 0x27, 0x77, 0xEB, 0xD7, 0xE7, 0xE2, 0x5D, 0x17]
+        );
+    }
+
+    #[test]
+    fn test_text_and_svg_rendering() {
+        let qr = QrCode::encode_bytes(b"seed").unwrap();
+
+        let text = qr.to_text(4);
+        assert_eq!(text.lines().count(), qr.size() + 8);
+        assert!(text
+            .lines()
+            .all(|line| line.chars().count() == (qr.size() + 8) * 2));
+        assert!(text.lines().next().unwrap().trim().is_empty());
+
+        let svg = qr.to_svg(2);
+        assert!(svg.contains("viewBox=\"0 0 25 25\""));
+        assert!(svg.contains("M2,2h1v1h-1z"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
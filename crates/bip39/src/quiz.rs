@@ -0,0 +1,371 @@
+//! Backup verification quizzes.
+//!
+//! After showing a new mnemonic, wallets usually ask the user to type back a
+//! few randomly chosen words to confirm the phrase was written down.
+//! [`BackupQuiz`] picks the positions, checks each answer in constant time
+//! and keeps track of which questions have been answered correctly, so the
+//! UI only has to display the questions and collect the answers.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Language, Mnemonic, WordCount};
+//!
+//! let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English)?;
+//! let mut quiz = mnemonic.backup_quiz(3)?;
+//!
+//! let words: Vec<&str> = mnemonic.phrase().split(' ').collect();
+//! for position in quiz.positions().to_vec() {
+//!     // The UI asks for word number `position + 1`
+//!     assert!(quiz.check(position, words[position])?);
+//! }
+//! assert!(quiz.is_passed());
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use zeroize::Zeroizing;
+
+use crate::utils::constant_time_eq;
+#[cfg(feature = "rand")]
+use crate::OsEntropy;
+use crate::{codec, EntropySource, Error, Language, Mnemonic, Result};
+
+/// A quiz asking for the words at randomly chosen positions of a mnemonic.
+///
+/// Created by [`Mnemonic::backup_quiz`]. The expected words are held in a
+/// zeroizing buffer and are not shown by `Debug`.
+#[derive(Clone)]
+pub struct BackupQuiz {
+    language: Language,
+    positions: Vec<usize>,
+    expected: Zeroizing<Vec<u16>>,
+    correct: Vec<bool>,
+    mistakes: usize,
+}
+
+impl BackupQuiz {
+    /// Returns the zero-based word positions asked for, in ascending order.
+    ///
+    /// Display them to the user as `position + 1`.
+    pub fn positions(&self) -> &[usize] {
+        &self.positions
+    }
+
+    /// Checks the user's answer for the word at `position`.
+    ///
+    /// The answer is matched like a word in
+    /// [`Mnemonic::from_phrase`]: case-insensitively, after NFKD
+    /// normalization, and as a unique four-letter prefix. It is compared with
+    /// the expected word in constant time. A wrong answer counts as a
+    /// mistake and marks the question as unanswered again.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the answer is correct
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQuiz`] - If `position` is not one of
+    ///   [`positions`](Self::positions)
+    pub fn check(&mut self, position: usize, answer: &str) -> Result<bool> {
+        let question = self
+            .positions
+            .iter()
+            .position(|&p| p == position)
+            .ok_or_else(|| Error::InvalidQuiz {
+                reason: format!("Word {} is not part of the quiz", position + 1),
+            })?;
+
+        let answer = answer.trim();
+        let word = self.language.expand_prefix(answer).unwrap_or(answer);
+        // Unknown words get an index no wordlist entry has.
+        let index = self.language.index_of(word).unwrap_or(u16::MAX);
+        let is_correct =
+            constant_time_eq(&index.to_be_bytes(), &self.expected[question].to_be_bytes());
+
+        self.correct[question] = is_correct;
+        if !is_correct {
+            self.mistakes += 1;
+        }
+        Ok(is_correct)
+    }
+
+    /// Checks one answer per question, in the order of
+    /// [`positions`](Self::positions).
+    ///
+    /// # Returns
+    ///
+    /// `true` if every answer is correct
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQuiz`] - If the number of answers differs from the
+    ///   number of questions
+    pub fn check_all(&mut self, answers: &[&str]) -> Result<bool> {
+        if answers.len() != self.positions.len() {
+            return Err(Error::InvalidQuiz {
+                reason: format!(
+                    "Expected {} answers, found {}",
+                    self.positions.len(),
+                    answers.len()
+                ),
+            });
+        }
+        for (position, answer) in self.positions.clone().into_iter().zip(answers) {
+            self.check(position, answer)?;
+        }
+        Ok(self.is_passed())
+    }
+
+    /// Returns `true` once every question has been answered correctly.
+    pub fn is_passed(&self) -> bool {
+        self.correct.iter().all(|&correct| correct)
+    }
+
+    /// Returns the number of questions currently answered correctly.
+    pub fn correct_count(&self) -> usize {
+        self.correct.iter().filter(|&&correct| correct).count()
+    }
+
+    /// Returns the positions that still need a correct answer.
+    pub fn remaining_positions(&self) -> Vec<usize> {
+        self.positions
+            .iter()
+            .zip(&self.correct)
+            .filter(|(_, &correct)| !correct)
+            .map(|(&position, _)| position)
+            .collect()
+    }
+
+    /// Returns the number of wrong answers given so far.
+    pub fn mistakes(&self) -> usize {
+        self.mistakes
+    }
+}
+
+/// Shows the positions and progress; the expected words are omitted.
+impl fmt::Debug for BackupQuiz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BackupQuiz")
+            .field("positions", &self.positions)
+            .field("correct", &self.correct)
+            .field("mistakes", &self.mistakes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Mnemonic {
+    /// Creates a quiz asking for `questions` randomly chosen words of this
+    /// mnemonic.
+    ///
+    /// Positions are drawn from the OS random number generator. Requires
+    /// the `rand` feature; see [`backup_quiz_with_source`](Self::backup_quiz_with_source).
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQuiz`] - If `questions` is zero or more than the
+    ///   number of words
+    /// * [`Error::RandomGeneration`] - If the OS random number generator fails
+    #[cfg(feature = "rand")]
+    pub fn backup_quiz(&self, questions: usize) -> Result<BackupQuiz> {
+        self.backup_quiz_with_source(questions, &mut OsEntropy)
+    }
+
+    /// Creates a quiz asking for `questions` words at positions drawn from
+    /// `source`.
+    ///
+    /// Each set of positions is equally likely.
+    ///
+    /// # Arguments
+    ///
+    /// * `questions` - The number of words to ask for
+    /// * `source` - The source of randomness for choosing positions
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQuiz`] - If `questions` is zero or more than the
+    ///   number of words
+    /// * [`Error::RandomGeneration`] - If the source fails to produce bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{DeterministicEntropy, Language, Mnemonic};
+    ///
+    /// let mnemonic = Mnemonic::new(&[0x2c; 32], Language::English)?;
+    /// let mut source = DeterministicEntropy::new(b"quiz");
+    /// let mut quiz = mnemonic.backup_quiz_with_source(4, &mut source)?;
+    ///
+    /// assert_eq!(quiz.positions().len(), 4);
+    /// let position = quiz.positions()[0];
+    /// assert!(!quiz.check(position, "zoo")?);
+    /// assert_eq!(quiz.mistakes(), 1);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn backup_quiz_with_source<S: EntropySource + ?Sized>(
+        &self,
+        questions: usize,
+        source: &mut S,
+    ) -> Result<BackupQuiz> {
+        let word_count = self.word_count().word_count();
+        if questions == 0 || questions > word_count {
+            return Err(Error::InvalidQuiz {
+                reason: format!("Questions must be between 1 and {word_count}"),
+            });
+        }
+
+        // Partial Fisher-Yates shuffle of the word positions.
+        let mut order: Vec<usize> = (0..word_count).collect();
+        for i in 0..questions {
+            let j = i + random_below(source, word_count - i)?;
+            order.swap(i, j);
+        }
+        let mut positions = order[..questions].to_vec();
+        positions.sort_unstable();
+
+        let indices = codec::entropy_to_indices(self.entropy())?;
+        let expected = Zeroizing::new(positions.iter().map(|&p| indices[p]).collect());
+        Ok(BackupQuiz {
+            language: self.language(),
+            correct: vec![false; questions],
+            positions,
+            expected,
+            mistakes: 0,
+        })
+    }
+}
+
+/// Draws a uniformly distributed number below `bound` from `source`.
+fn random_below<S: EntropySource + ?Sized>(source: &mut S, bound: usize) -> Result<usize> {
+    let bound = bound as u32;
+    // Reject the top values that would make some results more likely.
+    let limit = u32::MAX - u32::MAX % bound;
+    loop {
+        let mut bytes = [0u8; 4];
+        source.fill_entropy(&mut bytes)?;
+        let value = u32::from_be_bytes(bytes);
+        if value < limit {
+            return Ok((value % bound) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeterministicEntropy;
+
+    fn quiz(mnemonic: &Mnemonic, questions: usize) -> BackupQuiz {
+        mnemonic
+            .backup_quiz_with_source(questions, &mut DeterministicEntropy::new(b"quiz tests"))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_positions_are_distinct_and_sorted() {
+        let mnemonic = Mnemonic::new(&[0x11; 32], Language::English).unwrap();
+        for questions in 1..=24 {
+            let quiz = quiz(&mnemonic, questions);
+            let positions = quiz.positions();
+            assert_eq!(positions.len(), questions);
+            assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(positions.iter().all(|&p| p < 24));
+        }
+    }
+
+    #[test]
+    fn test_positions_cover_every_word() {
+        let mnemonic = Mnemonic::new(&[0x11; 16], Language::English).unwrap();
+        let mut source = DeterministicEntropy::new(b"coverage");
+        let mut seen = [0usize; 12];
+        for _ in 0..600 {
+            let quiz = mnemonic.backup_quiz_with_source(1, &mut source).unwrap();
+            seen[quiz.positions()[0]] += 1;
+        }
+        // Each position is expected 50 times
+        assert!(
+            seen.iter().all(|&count| (20..=80).contains(&count)),
+            "{seen:?}"
+        );
+    }
+
+    #[test]
+    fn test_partial_progress() {
+        // "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic"
+        let entropy = hex::decode("9e885d952ad362caeb4efe34a8e91bd2").unwrap();
+        let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+        let words: Vec<&str> = mnemonic.phrase().split(' ').collect();
+        let mut quiz = quiz(&mnemonic, 3);
+        let positions = quiz.positions().to_vec();
+
+        assert!(quiz.check(positions[0], words[positions[0]]).unwrap());
+        assert!(!quiz.check(positions[1], "zzzz").unwrap());
+        assert_eq!(quiz.correct_count(), 1);
+        assert_eq!(quiz.mistakes(), 1);
+        assert_eq!(quiz.remaining_positions(), positions[1..]);
+        assert!(!quiz.is_passed());
+
+        // Prefixes and other cases are accepted
+        let prefix: String = words[positions[1]].chars().take(4).collect();
+        assert!(quiz.check(positions[1], &prefix.to_uppercase()).unwrap());
+        assert!(quiz.check(positions[2], words[positions[2]]).unwrap());
+        assert!(quiz.is_passed());
+
+        // Changing an answer to a wrong word fails the quiz again
+        assert!(!quiz.check(positions[2], words[positions[0]]).unwrap());
+        assert!(!quiz.is_passed());
+        assert_eq!(quiz.mistakes(), 2);
+    }
+
+    #[test]
+    fn test_check_all() {
+        let mnemonic = Mnemonic::new(&[0x6e; 20], Language::Japanese).unwrap();
+        let words: Vec<&str> = mnemonic.phrase().split('\u{3000}').collect();
+        let mut quiz = quiz(&mnemonic, 4);
+        let answers: Vec<&str> = quiz.positions().iter().map(|&p| words[p]).collect();
+
+        assert!(matches!(
+            quiz.check_all(&answers[..3]),
+            Err(Error::InvalidQuiz { .. })
+        ));
+        assert!(quiz.check_all(&answers).unwrap());
+        assert_eq!(quiz.correct_count(), 4);
+    }
+
+    #[test]
+    fn test_invalid_quiz() {
+        let mnemonic = Mnemonic::new(&[0x6e; 16], Language::English).unwrap();
+        let mut source = DeterministicEntropy::new(b"invalid");
+        for questions in [0, 13] {
+            assert!(matches!(
+                mnemonic.backup_quiz_with_source(questions, &mut source),
+                Err(Error::InvalidQuiz { .. })
+            ));
+        }
+
+        let mut quiz = quiz(&mnemonic, 1);
+        let missing = (0..12).find(|p| !quiz.positions().contains(p)).unwrap();
+        assert_eq!(
+            quiz.check(missing, "abandon"),
+            Err(Error::InvalidQuiz {
+                reason: format!("Word {} is not part of the quiz", missing + 1)
+            })
+        );
+        assert_eq!(quiz.mistakes(), 0);
+    }
+
+    #[test]
+    fn test_debug_hides_words() {
+        let mnemonic = Mnemonic::new(&[0u8; 16], Language::English).unwrap();
+        let quiz = quiz(&mnemonic, 12);
+        let debug = format!("{quiz:?}");
+        assert!(debug.contains("positions"));
+        assert!(!debug.contains("abandon"));
+        assert!(!debug.contains("about"));
+    }
+}
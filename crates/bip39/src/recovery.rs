@@ -0,0 +1,445 @@
+//! Recovery of damaged mnemonic phrases.
+//!
+//! Backups are often written down by hand and read back years later. This
+//! module searches for the phrases a damaged backup could have been:
+//!
+//! - Words marked `?` are unknown and tried against the whole wordlist
+//! - Words that are not in the wordlist are replaced by the wordlist entries
+//!   within a small edit distance
+//!
+//! Every combination that passes the BIP39 checksum is returned, ranked by
+//! the total edit distance of its corrections. An optional verifier can check
+//! each candidate's seed against a known fingerprint or address, so that only
+//! the phrase that actually controls the funds is reported.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Language, PhraseRecovery};
+//!
+//! // The last word was unreadable and the first one was misspelled.
+//! let phrase = "abandn abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon ?";
+//! let candidates = PhraseRecovery::new(phrase, Language::English).search()?;
+//!
+//! assert!(candidates
+//!     .iter()
+//!     .any(|c| c.mnemonic().phrase().ends_with("abandon about")));
+//! assert!(candidates.iter().all(|c| c.distance() == 1));
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use sha2::{Digest, Sha256};
+
+use crate::language::normalize_word;
+use crate::utils::edit_distance;
+use crate::{Error, Language, Mnemonic, Result, Seed, WordCount};
+
+/// The marker for a word that is entirely unknown.
+pub const UNKNOWN_WORD: &str = "?";
+
+/// Default maximum edit distance considered when correcting a misspelled word.
+const DEFAULT_MAX_EDIT_DISTANCE: usize = 2;
+
+/// Default upper bound on the number of phrases checked by one search.
+///
+/// Two unknown words need 2048² ≈ 4.2 million checksum checks.
+const DEFAULT_MAX_COMBINATIONS: u64 = 1 << 23;
+
+/// A callback that accepts or rejects a candidate's 64-byte seed.
+type SeedVerifier<'a> = Box<dyn FnMut(&Seed) -> bool + 'a>;
+
+/// A checksum-valid phrase found by [`PhraseRecovery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryCandidate {
+    mnemonic: Mnemonic,
+    distance: usize,
+    corrected_positions: Vec<usize>,
+}
+
+impl RecoveryCandidate {
+    /// Returns the recovered mnemonic.
+    pub fn mnemonic(&self) -> &Mnemonic {
+        &self.mnemonic
+    }
+
+    /// Returns the total edit distance of all corrected words.
+    ///
+    /// Unknown (`?`) words do not contribute to the distance.
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    /// Returns the zero-based positions of words that were filled in or corrected.
+    pub fn corrected_positions(&self) -> &[usize] {
+        &self.corrected_positions
+    }
+
+    /// Consumes the candidate and returns the recovered mnemonic.
+    pub fn into_mnemonic(self) -> Mnemonic {
+        self.mnemonic
+    }
+}
+
+/// Searches for checksum-valid phrases matching a damaged mnemonic.
+///
+/// Configure the search with the builder methods and run it with
+/// [`search`](PhraseRecovery::search).
+///
+/// # Examples
+///
+/// Filtering candidates with a seed verifier:
+///
+/// ```rust
+/// use khodpay_bip39::{Language, Mnemonic, PhraseRecovery};
+///
+/// let original = Mnemonic::new(&[0u8; 16], Language::English)?;
+/// let expected_seed = original.to_seed("")?;
+///
+/// let phrase = "abandon abandon ? abandon abandon abandon abandon abandon abandon abandon abandon about";
+/// let candidates = PhraseRecovery::new(phrase, Language::English)
+///     .verify_seed("", move |seed| *seed == expected_seed)
+///     .search()?;
+///
+/// assert_eq!(candidates.len(), 1);
+/// assert_eq!(candidates[0].mnemonic(), &original);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+pub struct PhraseRecovery<'a> {
+    phrase: &'a str,
+    language: Language,
+    max_edit_distance: usize,
+    max_combinations: u64,
+    passphrase: String,
+    verifier: Option<SeedVerifier<'a>>,
+}
+
+impl<'a> PhraseRecovery<'a> {
+    /// Creates a search for `phrase` in the given language.
+    ///
+    /// Unknown words must be written as `?`. Words are lowercased and
+    /// NFKD-normalized like [`Language::index_of`], so accented words typed in
+    /// composed form are not counted as corrections.
+    pub fn new(phrase: &'a str, language: Language) -> Self {
+        Self {
+            phrase,
+            language,
+            max_edit_distance: DEFAULT_MAX_EDIT_DISTANCE,
+            max_combinations: DEFAULT_MAX_COMBINATIONS,
+            passphrase: String::new(),
+            verifier: None,
+        }
+    }
+
+    /// Sets the maximum edit distance used to correct misspelled words (default 2).
+    pub fn max_edit_distance(mut self, distance: usize) -> Self {
+        self.max_edit_distance = distance;
+        self
+    }
+
+    /// Sets the maximum number of phrases the search may check (default 2²³).
+    pub fn max_combinations(mut self, limit: u64) -> Self {
+        self.max_combinations = limit;
+        self
+    }
+
+    /// Keeps only candidates whose seed, derived with `passphrase`, satisfies `verifier`.
+    ///
+    /// The verifier typically derives a master fingerprint or first address
+    /// from the 64-byte seed and compares it with a known value. Seed
+    /// derivation is expensive, so it only runs for checksum-valid candidates.
+    pub fn verify_seed<F>(mut self, passphrase: &str, verifier: F) -> Self
+    where
+        F: FnMut(&Seed) -> bool + 'a,
+    {
+        self.passphrase = passphrase.to_string();
+        self.verifier = Some(Box::new(verifier));
+        self
+    }
+
+    /// Runs the search and returns all matching candidates, closest first.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidMnemonic`] - If the phrase is empty
+    /// * [`Error::InvalidWordCount`] - If the number of words is not a BIP39 word count
+    /// * [`Error::InvalidWord`] - If a word has no wordlist entry within the edit distance
+    /// * [`Error::SearchSpaceTooLarge`] - If the search would exceed the combination limit
+    pub fn search(mut self) -> Result<Vec<RecoveryCandidate>> {
+        let tokens: Vec<String> = self.phrase.split_whitespace().map(normalize_word).collect();
+        if tokens.is_empty() {
+            return Err(Error::InvalidMnemonic {
+                reason: "Empty phrase".to_string(),
+            });
+        }
+        let word_count = WordCount::from_word_count(tokens.len())?;
+
+        let language = self.language.clone();
+        let word_list = language.word_list();
+        let options = tokens
+            .iter()
+            .enumerate()
+            .map(|(position, token)| self.word_options(word_list, position, token))
+            .collect::<Result<Vec<_>>>()?;
+
+        let combinations = options
+            .iter()
+            .fold(1u64, |acc, opts| acc.saturating_mul(opts.len() as u64));
+        if combinations > self.max_combinations {
+            return Err(Error::SearchSpaceTooLarge {
+                combinations,
+                limit: self.max_combinations,
+            });
+        }
+
+        let mut candidates = Vec::new();
+        let mut cursor = vec![0usize; options.len()];
+        let mut indices = vec![0u16; options.len()];
+        loop {
+            for (i, opts) in options.iter().enumerate() {
+                indices[i] = opts[cursor[i]].0;
+            }
+
+            if let Some(entropy) = checksum_valid_entropy(&indices, word_count) {
+                let mnemonic = Mnemonic::new(&entropy, language.clone())?;
+                if self.verify(&mnemonic)? {
+                    let distance = options
+                        .iter()
+                        .zip(&cursor)
+                        .map(|(opts, &c)| opts[c].1)
+                        .sum();
+                    let corrected_positions = tokens
+                        .iter()
+                        .zip(&indices)
+                        .enumerate()
+                        .filter(|(_, (token, &index))| **token != word_list[index as usize])
+                        .map(|(position, _)| position)
+                        .collect();
+                    candidates.push(RecoveryCandidate {
+                        mnemonic,
+                        distance,
+                        corrected_positions,
+                    });
+                }
+            }
+
+            if !advance(&mut cursor, &options) {
+                break;
+            }
+        }
+
+        candidates.sort_by_key(|candidate| candidate.distance);
+        Ok(candidates)
+    }
+
+    /// Lists the wordlist indices (and their edit distances) a token may stand for.
+    fn word_options(
+        &self,
+        word_list: &[&'static str; 2048],
+        position: usize,
+        token: &str,
+    ) -> Result<Vec<(u16, usize)>> {
+        if token == UNKNOWN_WORD {
+            return Ok((0..2048).map(|index| (index, 0)).collect());
+        }
+        if let Some(index) = self.language.index_of(token) {
+            return Ok(vec![(index, 0)]);
+        }
+
+        let mut options: Vec<(u16, usize)> = word_list
+            .iter()
+            .enumerate()
+            .map(|(index, word)| (index as u16, edit_distance(token, word)))
+            .filter(|&(_, distance)| distance <= self.max_edit_distance)
+            .collect();
+        if options.is_empty() {
+            return Err(Error::InvalidWord {
+                word: token.to_string(),
+                position,
+            });
+        }
+        options.sort_by_key(|&(_, distance)| distance);
+        Ok(options)
+    }
+
+    /// Runs the verifier, if any, against the candidate's seed.
+    fn verify(&mut self, mnemonic: &Mnemonic) -> Result<bool> {
+        match self.verifier.as_mut() {
+            Some(verifier) => Ok(verifier(&mnemonic.to_seed(&self.passphrase)?)),
+            None => Ok(true),
+        }
+    }
+}
+
+/// Advances the odometer `cursor` over `options`, returning `false` when exhausted.
+fn advance(cursor: &mut [usize], options: &[Vec<(u16, usize)>]) -> bool {
+    for (position, opts) in cursor.iter_mut().zip(options).rev() {
+        *position += 1;
+        if *position < opts.len() {
+            return true;
+        }
+        *position = 0;
+    }
+    false
+}
+
+/// Returns the entropy encoded by `indices` if its checksum is valid.
+fn checksum_valid_entropy(indices: &[u16], word_count: WordCount) -> Option<Vec<u8>> {
+    let entropy_length = word_count.entropy_length();
+    let checksum_bits = word_count.checksum_bits();
+
+    // 11 bits per word; at most 264 bits, i.e. 33 bytes.
+    let mut bits = [0u8; 33];
+    for (i, &index) in indices.iter().enumerate() {
+        for bit in 0..11 {
+            if (index >> (10 - bit)) & 1 == 1 {
+                let offset = i * 11 + bit;
+                bits[offset / 8] |= 0x80 >> (offset % 8);
+            }
+        }
+    }
+
+    let entropy = &bits[..entropy_length];
+    let expected = Sha256::digest(entropy)[0] >> (8 - checksum_bits);
+    let actual = bits[entropy_length] >> (8 - checksum_bits);
+    (expected == actual).then(|| entropy.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_normalization::UnicodeNormalization;
+
+    const VALID_12_WORD_PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_valid_phrase_is_its_own_candidate() {
+        let candidates = PhraseRecovery::new(VALID_12_WORD_PHRASE, Language::English)
+            .search()
+            .unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].mnemonic().phrase(), VALID_12_WORD_PHRASE);
+        assert_eq!(candidates[0].distance(), 0);
+        assert!(candidates[0].corrected_positions().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_last_word_yields_all_checksum_words() {
+        let phrase = VALID_12_WORD_PHRASE.replace("about", "?");
+        let candidates = PhraseRecovery::new(&phrase, Language::English)
+            .search()
+            .unwrap();
+
+        // 4 checksum bits leave 2048 / 16 = 128 valid final words.
+        assert_eq!(candidates.len(), 128);
+        assert!(candidates
+            .iter()
+            .any(|c| c.mnemonic().phrase() == VALID_12_WORD_PHRASE));
+        assert!(candidates.iter().all(|c| c.corrected_positions() == [11]));
+    }
+
+    #[test]
+    fn test_nfc_and_uppercase_input_is_not_corrected() {
+        let mnemonic = (0u8..)
+            .map(|byte| Mnemonic::new(&[byte; 16], Language::French).unwrap())
+            .find(|mnemonic| !mnemonic.phrase().is_ascii())
+            .unwrap();
+        let composed: String = mnemonic.phrase().nfc().collect();
+        assert_ne!(composed, mnemonic.phrase());
+
+        let mut words: Vec<String> = composed.split(' ').map(str::to_uppercase).collect();
+        words[11] = UNKNOWN_WORD.to_string();
+        let candidates = PhraseRecovery::new(&words.join(" "), Language::French)
+            .search()
+            .unwrap();
+
+        let recovered = candidates
+            .iter()
+            .find(|c| c.mnemonic() == &mnemonic)
+            .unwrap();
+        assert_eq!(recovered.distance(), 0);
+        assert_eq!(recovered.corrected_positions(), [11]);
+    }
+
+    #[test]
+    fn test_typo_is_corrected_and_ranked() {
+        let phrase = VALID_12_WORD_PHRASE.replace("about", "abuot");
+        let candidates = PhraseRecovery::new(&phrase, Language::English)
+            .search()
+            .unwrap();
+
+        assert!(!candidates.is_empty());
+        assert_eq!(candidates[0].mnemonic().phrase(), VALID_12_WORD_PHRASE);
+        assert!(candidates
+            .windows(2)
+            .all(|pair| pair[0].distance() <= pair[1].distance()));
+    }
+
+    #[test]
+    fn test_verifier_selects_the_right_phrase() {
+        let original = Mnemonic::from_phrase(VALID_12_WORD_PHRASE, Language::English).unwrap();
+        let expected = original.to_seed("TREZOR").unwrap();
+
+        let phrase = VALID_12_WORD_PHRASE.replace("about", "?");
+        let mut checked = 0;
+        let candidates = PhraseRecovery::new(&phrase, Language::English)
+            .verify_seed("TREZOR", |seed| {
+                checked += 1;
+                *seed == expected
+            })
+            .search()
+            .unwrap();
+
+        assert_eq!(checked, 128);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].mnemonic(), &original);
+    }
+
+    #[test]
+    fn test_unrecognizable_word_is_reported() {
+        let phrase = VALID_12_WORD_PHRASE.replacen("abandon", "xyzzyqqq", 1);
+        let result = PhraseRecovery::new(&phrase, Language::English).search();
+        assert_eq!(
+            result.unwrap_err(),
+            Error::InvalidWord {
+                word: "xyzzyqqq".to_string(),
+                position: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_search_space_limit() {
+        let phrase = "? ? ? abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let result = PhraseRecovery::new(phrase, Language::English).search();
+        assert!(matches!(
+            result.unwrap_err(),
+            Error::SearchSpaceTooLarge { .. }
+        ));
+    }
+
+    #[test]
+    fn test_invalid_word_count() {
+        let result = PhraseRecovery::new("abandon ? about", Language::English).search();
+        assert_eq!(result.unwrap_err(), Error::InvalidWordCount { count: 3 });
+    }
+
+    #[test]
+    fn test_checksum_valid_entropy_matches_mnemonic() {
+        let mnemonic = Mnemonic::new(&[0x5au8; 32], Language::English).unwrap();
+        let word_list = Language::English.word_list();
+        let indices: Vec<u16> = mnemonic
+            .phrase()
+            .split_whitespace()
+            .map(|w| word_list.iter().position(|x| *x == w).unwrap() as u16)
+            .collect();
+        assert_eq!(
+            checksum_valid_entropy(&indices, WordCount::TwentyFour).unwrap(),
+            mnemonic.entropy()
+        );
+    }
+}
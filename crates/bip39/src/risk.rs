@@ -0,0 +1,588 @@
+//! Detection of valid but unsafe mnemonics.
+//!
+//! A phrase can pass every BIP39 check and still protect nothing: test
+//! vectors, the default phrases of development tools and phrases built from
+//! obvious patterns are swept by bots within seconds of receiving funds.
+//! [`Mnemonic::risk_report`] looks for these cases so a wallet can refuse to
+//! start on them.
+//!
+//! Random entropy triggers the statistical checks with negligible
+//! probability. Passing them does not prove the entropy is random; it only
+//! catches phrases that were clearly not generated securely.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Language, Mnemonic, MnemonicRisk};
+//!
+//! let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let report = Mnemonic::from_phrase(phrase, Language::English)?.risk_report();
+//!
+//! assert!(report.is_weak());
+//! assert_eq!(
+//!     report.risks()[0],
+//!     MnemonicRisk::KnownPhrase { source: "BIP39 English test vector" }
+//! );
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use zeroize::Zeroizing;
+
+use crate::{codec, Mnemonic};
+
+/// Entropy (hex) of published phrases, with where they were published.
+const KNOWN_ENTROPIES: &[(&str, &str)] = &[
+    (
+        "00000000000000000000000000000000",
+        "BIP39 English test vector",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "BIP39 English test vector",
+    ),
+    (
+        "80808080808080808080808080808080",
+        "BIP39 English test vector",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffff",
+        "BIP39 English test vector",
+    ),
+    (
+        "000000000000000000000000000000000000000000000000",
+        "BIP39 English test vector",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "BIP39 English test vector",
+    ),
+    (
+        "808080808080808080808080808080808080808080808080",
+        "BIP39 English test vector",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffff",
+        "BIP39 English test vector",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "BIP39 English test vector",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "BIP39 English test vector",
+    ),
+    (
+        "8080808080808080808080808080808080808080808080808080808080808080",
+        "BIP39 English test vector",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "BIP39 English test vector",
+    ),
+    (
+        "9e885d952ad362caeb4efe34a8e91bd2",
+        "BIP39 English test vector",
+    ),
+    (
+        "6610b25967cdcca9d59875f5cb50b0ea75433311869e930b",
+        "BIP39 English test vector",
+    ),
+    (
+        "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+        "BIP39 English test vector",
+    ),
+    (
+        "c0ba5a8e914111210f2bd131f3d5e08d",
+        "BIP39 English test vector",
+    ),
+    (
+        "6d9be1ee6ebd27a258115aad99b7317b9c8d28b6d76431c3",
+        "BIP39 English test vector",
+    ),
+    (
+        "9f6a2878b2520799a44ef18bc7df394e7061a224d2c33cd015b157d746869863",
+        "BIP39 English test vector",
+    ),
+    (
+        "23db8160a31d3e0dca3688ed941adbf3",
+        "BIP39 English test vector",
+    ),
+    (
+        "8197a4a47f0425faeaa69deebc05ca29c0a5b5cc76ceacc0",
+        "BIP39 English test vector",
+    ),
+    (
+        "066dca1a2bb7e8a1db2832148ce9933eea0f3ac9548d793112d9a95c9407efad",
+        "BIP39 English test vector",
+    ),
+    (
+        "f30f8c1da665478f49b001d94c5fc452",
+        "BIP39 English test vector",
+    ),
+    (
+        "c10ec20dc3cd9f652c7fac2f1230f7a3c828389a14392f05",
+        "BIP39 English test vector",
+    ),
+    (
+        "f585c11aec520db57dd353c69554b21a89b20fb0650966fa0a9d6f74fd989d8f",
+        "BIP39 English test vector",
+    ),
+    (
+        "77c2b00716cec7213839159e404db50d",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "b63a9c59a6e641f288ebc103017f1da9f8290b3da6bdef7b",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "3e141609b97933b66a060dcddc71fad1d91677db872031e85f4c015c5e7e8982",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "0460ef47585604c5660618db2e6a7e7f",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "72f60ebac5dd8add8d2a25a797102c3ce21bc029c200076f",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "2c85efc7f24ee4573d2b81a6ec66cee209b2dcbd09d8eddc51e0215b0b68e416",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "eaebabb2383351fd31d703840b32e9e2",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "7ac45cfe7722ee6c7ba84fbc2d5bd61b45cb2fe5eb65aa78",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "4fa1a8bc3e6d80ee1316050e862c1812031493212b7ec3f3bb1b08f168cabeef",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "18ab19a9f54a9274f03e5209a2ac8a91",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "18a2e1d81b8ecfb2a333adcb0c17a5b9eb76cc5d05db91a4",
+        "BIP39 Japanese test vector",
+    ),
+    (
+        "15da872c95a13dd738fbf50e427583ad61f18fd99f628c417a61cf8343c90419",
+        "BIP39 Japanese test vector",
+    ),
+    // "test test test test test test test test test test test junk"
+    (
+        "df9bf37e6fcdf9bf37e6fcdf9bf37e3c",
+        "Hardhat and Foundry development phrase",
+    ),
+    // "candy maple cake sugar pudding cream honey rich smooth crumble sweet treat"
+    (
+        "2150f0816c6ad265db4dcacce69b6ef3",
+        "Truffle development phrase",
+    ),
+    // "myth like bonus scare over problem client lizard pioneer submit female collect"
+    (
+        "92903465e029df56cab416a53b015396",
+        "Ganache deterministic phrase",
+    ),
+    // "all all all all all all all all all all all all"
+    (
+        "0660cc198330660cc198330660cc1983",
+        "Trezor test suite phrase",
+    ),
+];
+
+/// Minimum number of consecutive words with evenly spaced indices reported
+/// as a sequence.
+///
+/// A random phrase contains such a run of four with probability below
+/// 1 in 100,000.
+const MIN_SEQUENCE_LENGTH: usize = 4;
+
+/// Probability below which the statistical entropy checks report bias.
+const BIAS_PROBABILITY_THRESHOLD: f64 = 1e-6;
+
+/// Standard score of the one-bit count matching
+/// [`BIAS_PROBABILITY_THRESHOLD`] (two-sided, normal approximation).
+const BIT_BALANCE_Z_SCORE: f64 = 4.89;
+
+/// A reason why a valid mnemonic is unsafe to hold funds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MnemonicRisk {
+    /// The entropy is that of a published phrase.
+    KnownPhrase {
+        /// Where the phrase was published
+        source: &'static str,
+    },
+
+    /// At most half of the words are distinct.
+    RepeatedWords {
+        /// The number of distinct words
+        distinct_words: usize,
+    },
+
+    /// Consecutive words whose wordlist indices are evenly spaced, such as
+    /// the same word repeated or words taken in wordlist order.
+    SequentialWords {
+        /// The zero-based position of the first word in the run
+        position: usize,
+        /// The number of words in the run
+        length: usize,
+    },
+
+    /// The entropy bytes repeat with a short period.
+    RepeatingEntropy {
+        /// The period in bytes
+        period: usize,
+    },
+
+    /// The entropy has far more one bits or zero bits than random data.
+    BiasedEntropy {
+        /// The number of one bits
+        ones: usize,
+        /// The number of entropy bits
+        bits: usize,
+    },
+
+    /// The entropy contains a run of identical bits too long for random data.
+    BitRun {
+        /// The length of the longest run
+        length: usize,
+    },
+}
+
+impl fmt::Display for MnemonicRisk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MnemonicRisk::KnownPhrase { source } => write!(f, "Phrase is a published {source}"),
+            MnemonicRisk::RepeatedWords { distinct_words } => {
+                write!(f, "Too few distinct words ({distinct_words})")
+            }
+            MnemonicRisk::SequentialWords { position, length } => write!(
+                f,
+                "{length} words from position {position} have evenly spaced indices"
+            ),
+            MnemonicRisk::RepeatingEntropy { period } => {
+                write!(f, "Entropy repeats every {period} bytes")
+            }
+            MnemonicRisk::BiasedEntropy { ones, bits } => {
+                write!(f, "{ones} of {bits} entropy bits are set")
+            }
+            MnemonicRisk::BitRun { length } => {
+                write!(f, "{length} identical entropy bits in a row")
+            }
+        }
+    }
+}
+
+/// The result of [`Mnemonic::risk_report`].
+///
+/// Displays as the list of risks found, separated by semicolons.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskReport {
+    risks: Vec<MnemonicRisk>,
+}
+
+impl RiskReport {
+    /// Returns `true` if any risk was found.
+    pub fn is_weak(&self) -> bool {
+        !self.risks.is_empty()
+    }
+
+    /// Returns every risk found, known phrases first.
+    pub fn risks(&self) -> &[MnemonicRisk] {
+        &self.risks
+    }
+}
+
+impl fmt::Display for RiskReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.risks.is_empty() {
+            return write!(f, "No risks found");
+        }
+        for (i, risk) in self.risks.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{risk}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Mnemonic {
+    /// Checks whether this mnemonic is valid but unsafe to hold funds.
+    ///
+    /// The report lists:
+    ///
+    /// - entropy of a published phrase (BIP39 test vectors and the default
+    ///   phrases of Hardhat, Foundry, Truffle, Ganache and the Trezor test
+    ///   suite)
+    /// - phrases in which at most half of the words are distinct
+    /// - runs of four or more words whose wordlist indices are evenly spaced
+    /// - entropy bytes that repeat with a short period
+    /// - entropy with far more one or zero bits, or a longer run of
+    ///   identical bits, than a random source would produce with probability
+    ///   one in a million
+    ///
+    /// The checks depend only on the entropy, so the result is the same in
+    /// every language.
+    ///
+    /// # Returns
+    ///
+    /// A [`RiskReport`] listing every [`MnemonicRisk`] found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic, WordCount};
+    ///
+    /// let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English)?;
+    /// assert!(!mnemonic.risk_report().is_weak());
+    ///
+    /// let weak = Mnemonic::new(&[0x5a; 16], Language::English)?;
+    /// assert!(weak.risk_report().is_weak());
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn risk_report(&self) -> RiskReport {
+        let entropy = self.entropy();
+        let mut risks = Vec::new();
+
+        let hex = Zeroizing::new(
+            entropy
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>(),
+        );
+        if let Some(&(_, source)) = KNOWN_ENTROPIES.iter().find(|(known, _)| *known == *hex) {
+            risks.push(MnemonicRisk::KnownPhrase { source });
+        }
+
+        let indices =
+            codec::entropy_to_indices(entropy).expect("mnemonic entropy always has a valid length");
+        let mut distinct = Zeroizing::new(indices.to_vec());
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() * 2 <= indices.len() {
+            risks.push(MnemonicRisk::RepeatedWords {
+                distinct_words: distinct.len(),
+            });
+        }
+
+        // A run of n words spans n - 1 equal differences.
+        let steps = Zeroizing::new(
+            indices
+                .windows(2)
+                .map(|pair| i32::from(pair[1]) - i32::from(pair[0]))
+                .collect::<Vec<_>>(),
+        );
+        let mut position = 0;
+        for run in steps.chunk_by(|a, b| a == b) {
+            if run.len() + 1 >= MIN_SEQUENCE_LENGTH {
+                risks.push(MnemonicRisk::SequentialWords {
+                    position,
+                    length: run.len() + 1,
+                });
+            }
+            position += run.len();
+        }
+
+        if let Some(period) = (1..=entropy.len() / 4)
+            .find(|&p| (p..entropy.len()).all(|i| entropy[i] == entropy[i - p]))
+        {
+            risks.push(MnemonicRisk::RepeatingEntropy { period });
+        }
+
+        let bits = entropy.len() * 8;
+        let ones = entropy.iter().map(|byte| byte.count_ones() as usize).sum();
+        let deviation = libm::fabs(ones as f64 - bits as f64 / 2.0);
+        if deviation > BIT_BALANCE_Z_SCORE * libm::sqrt(bits as f64) / 2.0 {
+            risks.push(MnemonicRisk::BiasedEntropy { ones, bits });
+        }
+
+        // A run of length r starts at a given bit with probability 2^-(r-1).
+        let max_run =
+            1 + libm::ceil(libm::log(BIAS_PROBABILITY_THRESHOLD / bits as f64) / libm::log(0.5))
+                as usize;
+        let mut longest_run = 0;
+        let mut run = 0;
+        let mut previous = None;
+        for bit in (0..bits).map(|i| (entropy[i / 8] >> (7 - i % 8)) & 1) {
+            run = if previous == Some(bit) { run + 1 } else { 1 };
+            longest_run = longest_run.max(run);
+            previous = Some(bit);
+        }
+        if longest_run > max_run {
+            risks.push(MnemonicRisk::BitRun {
+                length: longest_run,
+            });
+        }
+
+        RiskReport { risks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Language, WordCount};
+    use sha2::{Digest, Sha256};
+
+    fn report(phrase: &str) -> RiskReport {
+        Mnemonic::from_phrase(phrase, Language::English)
+            .unwrap()
+            .risk_report()
+    }
+
+    #[test]
+    fn test_known_phrases() {
+        let cases = [
+            (
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "BIP39 English test vector",
+            ),
+            (
+                "test test test test test test test test test test test junk",
+                "Hardhat and Foundry development phrase",
+            ),
+            (
+                "candy maple cake sugar pudding cream honey rich smooth crumble sweet treat",
+                "Truffle development phrase",
+            ),
+            (
+                "myth like bonus scare over problem client lizard pioneer submit female collect",
+                "Ganache deterministic phrase",
+            ),
+            (
+                "all all all all all all all all all all all all",
+                "Trezor test suite phrase",
+            ),
+        ];
+        for (phrase, source) in cases {
+            let report = report(phrase);
+            assert!(report.is_weak());
+            assert_eq!(report.risks()[0], MnemonicRisk::KnownPhrase { source });
+        }
+    }
+
+    #[test]
+    fn test_known_entropy_in_other_languages() {
+        let mnemonic = Mnemonic::new(
+            &hex::decode("9e885d952ad362caeb4efe34a8e91bd2").unwrap(),
+            Language::Spanish,
+        )
+        .unwrap();
+        assert_eq!(
+            mnemonic.risk_report().risks(),
+            [MnemonicRisk::KnownPhrase {
+                source: "BIP39 English test vector"
+            }]
+        );
+    }
+
+    #[test]
+    fn test_repeated_words() {
+        let report = report("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+        assert!(report
+            .risks()
+            .contains(&MnemonicRisk::RepeatedWords { distinct_words: 2 }));
+        assert!(report.risks().contains(&MnemonicRisk::SequentialWords {
+            position: 0,
+            length: 11
+        }));
+    }
+
+    #[test]
+    fn test_sequential_words() {
+        // Indices 100, 101, 102, 103 followed by random-looking words
+        let mut entropy = Sha256::digest(b"sequential")[..16].to_vec();
+        let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+        assert!(!mnemonic.risk_report().is_weak());
+
+        // Overwrite the first 44 bits with the four indices
+        let prefix: u64 = (100 << 33) | (101 << 22) | (102 << 11) | 103;
+        let shifted = prefix << 20;
+        for (i, byte) in entropy.iter_mut().take(6).enumerate() {
+            let mask = if i == 5 { 0xf0 } else { 0xff };
+            *byte = (*byte & !mask) | ((shifted >> (56 - 8 * i)) as u8 & mask);
+        }
+        let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+        assert_eq!(
+            mnemonic.risk_report().risks(),
+            [MnemonicRisk::SequentialWords {
+                position: 0,
+                length: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn test_repeating_entropy() {
+        let entropy: Vec<u8> = [0x3c, 0xa7, 0x51].repeat(11)[..32].to_vec();
+        let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+        assert!(mnemonic
+            .risk_report()
+            .risks()
+            .contains(&MnemonicRisk::RepeatingEntropy { period: 3 }));
+    }
+
+    #[test]
+    fn test_biased_entropy() {
+        // Random-looking bytes with most bits set
+        let entropy: Vec<u8> = Sha256::digest(b"biased")[..16]
+            .iter()
+            .map(|byte| byte | 0xb6)
+            .collect();
+        let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+        let ones = entropy.iter().map(|b| b.count_ones() as usize).sum();
+        assert!(mnemonic
+            .risk_report()
+            .risks()
+            .contains(&MnemonicRisk::BiasedEntropy { ones, bits: 128 }));
+    }
+
+    #[test]
+    fn test_bit_run() {
+        let mut entropy = Sha256::digest(b"bit run")[..16].to_vec();
+        entropy[4..8].fill(0);
+        let mnemonic = Mnemonic::new(&entropy, Language::English).unwrap();
+        let risks = mnemonic.risk_report().risks().to_vec();
+        assert!(matches!(risks[..], [MnemonicRisk::BitRun { length }] if length >= 32));
+    }
+
+    #[test]
+    fn test_random_entropy_is_not_weak() {
+        let mut state = Sha256::digest(b"risk");
+        for &word_count in WordCount::all_variants() {
+            for _ in 0..200 {
+                state = Sha256::digest(state);
+                let entropy = &state[..word_count.entropy_length()];
+                let mnemonic = Mnemonic::new(entropy, Language::English).unwrap();
+                let report = mnemonic.risk_report();
+                assert!(!report.is_weak(), "{report}");
+                assert_eq!(report.to_string(), "No risks found");
+            }
+        }
+    }
+
+    #[test]
+    fn test_report_display() {
+        let report = report("all all all all all all all all all all all all");
+        assert_eq!(
+            report.to_string(),
+            "Phrase is a published Trezor test suite phrase; Too few distinct words (1); \
+             12 words from position 0 have evenly spaced indices"
+        );
+    }
+}
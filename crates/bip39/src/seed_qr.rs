@@ -0,0 +1,317 @@
+//! SeedQR and CompactSeedQR encoding of BIP39 mnemonics.
+//!
+//! [SeedQR] is the QR format used by SeedSigner and other air-gapped signers
+//! to back up and load a mnemonic:
+//!
+//! - **SeedQR** concatenates the zero-padded 4-digit wordlist index of every
+//!   word (e.g. `abandon` is `0000`, `zoo` is `2047`) and encodes the digits
+//!   in QR numeric mode. A 12-word mnemonic fits a version 2 symbol (25x25)
+//!   and a 24-word mnemonic a version 3 symbol (29x29).
+//! - **CompactSeedQR** encodes the raw entropy bytes in QR byte mode, giving
+//!   a smaller symbol (21x21 for 12 words, 25x25 for 24 words) that is
+//!   quicker to transcribe by hand.
+//!
+//! The payloads only carry word indices, so the wordlist [`Language`] must
+//! be supplied when decoding. The word count follows from the payload length.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Language, Mnemonic};
+//!
+//! let mnemonic = Mnemonic::from_phrase(
+//!     "legal winner thank year wave sausage worth useful legal winner thank yellow",
+//!     Language::English,
+//! )?;
+//!
+//! let digits = mnemonic.to_seed_qr();
+//! assert!(digits.starts_with("1019"));
+//!
+//! let qr = mnemonic.to_seed_qr_code()?;
+//! assert_eq!(qr.size(), 25);
+//!
+//! // A scanner hands back the raw payload of either format
+//! let compact = mnemonic.to_compact_seed_qr();
+//! let recovered = Mnemonic::from_seed_qr_payload(&compact, Language::English)?;
+//! assert_eq!(recovered, mnemonic);
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+//!
+//! [SeedQR]: https://github.com/SeedSigner/seedsigner/blob/dev/docs/seed_qr/README.md
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use zeroize::Zeroizing;
+
+use crate::{Error, Language, Mnemonic, QrCode, Result, WordCount};
+
+/// Number of decimal digits per word in a standard SeedQR.
+const DIGITS_PER_WORD: usize = 4;
+
+impl Mnemonic {
+    /// Returns the standard SeedQR digit string of this mnemonic.
+    ///
+    /// Each word becomes its 4-digit, zero-padded index in the wordlist. The
+    /// digits encode the whole mnemonic, so they are zeroized on drop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic};
+    ///
+    /// let mnemonic = Mnemonic::new(&[0u8; 16], Language::English)?;
+    /// // Eleven times "abandon" (0000) followed by "about" (0003)
+    /// assert_eq!(*mnemonic.to_seed_qr(), format!("{}0003", "0000".repeat(11)));
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn to_seed_qr(&self) -> Zeroizing<String> {
+        let language = self.language();
+        // Sized up front so the buffer is never reallocated
+        let mut digits = Zeroizing::new(String::with_capacity(
+            self.word_count().word_count() * DIGITS_PER_WORD,
+        ));
+        for word in self.phrase().split_whitespace() {
+            let index = language
+                .index_of(word)
+                .expect("mnemonic words are always in the wordlist");
+            write!(digits, "{index:04}").expect("writing to a String cannot fail");
+        }
+        digits
+    }
+
+    /// Returns the CompactSeedQR payload of this mnemonic, i.e. its entropy
+    /// bytes, zeroized on drop.
+    pub fn to_compact_seed_qr(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.entropy().to_vec())
+    }
+
+    /// Renders this mnemonic as a standard SeedQR code.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQrData`] - Never for valid mnemonics; returned only
+    ///   if the payload does not fit a QR code
+    pub fn to_seed_qr_code(&self) -> Result<QrCode> {
+        QrCode::encode_numeric(&self.to_seed_qr())
+    }
+
+    /// Renders this mnemonic as a CompactSeedQR code.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidQrData`] - Never for valid mnemonics; returned only
+    ///   if the payload does not fit a QR code
+    pub fn to_compact_seed_qr_code(&self) -> Result<QrCode> {
+        QrCode::encode_bytes(&self.to_compact_seed_qr())
+    }
+
+    /// Decodes a standard SeedQR digit string.
+    ///
+    /// # Arguments
+    ///
+    /// * `digits` - The 4-digit word indices, without separators
+    /// * `language` - The wordlist the indices refer to
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSeedQr`] - If the string is not a sequence of 4-digit
+    ///   indices below 2048 for a valid word count
+    /// * [`Error::InvalidChecksum`] - If the decoded words fail the checksum
+    pub fn from_seed_qr(digits: &str, language: Language) -> Result<Mnemonic> {
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidSeedQr {
+                reason: "SeedQR payload must contain only digits".to_string(),
+            });
+        }
+        if digits.len() % DIGITS_PER_WORD != 0
+            || WordCount::from_word_count(digits.len() / DIGITS_PER_WORD).is_err()
+        {
+            return Err(Error::InvalidSeedQr {
+                reason: format!(
+                    "{} digits do not encode 12, 15, 18, 21 or 24 words",
+                    digits.len()
+                ),
+            });
+        }
+
+        let words = digits
+            .as_bytes()
+            .chunks(DIGITS_PER_WORD)
+            .map(|chunk| {
+                // The chunk is ASCII digits, so parsing cannot fail
+                let index: u16 = core::str::from_utf8(chunk).unwrap().parse().unwrap();
+                language.word_at(index).ok_or_else(|| Error::InvalidSeedQr {
+                    reason: format!("Word index {index} is out of range"),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The indices fix the entropy whatever the wordlist, so a phrase that
+        // is also valid in another language is not ambiguous here
+        Mnemonic::from_phrase_confirmed(&words.join(" "), language)
+    }
+
+    /// Decodes a CompactSeedQR payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The raw entropy bytes from the QR code
+    /// * `language` - The wordlist to use for the mnemonic
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSeedQr`] - If the payload is not 16, 20, 24, 28 or 32 bytes
+    pub fn from_compact_seed_qr(bytes: &[u8], language: Language) -> Result<Mnemonic> {
+        if WordCount::from_entropy_length(bytes.len()).is_err() {
+            return Err(Error::InvalidSeedQr {
+                reason: format!(
+                    "CompactSeedQR payload must be 16, 20, 24, 28 or 32 bytes, got {}",
+                    bytes.len()
+                ),
+            });
+        }
+        Mnemonic::new(bytes, language)
+    }
+
+    /// Decodes a scanned QR payload in either SeedQR format.
+    ///
+    /// Standard SeedQR payloads are 48 to 96 ASCII digits while
+    /// CompactSeedQR payloads are 16 to 32 bytes, so the format is detected
+    /// from the payload itself.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSeedQr`] - If the payload matches neither format
+    /// * [`Error::InvalidChecksum`] - If a standard SeedQR fails the checksum
+    pub fn from_seed_qr_payload(payload: &[u8], language: Language) -> Result<Mnemonic> {
+        if WordCount::from_entropy_length(payload.len()).is_ok() {
+            return Mnemonic::from_compact_seed_qr(payload, language);
+        }
+        let digits = core::str::from_utf8(payload).map_err(|_| Error::InvalidSeedQr {
+            reason: format!(
+                "{} bytes match neither SeedQR nor CompactSeedQR",
+                payload.len()
+            ),
+        })?;
+        Mnemonic::from_seed_qr(digits.trim(), language)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SeedSigner's documented 24-word SeedQR example.
+    const SEEDSIGNER_PHRASE: &str = "attack pizza motion avocado network gather crop fresh patrol unusual wild holiday candy pony ranch winter theme error hybrid van cereal salon goddess expire";
+    const SEEDSIGNER_DIGITS: &str = "011513251154012711900771041507421289190620080870026613431420201617920614089619290300152408010643";
+
+    fn parse(phrase: &str) -> Mnemonic {
+        Mnemonic::from_phrase(phrase, Language::English).unwrap()
+    }
+
+    #[test]
+    fn test_seedsigner_vector() {
+        let mnemonic = parse(SEEDSIGNER_PHRASE);
+        assert_eq!(*mnemonic.to_seed_qr(), SEEDSIGNER_DIGITS);
+        assert_eq!(
+            Mnemonic::from_seed_qr(SEEDSIGNER_DIGITS, Language::English).unwrap(),
+            mnemonic
+        );
+
+        let qr = mnemonic.to_seed_qr_code().unwrap();
+        assert_eq!(qr.version(), 3);
+        assert_eq!(qr.size(), 29);
+
+        // Module for module the symbol of the qrcodegen reference encoder
+        let expected = qrcodegen::QrCode::encode_segments_advanced(
+            &[qrcodegen::QrSegment::make_numeric(SEEDSIGNER_DIGITS)],
+            qrcodegen::QrCodeEcc::Low,
+            qrcodegen::Version::new(1),
+            qrcodegen::Version::new(3),
+            None,
+            false,
+        )
+        .unwrap();
+        for y in 0..29 {
+            for x in 0..29 {
+                assert_eq!(qr.is_dark(x, y), expected.get_module(x as i32, y as i32));
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_round_trip_all_word_counts() {
+        for &word_count in WordCount::all_variants() {
+            for language in [Language::English, Language::Japanese, Language::French] {
+                let mnemonic = Mnemonic::generate(word_count, language.clone()).unwrap();
+
+                let digits = mnemonic.to_seed_qr();
+                assert_eq!(digits.len(), word_count.word_count() * 4);
+                let decoded = Mnemonic::from_seed_qr(&digits, language.clone()).unwrap();
+                assert_eq!(decoded, mnemonic);
+                assert_eq!(decoded.word_count(), word_count);
+                assert_eq!(decoded.language(), language);
+
+                let compact = mnemonic.to_compact_seed_qr();
+                assert_eq!(compact.len(), word_count.entropy_length());
+                let decoded = Mnemonic::from_compact_seed_qr(&compact, language.clone()).unwrap();
+                assert_eq!(decoded, mnemonic);
+
+                for payload in [digits.as_bytes(), &compact[..]] {
+                    let decoded =
+                        Mnemonic::from_seed_qr_payload(payload, language.clone()).unwrap();
+                    assert_eq!(decoded, mnemonic);
+                    assert_eq!(decoded.language(), language);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_code_sizes() {
+        let twelve = Mnemonic::new(&[0x7F; 16], Language::English).unwrap();
+        let twenty_four = Mnemonic::new(&[0x7F; 32], Language::English).unwrap();
+
+        assert_eq!(twelve.to_seed_qr_code().unwrap().size(), 25);
+        assert_eq!(twelve.to_compact_seed_qr_code().unwrap().size(), 21);
+        assert_eq!(twenty_four.to_seed_qr_code().unwrap().size(), 29);
+        assert_eq!(twenty_four.to_compact_seed_qr_code().unwrap().size(), 25);
+    }
+
+    #[test]
+    fn test_invalid_payloads() {
+        let invalid = |result: Result<Mnemonic>| matches!(result, Err(Error::InvalidSeedQr { .. }));
+
+        assert!(invalid(Mnemonic::from_seed_qr("0000", Language::English)));
+        assert!(invalid(Mnemonic::from_seed_qr(
+            &"0000".repeat(13),
+            Language::English
+        )));
+        assert!(invalid(Mnemonic::from_seed_qr(
+            &format!("{}2048", "0000".repeat(11)),
+            Language::English
+        )));
+        assert!(invalid(Mnemonic::from_seed_qr(
+            &format!("{}000a", "0000".repeat(11)),
+            Language::English
+        )));
+        assert!(invalid(Mnemonic::from_compact_seed_qr(
+            &[0u8; 17],
+            Language::English
+        )));
+        assert!(invalid(Mnemonic::from_seed_qr_payload(
+            &[0xFF; 50],
+            Language::English
+        )));
+
+        // Twelve "abandon" words fail the checksum
+        assert_eq!(
+            Mnemonic::from_seed_qr(&"0000".repeat(12), Language::English).unwrap_err(),
+            Error::InvalidChecksum
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Seed XOR splitting of BIP39 mnemonics.
+//!
+//! [Seed XOR] splits a mnemonic into several parts of the same length whose
+//! entropies XOR together to the original entropy. Every part is itself a
+//! valid BIP39 mnemonic (with its checksum recomputed), so each one can hold
+//! a small decoy balance, and all parts are needed to recover the secret.
+//! The scheme is simple enough to combine by hand and is compatible with
+//! Coldcard's implementation.
+//!
+//! Unlike [SLIP-39](crate::Slip39Share) there is no threshold: losing any one
+//! part loses the secret.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Language, Mnemonic, WordCount};
+//!
+//! let mnemonic = Mnemonic::generate(WordCount::TwentyFour, Language::English)?;
+//! let parts = mnemonic.to_seed_xor_parts(3)?;
+//! assert_eq!(parts.len(), 3);
+//! assert!(parts.iter().all(|part| part.word_count() == WordCount::TwentyFour));
+//!
+//! let recovered = Mnemonic::from_seed_xor_parts(&parts)?;
+//! assert_eq!(recovered, mnemonic);
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+//!
+//! [Seed XOR]: https://seedxor.com/
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use zeroize::Zeroizing;
+
+#[cfg(feature = "rand")]
+use crate::OsEntropy;
+use crate::{EntropySource, Error, Mnemonic, Result};
+
+/// Minimum number of parts in a Seed XOR split.
+const MIN_PARTS: usize = 2;
+
+impl Mnemonic {
+    /// Splits this mnemonic into `count` Seed XOR parts.
+    ///
+    /// The first `count - 1` parts are random; the last is chosen so that
+    /// the entropies of all parts XOR to this mnemonic's entropy. All parts
+    /// use this mnemonic's word count and language.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidShare`] - If `count` is less than 2
+    /// * [`Error::RandomGeneration`] - If the OS random number generator fails
+    #[cfg(feature = "rand")]
+    pub fn to_seed_xor_parts(&self, count: usize) -> Result<Vec<Mnemonic>> {
+        self.to_seed_xor_parts_with_source(count, &mut OsEntropy)
+    }
+
+    /// Splits this mnemonic into `count` Seed XOR parts using `source` for the random parts.
+    ///
+    /// See [`to_seed_xor_parts`](Self::to_seed_xor_parts).
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidShare`] - If `count` is less than 2
+    /// * Any error returned by `source`
+    pub fn to_seed_xor_parts_with_source<S: EntropySource + ?Sized>(
+        &self,
+        count: usize,
+        source: &mut S,
+    ) -> Result<Vec<Mnemonic>> {
+        if count < MIN_PARTS {
+            return Err(Error::InvalidShare {
+                reason: format!("Seed XOR needs at least {MIN_PARTS} parts, {count} requested"),
+            });
+        }
+
+        let mut last = Zeroizing::new(self.entropy().to_vec());
+        let mut parts = Vec::with_capacity(count);
+        for _ in 1..count {
+            let mut entropy = Zeroizing::new(vec![0u8; last.len()]);
+            source.fill_entropy(&mut entropy)?;
+            xor_into(&mut last, &entropy);
+            parts.push(Mnemonic::new(&entropy, self.language())?);
+        }
+        parts.push(Mnemonic::new(&last, self.language())?);
+        Ok(parts)
+    }
+
+    /// Recombines Seed XOR parts into the original mnemonic.
+    ///
+    /// The parts may be given in any order. The result uses the language of
+    /// the first part.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientShares`] - If fewer than 2 parts are given
+    /// * [`Error::InvalidShare`] - If the parts have different word counts
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Language, Mnemonic};
+    ///
+    /// let parts = [
+    ///     "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    ///     "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    /// ]
+    /// .map(|phrase| Mnemonic::from_phrase(phrase, Language::English).unwrap());
+    ///
+    /// // Identical parts cancel out
+    /// let recovered = Mnemonic::from_seed_xor_parts(&parts)?;
+    /// assert_eq!(recovered.entropy(), [0u8; 16]);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn from_seed_xor_parts(parts: &[Mnemonic]) -> Result<Mnemonic> {
+        if parts.len() < MIN_PARTS {
+            return Err(Error::InsufficientShares {
+                required: MIN_PARTS,
+                provided: parts.len(),
+            });
+        }
+
+        let first = &parts[0];
+        let mut entropy = Zeroizing::new(first.entropy().to_vec());
+        for part in &parts[1..] {
+            if part.word_count() != first.word_count() {
+                return Err(Error::InvalidShare {
+                    reason: format!(
+                        "Seed XOR parts must have the same length: {} and {} words",
+                        first.word_count().word_count(),
+                        part.word_count().word_count()
+                    ),
+                });
+            }
+            xor_into(&mut entropy, part.entropy());
+        }
+        Mnemonic::new(&entropy, first.language())
+    }
+}
+
+/// XORs `other` into `target` byte by byte.
+fn xor_into(target: &mut [u8], other: &[u8]) {
+    for (byte, other) in target.iter_mut().zip(other) {
+        *byte ^= other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeterministicEntropy, Language};
+
+    /// Coldcard's published Seed XOR example (three 24-word parts).
+    const COLDCARD_PARTS: [&str; 3] = [
+        "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room",
+        "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager fringe sick camera series noodle toy crowd jeans select depth lounge",
+        "vault nominee cradle silk own frown throw leg cactus recall talent worry gadget surface shy planet purpose coffee drip few seven term squeeze educate",
+    ];
+    const COLDCARD_SECRET: &str = "silent toe meat possible chair blossom wait occur this worth option bag nurse find fish scene bench asthma bike wage world quit primary indoor";
+
+    fn parse(phrase: &str) -> Mnemonic {
+        Mnemonic::from_phrase(phrase, Language::English).unwrap()
+    }
+
+    #[test]
+    fn test_coldcard_vector() {
+        let parts: Vec<Mnemonic> = COLDCARD_PARTS.iter().map(|p| parse(p)).collect();
+        let secret = Mnemonic::from_seed_xor_parts(&parts).unwrap();
+        assert_eq!(secret.phrase(), COLDCARD_SECRET);
+
+        // Order does not matter
+        let reversed: Vec<Mnemonic> = parts.into_iter().rev().collect();
+        assert_eq!(
+            Mnemonic::from_seed_xor_parts(&reversed).unwrap().phrase(),
+            COLDCARD_SECRET
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_split_and_combine() {
+        for &word_count in crate::WordCount::all_variants() {
+            let mnemonic = Mnemonic::generate(word_count, Language::Spanish).unwrap();
+            for count in 2..=4 {
+                let parts = mnemonic.to_seed_xor_parts(count).unwrap();
+                assert_eq!(parts.len(), count);
+                for part in &parts {
+                    assert_eq!(part.word_count(), word_count);
+                    assert_eq!(part.language(), Language::Spanish);
+                    assert_ne!(part, &mnemonic);
+                    // Every part is a valid mnemonic on its own
+                    assert!(Mnemonic::from_phrase(part.phrase(), Language::Spanish).is_ok());
+                }
+                assert_eq!(Mnemonic::from_seed_xor_parts(&parts).unwrap(), mnemonic);
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_is_deterministic_with_source() {
+        let mnemonic = parse(COLDCARD_SECRET);
+        let first = mnemonic
+            .to_seed_xor_parts_with_source(3, &mut DeterministicEntropy::new(b"xor"))
+            .unwrap();
+        let second = mnemonic
+            .to_seed_xor_parts_with_source(3, &mut DeterministicEntropy::new(b"xor"))
+            .unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_invalid_part_counts() {
+        let mnemonic = parse(COLDCARD_SECRET);
+        assert!(matches!(
+            mnemonic.to_seed_xor_parts(1),
+            Err(Error::InvalidShare { .. })
+        ));
+        assert_eq!(
+            Mnemonic::from_seed_xor_parts(&[mnemonic]).unwrap_err(),
+            Error::InsufficientShares {
+                required: 2,
+                provided: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_mismatched_lengths() {
+        let parts = [
+            parse(COLDCARD_SECRET),
+            parse("legal winner thank year wave sausage worth useful legal winner thank yellow"),
+        ];
+        assert!(matches!(
+            Mnemonic::from_seed_xor_parts(&parts),
+            Err(Error::InvalidShare { .. })
+        ));
+    }
+}
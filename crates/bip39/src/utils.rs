@@ -21,7 +21,14 @@
 //! assert!(validate_phrase(invalid_phrase).is_err());
 //! ```
 
-use crate::{Error, Language, Result, WordCount};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::codec;
+#[cfg(feature = "rand")]
+use crate::OsEntropy;
+use crate::{EntropySource, Language, Mnemonic, Result, Seed, WordCount};
 
 /// Validates a BIP39 mnemonic phrase in English.
 ///
@@ -48,7 +55,6 @@ use crate::{Error, Language, Result, WordCount};
 /// * [`Error::InvalidWordCount`] - For unsupported word counts
 /// * [`Error::InvalidWord`] - For words not in the BIP39 word list
 /// * [`Error::InvalidChecksum`] - For phrases with invalid checksums
-/// * [`Error::Bip39Error`] - For other BIP39-related validation errors
 ///
 /// # Examples
 ///
@@ -94,7 +100,6 @@ pub fn validate_phrase(phrase: &str) -> Result<()> {
 /// * [`Error::InvalidWordCount`] - For unsupported word counts
 /// * [`Error::InvalidWord`] - For words not in the specified language's word list
 /// * [`Error::InvalidChecksum`] - For phrases with invalid checksums
-/// * [`Error::Bip39Error`] - For other BIP39-related validation errors
 ///
 /// # Examples
 ///
@@ -109,51 +114,9 @@ pub fn validate_phrase(phrase: &str) -> Result<()> {
 /// assert!(validate_phrase_in_language(phrase, Language::Japanese).is_err());
 /// ```
 pub fn validate_phrase_in_language(phrase: &str, language: Language) -> Result<()> {
-    // Step 1: Normalize whitespace and handle empty strings
-    let normalized = phrase.trim();
-    if normalized.is_empty() {
-        return Err(Error::InvalidMnemonic {
-            reason: "Empty phrase".to_string(),
-        });
-    }
-
-    // Split into words and remove extra whitespace
-    let words: Vec<&str> = normalized.split_whitespace().collect();
-
-    // Step 2: Validate word count using our WordCount enum
-    let _word_count = WordCount::from_word_count(words.len())?;
-
-    // Step 3: Check each word against BIP39 word list for the specified language
-    let upstream_language = language.to_upstream();
-    for (index, word) in words.iter().enumerate() {
-        let word_lower = word.to_lowercase();
-
-        // Check if word is in the BIP39 word list for the specified language
-        let word_list = upstream_language.word_list();
-        let is_valid_word = word_list.iter().any(|&w| w == word_lower);
-
-        if !is_valid_word {
-            return Err(Error::InvalidWord {
-                word: word.to_string(),
-                position: index,
-            });
-        }
-    }
-
-    // Step 4: Now validate the complete phrase including checksum in the specified language
-    let normalized_phrase = words
-        .iter()
-        .map(|w| w.to_lowercase())
-        .collect::<Vec<_>>()
-        .join(" ");
-
-    match bip39_upstream::Mnemonic::parse_in_normalized(upstream_language, &normalized_phrase) {
-        Ok(_) => Ok(()),
-        Err(_) => {
-            // At this point, words are valid but checksum is wrong
-            Err(Error::InvalidChecksum)
-        }
-    }
+    // Decoding checks, in order: an empty phrase, the word count, each word
+    // against the language's wordlist, and finally the checksum
+    codec::phrase_to_entropy(phrase, &language).map(|_| ())
 }
 
 /// Converts a BIP39 mnemonic phrase into a cryptographic seed (English).
@@ -172,7 +135,7 @@ pub fn validate_phrase_in_language(phrase: &str, language: Language) -> Result<(
 ///
 /// # Returns
 ///
-/// * `Ok([u8; 64])` - A 64-byte (512-bit) cryptographic seed
+/// * `Ok(Seed)` - A 64-byte (512-bit) cryptographic seed, zeroized on drop
 /// * `Err(Error)` - If the phrase is invalid or seed derivation fails
 ///
 /// # Security Note
@@ -189,14 +152,14 @@ pub fn validate_phrase_in_language(phrase: &str, language: Language) -> Result<(
 /// // Without passphrase
 /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
 /// let seed = phrase_to_seed(phrase, "").unwrap();
-/// assert_eq!(seed.len(), 64);
+/// assert_eq!(seed.as_bytes().len(), 64);
 ///
 /// // With passphrase (recommended for additional security)
 /// let seed_with_pass = phrase_to_seed(phrase, "my secret passphrase").unwrap();
-/// assert_eq!(seed_with_pass.len(), 64);
+/// assert_eq!(seed_with_pass.as_bytes().len(), 64);
 /// assert_ne!(seed, seed_with_pass); // Different passphrases produce different seeds
 /// ```
-pub fn phrase_to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64]> {
+pub fn phrase_to_seed(phrase: &str, passphrase: &str) -> Result<Seed> {
     phrase_to_seed_in_language(phrase, passphrase, Language::English)
 }
 
@@ -221,9 +184,15 @@ pub fn phrase_to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64]> {
 ///
 /// # Returns
 ///
-/// * `Ok([u8; 64])` - A 64-byte (512-bit) cryptographic seed
+/// * `Ok(Seed)` - A 64-byte (512-bit) cryptographic seed, zeroized on drop
 /// * `Err(Error)` - If the phrase is invalid or seed derivation fails
 ///
+/// # Normalization
+///
+/// The phrase and passphrase are NFKD-normalized as described on
+/// [`Mnemonic::to_seed`], so input in any Unicode normalization form and
+/// with any whitespace between words derives the standard BIP39 seed.
+///
 /// # Security Note
 ///
 /// The passphrase adds an extra layer of security but must be remembered.
@@ -238,42 +207,31 @@ pub fn phrase_to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64]> {
 /// // English phrase without passphrase
 /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
 /// let seed = phrase_to_seed_in_language(phrase, "", Language::English).unwrap();
-/// assert_eq!(seed.len(), 64);
+/// assert_eq!(seed.as_bytes().len(), 64);
 ///
 /// // With passphrase (recommended for additional security)
 /// let seed_with_pass = phrase_to_seed_in_language(phrase, "my secret passphrase", Language::English).unwrap();
-/// assert_eq!(seed_with_pass.len(), 64);
+/// assert_eq!(seed_with_pass.as_bytes().len(), 64);
 /// assert_ne!(seed, seed_with_pass); // Different passphrases produce different seeds
 /// ```
 pub fn phrase_to_seed_in_language(
     phrase: &str,
     passphrase: &str,
     language: Language,
-) -> Result<[u8; 64]> {
+) -> Result<Seed> {
     // Step 1: Validate the mnemonic phrase first
     // This ensures we only process valid BIP39 phrases in the specified language
-    validate_phrase_in_language(phrase, language)?;
-
-    // Step 2: Parse the mnemonic using the upstream crate
-    // We've already validated it, so this should succeed
-    // Using parse_in_normalized for consistent behavior with validation
-    let upstream_language = language.to_upstream();
-    let mnemonic = bip39_upstream::Mnemonic::parse_in_normalized(upstream_language, phrase)
-        .map_err(|_| Error::InvalidMnemonic {
-            reason: "Failed to parse validated phrase".to_string(),
-        })?;
-
-    // Step 3: Generate the seed using PBKDF2-HMAC-SHA512
-    // The upstream crate handles:
+    validate_phrase_in_language(phrase, language.clone())?;
+
+    // Step 2: Parse the validated phrase and derive the seed
+    // Mnemonic::to_seed handles:
     // - Unicode NFKD normalization of phrase and passphrase
     // - Salt = "mnemonic" + passphrase
     // - 2048 iterations of PBKDF2-HMAC-SHA512
-    // - 512-bit (64-byte) output
-    let seed = mnemonic.to_seed(passphrase);
-
-    // Step 4: Convert the seed bytes to a fixed-size array
-    // The seed is guaranteed to be 64 bytes per BIP39 spec
-    Ok(seed)
+    // - 512-bit (64-byte) output, written into a zeroizing Seed
+    // The seed depends only on the words, so a phrase that is also valid in
+    // another language derives the same seed and is not ambiguous here
+    Mnemonic::from_phrase_confirmed(phrase, language)?.to_seed(passphrase)
 }
 
 /// Generates a new random BIP39 mnemonic phrase in English.
@@ -312,6 +270,7 @@ pub fn phrase_to_seed_in_language(
 /// let mnemonic_24 = generate_mnemonic(WordCount::TwentyFour).unwrap();
 /// assert_eq!(mnemonic_24.split_whitespace().count(), 24);
 /// ```
+#[cfg(feature = "rand")]
 pub fn generate_mnemonic(word_count: WordCount) -> Result<String> {
     generate_mnemonic_in_language(word_count, Language::English)
 }
@@ -350,42 +309,88 @@ pub fn generate_mnemonic(word_count: WordCount) -> Result<String> {
 /// let mnemonic_ja = generate_mnemonic_in_language(WordCount::TwentyFour, Language::Japanese).unwrap();
 /// assert_eq!(mnemonic_ja.split_whitespace().count(), 24);
 /// ```
+#[cfg(feature = "rand")]
 pub fn generate_mnemonic_in_language(word_count: WordCount, language: Language) -> Result<String> {
-    use rand::rngs::OsRng;
-    use rand::RngCore;
+    // Uses OsRng for reliable entropy on mobile/static library targets
+    generate_mnemonic_with_source(word_count, language, &mut OsEntropy)
+}
 
+/// Generates a new BIP39 mnemonic phrase with entropy drawn from `source`.
+///
+/// This is the same as [`generate_mnemonic_in_language`], which uses
+/// [`OsEntropy`], but lets the caller choose where the entropy comes from.
+///
+/// # Arguments
+///
+/// * `word_count` - The number of words in the mnemonic (12, 15, 18, 21, or 24)
+/// * `language` - The language for the mnemonic phrase
+/// * `source` - The source of random bytes
+///
+/// # Returns
+///
+/// * `Ok(String)` - A valid BIP39 mnemonic phrase in the specified language
+/// * `Err(Error)` - If the source fails to produce entropy
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{generate_mnemonic_with_source, DeterministicEntropy, Language, WordCount};
+///
+/// let first = generate_mnemonic_with_source(WordCount::Twelve, Language::English, &mut DeterministicEntropy::new(b"x"))?;
+/// let second = generate_mnemonic_with_source(WordCount::Twelve, Language::English, &mut DeterministicEntropy::new(b"x"))?;
+/// assert_eq!(first, second);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+pub fn generate_mnemonic_with_source<S: EntropySource + ?Sized>(
+    word_count: WordCount,
+    language: Language,
+    source: &mut S,
+) -> Result<String> {
     // Step 1: Calculate the required entropy length based on word count
     let entropy_length = word_count.entropy_length();
 
-    // Step 2: Generate cryptographically secure random entropy
-    // Uses OsRng for reliable entropy on mobile/static library targets
+    // Step 2: Fill the entropy from the source
     let mut entropy = vec![0u8; entropy_length];
-    OsRng.fill_bytes(&mut entropy);
-
-    // Step 3: Convert language to upstream format
-    let upstream_language = language.to_upstream();
-
-    // Step 4: Create mnemonic from entropy using upstream crate
-    // The upstream crate handles:
-    // - Entropy validation
-    // - Checksum calculation (appends checksum bits to entropy)
-    // - Word selection from language-specific wordlist
-    // - Proper formatting with spaces between words
-    let mnemonic =
-        bip39_upstream::Mnemonic::from_entropy_in(upstream_language, &entropy).map_err(|_| {
-            Error::InvalidEntropyLength {
-                length: entropy.len(),
-            }
-        })?;
+    source.fill_entropy(&mut entropy)?;
+
+    // Step 3: Append the checksum and map each 11-bit group to a word
+    let phrase = codec::entropy_to_phrase(&entropy, &language)?;
+    Ok(phrase.to_string())
+}
 
-    // Step 5: Convert to string and return
-    // The mnemonic is formatted with spaces between words
-    Ok(mnemonic.to_string())
+/// Computes the Levenshtein edit distance between two words.
+///
+/// Distances are counted in Unicode scalar values, so accented and
+/// non-Latin words are compared character by character.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Compares two byte strings in time that depends only on their lengths.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(difference) == 0
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Error;
 
     // Known valid test vectors from BIP39 specification
     const VALID_12_WORD_PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
@@ -791,9 +796,9 @@ mod tests {
     #[test]
     fn test_language_integration() {
         // Test that all supported languages can be used (even if we don't have test phrases)
-        for &language in Language::all_variants() {
+        for language in Language::all_variants() {
             // This should not panic and should handle the language parameter correctly
-            let result = validate_phrase_in_language("invalid phrase", language);
+            let result = validate_phrase_in_language("invalid phrase", language.clone());
             assert!(
                 result.is_err(),
                 "Invalid phrase should fail in {} language",
@@ -812,7 +817,7 @@ mod tests {
         let seed = phrase_to_seed(VALID_12_WORD_PHRASE, "").unwrap();
 
         // BIP39 seeds are always 64 bytes (512 bits)
-        assert_eq!(seed.len(), 64, "Seed should be 64 bytes");
+        assert_eq!(seed.as_bytes().len(), 64, "Seed should be 64 bytes");
 
         // Known test vector from BIP39 specification
         // Mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
@@ -822,7 +827,7 @@ mod tests {
         let expected_seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
         let expected_seed = hex::decode(expected_seed_hex).unwrap();
         assert_eq!(
-            &seed[..],
+            &seed.as_bytes()[..],
             &expected_seed[..],
             "Seed should match BIP39 test vector"
         );
@@ -834,7 +839,7 @@ mod tests {
         let passphrase = "TREZOR";
         let seed = phrase_to_seed(VALID_12_WORD_PHRASE, passphrase).unwrap();
 
-        assert_eq!(seed.len(), 64, "Seed should be 64 bytes");
+        assert_eq!(seed.as_bytes().len(), 64, "Seed should be 64 bytes");
 
         // Known test vector from BIP39 specification with TREZOR passphrase
         // Mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
@@ -844,7 +849,7 @@ mod tests {
         let expected_seed_hex = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
         let expected_seed = hex::decode(expected_seed_hex).unwrap();
         assert_eq!(
-            &seed[..],
+            &seed.as_bytes()[..],
             &expected_seed[..],
             "Seed with passphrase should match BIP39 test vector"
         );
@@ -876,7 +881,7 @@ mod tests {
         // Test with 24-word phrase
         let seed = phrase_to_seed(VALID_24_WORD_PHRASE, "").unwrap();
         assert_eq!(
-            seed.len(),
+            seed.as_bytes().len(),
             64,
             "24-word phrase should also produce 64-byte seed"
         );
@@ -925,7 +930,11 @@ mod tests {
         // Test with Unicode passphrase (should be properly normalized)
         let unicode_passphrase = "test 日本語 emoji 🔑";
         let seed = phrase_to_seed(VALID_12_WORD_PHRASE, unicode_passphrase).unwrap();
-        assert_eq!(seed.len(), 64, "Unicode passphrase should work correctly");
+        assert_eq!(
+            seed.as_bytes().len(),
+            64,
+            "Unicode passphrase should work correctly"
+        );
     }
 
     #[test]
@@ -998,7 +1007,7 @@ mod tests {
             let seed = phrase_to_seed(&phrase, "").unwrap();
 
             assert_eq!(
-                seed.len(),
+                seed.as_bytes().len(),
                 64,
                 "{}-word phrase should produce 64-byte seed",
                 word_count
@@ -1010,6 +1019,7 @@ mod tests {
     // Tests for generate_mnemonic function (Task 10)
     // ============================================================================
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_12_words() {
         // Generate a 12-word mnemonic
@@ -1026,6 +1036,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_15_words() {
         // Generate a 15-word mnemonic
@@ -1039,6 +1050,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_18_words() {
         // Generate an 18-word mnemonic
@@ -1052,6 +1064,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_21_words() {
         // Generate a 21-word mnemonic
@@ -1065,6 +1078,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_24_words() {
         // Generate a 24-word mnemonic
@@ -1078,6 +1092,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_randomness() {
         // Generate multiple mnemonics and verify they're different (randomness)
@@ -1091,6 +1106,7 @@ mod tests {
         assert_ne!(mnemonic1, mnemonic3, "Generated mnemonics should be random");
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_can_generate_seed() {
         // Generate a mnemonic and verify it can be used to generate a seed
@@ -1099,12 +1115,13 @@ mod tests {
         // Should be able to generate a seed from the generated mnemonic
         let seed = phrase_to_seed(&mnemonic, "").unwrap();
         assert_eq!(
-            seed.len(),
+            seed.as_bytes().len(),
             64,
             "Generated mnemonic should produce valid seed"
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_with_passphrase() {
         // Generate a mnemonic and use it with a passphrase
@@ -1119,6 +1136,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_english_words() {
         // Verify generated mnemonic contains valid English BIP39 words
@@ -1134,6 +1152,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_in_language_english() {
         // Test explicit English language generation
@@ -1147,6 +1166,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_in_language_japanese() {
         // Test Japanese language generation
@@ -1165,6 +1185,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_all_word_counts() {
         // Test that all word counts can be generated successfully
@@ -1187,21 +1208,24 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_all_languages() {
         // Test that all languages can generate valid mnemonics
         let languages = Language::all_variants();
 
-        for &language in languages {
-            let mnemonic = generate_mnemonic_in_language(WordCount::Twelve, language).unwrap();
+        for language in languages {
+            let mnemonic =
+                generate_mnemonic_in_language(WordCount::Twelve, language.clone()).unwrap();
             assert!(
-                validate_phrase_in_language(&mnemonic, language).is_ok(),
+                validate_phrase_in_language(&mnemonic, language.clone()).is_ok(),
                 "Generated {} mnemonic should be valid",
                 language.name()
             );
         }
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_deterministic_seed() {
         // Verify that the same generated mnemonic always produces the same seed
@@ -1216,6 +1240,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mnemonic_entropy_strength() {
         // Verify different word counts have different entropy strengths
@@ -1232,4 +1257,14 @@ mod tests {
         assert!(validate_phrase(&mnemonic_12).is_ok());
         assert!(validate_phrase(&mnemonic_24).is_ok());
     }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("abandon", "abandon"), 0);
+        assert_eq!(edit_distance("abandon", "abandom"), 1);
+        assert_eq!(edit_distance("about", "abuot"), 2);
+        assert_eq!(edit_distance("zoo", ""), 3);
+        assert_eq!(edit_distance("", "zoo"), 3);
+        assert_eq!(edit_distance("ábaco", "abaco"), 1);
+    }
 }
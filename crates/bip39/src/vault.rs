@@ -0,0 +1,1113 @@
+//! Password-encrypted mnemonic vaults.
+//!
+//! A vault is a versioned binary file holding one [`Mnemonic`] encrypted
+//! with XChaCha20-Poly1305 under a key stretched from a password with
+//! Argon2id or scrypt. Next to the ciphertext, the vault stores an optional
+//! passphrase hint and free-form metadata (labels, creation dates, wallet
+//! ids). These stay readable without the password but are authenticated,
+//! so they cannot be changed without detection.
+//!
+//! # Format (version 1)
+//!
+//! All integers are big-endian.
+//!
+//! | Field          | Size              | Description                                   |
+//! |----------------|-------------------|-----------------------------------------------|
+//! | magic          | 4                 | `KPMV`                                        |
+//! | version        | 1                 | `1`                                           |
+//! | KDF            | 1 + params        | `1` Argon2id (m, t, p as u32) or `2` scrypt (log₂N as u8, r, p as u32) |
+//! | salt           | 16                | Random KDF salt                               |
+//! | nonce          | 24                | Random XChaCha20-Poly1305 nonce               |
+//! | key check      | 16                | Extra KDF output for password verification    |
+//! | hint           | 2 + n             | UTF-8 passphrase hint, empty if none          |
+//! | metadata       | 2 + entries       | Count, then length-prefixed key/value pairs   |
+//! | ciphertext     | 4 + n             | Language code and entropy, plus 16-byte tag   |
+//!
+//! The KDF produces 48 bytes: the first 32 are the encryption key and the
+//! last 16 the key check value, which lets [`Mnemonic::unseal`] tell a wrong
+//! password ([`Error::WrongPassword`]) from a modified file
+//! ([`Error::VaultTampered`]). Everything before the ciphertext is
+//! authenticated as associated data.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip39::{Kdf, Language, Mnemonic, SealedMnemonic, VaultOptions, WordCount};
+//!
+//! let mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English)?;
+//!
+//! // Mnemonic::seal uses strong Argon2id defaults; cheap parameters keep this example fast
+//! let options = VaultOptions::new()
+//!     .kdf(Kdf::Scrypt { log_n: 10, r: 8, p: 1 })
+//!     .passphrase_hint("first pet")
+//!     .metadata("label", "cold storage");
+//! let vault = mnemonic.seal_with("correct horse battery staple", &options)?;
+//!
+//! // The hint is readable before unlocking
+//! let sealed = SealedMnemonic::from_bytes(&vault)?;
+//! assert_eq!(sealed.passphrase_hint(), Some("first pet"));
+//!
+//! let unsealed = Mnemonic::unseal(&vault, "correct horse battery staple")?;
+//! assert_eq!(unsealed, mnemonic);
+//! # Ok::<(), khodpay_bip39::Error>(())
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use crate::utils::constant_time_eq;
+#[cfg(feature = "rand")]
+use crate::OsEntropy;
+use crate::{EntropySource, Error, Language, Mnemonic, Result};
+
+/// File signature at the start of every vault.
+const MAGIC: [u8; 4] = *b"KPMV";
+
+/// Current vault format version.
+const FORMAT_VERSION: u8 = 1;
+
+const KDF_ARGON2ID: u8 = 1;
+const KDF_SCRYPT: u8 = 2;
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 24;
+const KEY_LENGTH: usize = 32;
+const KEY_CHECK_LENGTH: usize = 16;
+
+/// Default upper bounds on KDF cost, so that a crafted vault file cannot
+/// make unsealing exhaust memory or run for minutes.
+const DEFAULT_MAX_MEMORY_BYTES: u64 = 1024 * 1024 * 1024;
+const DEFAULT_MAX_ARGON2_ITERATIONS: u32 = 16;
+const DEFAULT_MAX_ARGON2_PARALLELISM: u32 = 16;
+const DEFAULT_MAX_SCRYPT_R_TIMES_P: u64 = 32;
+
+/// Password-based key derivation function and cost parameters of a vault.
+///
+/// Sealing and unsealing reject costs above the [`KdfLimits`] in effect, by
+/// default 1 GiB of memory, Argon2id with more than 16 passes or lanes, and
+/// scrypt with r·p above 32.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kdf {
+    /// Argon2id (RFC 9106).
+    Argon2id {
+        /// Memory cost in KiB
+        memory_kib: u32,
+        /// Number of passes over the memory
+        iterations: u32,
+        /// Degree of parallelism (lanes)
+        parallelism: u32,
+    },
+
+    /// scrypt (RFC 7914).
+    Scrypt {
+        /// Base-2 logarithm of the CPU/memory cost N
+        log_n: u8,
+        /// Block size
+        r: u32,
+        /// Parallelization
+        p: u32,
+    },
+}
+
+impl Default for Kdf {
+    /// Argon2id with 64 MiB of memory, 3 passes and a single lane.
+    fn default() -> Self {
+        Kdf::Argon2id {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+fn invalid_kdf(e: &dyn core::fmt::Display) -> Error {
+    Error::InvalidVault {
+        reason: format!("Invalid KDF parameters: {e}"),
+    }
+}
+
+/// Upper bounds on the KDF cost that sealing and unsealing accept.
+///
+/// The KDF parameters of a vault are stored in the file, so without bounds a
+/// crafted vault could make [`Mnemonic::unseal`] allocate gigabytes or run
+/// for hours before the password is even checked. The defaults allow 1 GiB
+/// of memory, Argon2id with up to 16 passes and 16 lanes, and scrypt with
+/// r·p up to 32, comfortably above [`Kdf::default`] and the usual scrypt
+/// settings. Raise them with [`SealedMnemonic::unseal_with_limits`] and
+/// [`VaultOptions::limits`] for vaults known to use heavier parameters.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{Kdf, KdfLimits, Language, Mnemonic, VaultOptions};
+///
+/// let mnemonic = Mnemonic::new(&[7u8; 16], Language::English)?;
+/// let options = VaultOptions::new().kdf(Kdf::Scrypt { log_n: 10, r: 8, p: 1 });
+/// let vault = mnemonic.seal_with("hunter2", &options)?;
+///
+/// // Accept up to 2 GiB of memory on a machine that has it
+/// let limits = KdfLimits::new().max_memory_bytes(2 * 1024 * 1024 * 1024);
+/// assert_eq!(Mnemonic::unseal_with_limits(&vault, "hunter2", &limits)?, mnemonic);
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfLimits {
+    max_memory_bytes: u64,
+    max_argon2_iterations: u32,
+    max_argon2_parallelism: u32,
+    max_scrypt_r_times_p: u64,
+}
+
+impl Default for KdfLimits {
+    /// 1 GiB of memory, 16 Argon2id passes and lanes, and scrypt r·p of 32.
+    fn default() -> Self {
+        Self {
+            max_memory_bytes: DEFAULT_MAX_MEMORY_BYTES,
+            max_argon2_iterations: DEFAULT_MAX_ARGON2_ITERATIONS,
+            max_argon2_parallelism: DEFAULT_MAX_ARGON2_PARALLELISM,
+            max_scrypt_r_times_p: DEFAULT_MAX_SCRYPT_R_TIMES_P,
+        }
+    }
+}
+
+impl KdfLimits {
+    /// Creates the default limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the most memory either KDF may use, in bytes.
+    pub fn max_memory_bytes(mut self, bytes: u64) -> Self {
+        self.max_memory_bytes = bytes;
+        self
+    }
+
+    /// Sets the most Argon2id passes over the memory.
+    pub fn max_argon2_iterations(mut self, iterations: u32) -> Self {
+        self.max_argon2_iterations = iterations;
+        self
+    }
+
+    /// Sets the most Argon2id lanes.
+    pub fn max_argon2_parallelism(mut self, parallelism: u32) -> Self {
+        self.max_argon2_parallelism = parallelism;
+        self
+    }
+
+    /// Sets the largest scrypt r·p, which bounds its running time.
+    pub fn max_scrypt_r_times_p(mut self, r_times_p: u64) -> Self {
+        self.max_scrypt_r_times_p = r_times_p;
+        self
+    }
+}
+
+impl Kdf {
+    /// Rejects cost parameters above `limits`.
+    ///
+    /// scrypt needs 128·r·N bytes of memory and runs p mixes of r blocks each,
+    /// so both the memory and r·p are bounded.
+    fn check_cost(&self, limits: &KdfLimits) -> Result<()> {
+        let max_mib = limits.max_memory_bytes >> 20;
+        match *self {
+            Kdf::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => {
+                if u64::from(memory_kib) * 1024 > limits.max_memory_bytes {
+                    return Err(invalid_kdf(&format!(
+                        "Argon2id memory cost above {max_mib} MiB"
+                    )));
+                }
+                if iterations > limits.max_argon2_iterations {
+                    return Err(invalid_kdf(&format!(
+                        "Argon2id iterations above {}",
+                        limits.max_argon2_iterations
+                    )));
+                }
+                if parallelism > limits.max_argon2_parallelism {
+                    return Err(invalid_kdf(&format!(
+                        "Argon2id parallelism above {}",
+                        limits.max_argon2_parallelism
+                    )));
+                }
+            }
+            Kdf::Scrypt { log_n, r, p } => {
+                // 128·r·2^63 still fits in a u128
+                if log_n >= 64
+                    || (128 * u128::from(r)) << log_n > u128::from(limits.max_memory_bytes)
+                {
+                    return Err(invalid_kdf(&format!(
+                        "scrypt memory cost above {max_mib} MiB"
+                    )));
+                }
+                if u64::from(r) * u64::from(p) > limits.max_scrypt_r_times_p {
+                    return Err(invalid_kdf(&format!(
+                        "scrypt r·p above {}",
+                        limits.max_scrypt_r_times_p
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Derives the encryption key and key check value from a password.
+    fn derive(
+        &self,
+        password: &str,
+        salt: &[u8],
+        limits: &KdfLimits,
+    ) -> Result<Zeroizing<[u8; 48]>> {
+        self.check_cost(limits)?;
+        let password = Zeroizing::new(password.nfkd().collect::<String>());
+        let mut output = Zeroizing::new([0u8; KEY_LENGTH + KEY_CHECK_LENGTH]);
+
+        match *self {
+            Kdf::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => {
+                let params =
+                    argon2::Params::new(memory_kib, iterations, parallelism, Some(output.len()))
+                        .map_err(|e| invalid_kdf(&e))?;
+                argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+                    .hash_password_into(password.as_bytes(), salt, output.as_mut())
+                    .map_err(|e| invalid_kdf(&e))?;
+            }
+            Kdf::Scrypt { log_n, r, p } => {
+                let params =
+                    scrypt::Params::new(log_n, r, p, output.len()).map_err(|e| invalid_kdf(&e))?;
+                scrypt::scrypt(password.as_bytes(), salt, &params, output.as_mut())
+                    .map_err(|e| invalid_kdf(&e))?;
+            }
+        }
+        Ok(output)
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        match *self {
+            Kdf::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => {
+                out.push(KDF_ARGON2ID);
+                out.extend_from_slice(&memory_kib.to_be_bytes());
+                out.extend_from_slice(&iterations.to_be_bytes());
+                out.extend_from_slice(&parallelism.to_be_bytes());
+            }
+            Kdf::Scrypt { log_n, r, p } => {
+                out.push(KDF_SCRYPT);
+                out.push(log_n);
+                out.extend_from_slice(&r.to_be_bytes());
+                out.extend_from_slice(&p.to_be_bytes());
+            }
+        }
+    }
+
+    fn read(reader: &mut Reader<'_>) -> Result<Self> {
+        let kdf = match reader.u8()? {
+            KDF_ARGON2ID => Ok(Kdf::Argon2id {
+                memory_kib: reader.u32()?,
+                iterations: reader.u32()?,
+                parallelism: reader.u32()?,
+            }),
+            KDF_SCRYPT => Ok(Kdf::Scrypt {
+                log_n: reader.u8()?,
+                r: reader.u32()?,
+                p: reader.u32()?,
+            }),
+            id => Err(Error::InvalidVault {
+                reason: format!("Unknown KDF identifier {id}"),
+            }),
+        }?;
+        Ok(kdf)
+    }
+}
+
+/// Options for [`Mnemonic::seal_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip39::{Kdf, VaultOptions};
+///
+/// let options = VaultOptions::new()
+///     .kdf(Kdf::Scrypt { log_n: 17, r: 8, p: 1 })
+///     .passphrase_hint("the usual, reversed")
+///     .metadata("created", "2024-06-01");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VaultOptions {
+    kdf: Kdf,
+    limits: KdfLimits,
+    passphrase_hint: Option<String>,
+    metadata: Vec<(String, String)>,
+}
+
+impl VaultOptions {
+    /// Creates options with the default [`Kdf`] and [`KdfLimits`], no hint and
+    /// no metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the key derivation function and its cost parameters.
+    pub fn kdf(mut self, kdf: Kdf) -> Self {
+        self.kdf = kdf;
+        self
+    }
+
+    /// Sets the cost limits the KDF parameters are checked against.
+    ///
+    /// Vaults sealed above the default [`KdfLimits`] can only be opened with
+    /// [`SealedMnemonic::unseal_with_limits`].
+    pub fn limits(mut self, limits: KdfLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets a hint for the BIP39 passphrase used with the mnemonic.
+    ///
+    /// The hint is stored unencrypted; never store the passphrase itself.
+    pub fn passphrase_hint(mut self, hint: impl Into<String>) -> Self {
+        self.passphrase_hint = Some(hint.into());
+        self
+    }
+
+    /// Adds a metadata entry, stored unencrypted but authenticated.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), value.into()));
+        self
+    }
+}
+
+/// A parsed vault whose header can be inspected before unsealing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealedMnemonic {
+    kdf: Kdf,
+    salt: [u8; SALT_LENGTH],
+    nonce: [u8; NONCE_LENGTH],
+    key_check: [u8; KEY_CHECK_LENGTH],
+    passphrase_hint: Option<String>,
+    metadata: Vec<(String, String)>,
+    ciphertext: Vec<u8>,
+}
+
+impl SealedMnemonic {
+    /// Parses a vault file without decrypting it.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidVault`] - If the data is not a well-formed vault of a
+    ///   supported version
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader { data };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(Error::InvalidVault {
+                reason: "Missing vault signature".to_string(),
+            });
+        }
+        let version = reader.u8()?;
+        if version != FORMAT_VERSION {
+            return Err(Error::InvalidVault {
+                reason: format!("Unsupported vault version {version}"),
+            });
+        }
+
+        let kdf = Kdf::read(&mut reader)?;
+        let salt = reader.array()?;
+        let nonce = reader.array()?;
+        let key_check = reader.array()?;
+        let hint = reader.string()?;
+        let passphrase_hint = (!hint.is_empty()).then_some(hint);
+        let metadata = (0..reader.u16()?)
+            .map(|_| Ok((reader.string()?, reader.string()?)))
+            .collect::<Result<Vec<_>>>()?;
+        let length = reader.u32()? as usize;
+        let ciphertext = reader.take(length)?.to_vec();
+        if !reader.data.is_empty() {
+            return Err(Error::InvalidVault {
+                reason: format!(
+                    "{} unexpected bytes after the ciphertext",
+                    reader.data.len()
+                ),
+            });
+        }
+
+        Ok(Self {
+            kdf,
+            salt,
+            nonce,
+            key_check,
+            passphrase_hint,
+            metadata,
+            ciphertext,
+        })
+    }
+
+    /// Serializes the vault to its file format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = self.header();
+        out.extend_from_slice(&(self.ciphertext.len() as u32).to_be_bytes());
+        out.extend_from_slice(&self.ciphertext);
+        out
+    }
+
+    /// Returns the key derivation function and its parameters.
+    pub fn kdf(&self) -> Kdf {
+        self.kdf
+    }
+
+    /// Returns the passphrase hint, if one was stored.
+    pub fn passphrase_hint(&self) -> Option<&str> {
+        self.passphrase_hint.as_deref()
+    }
+
+    /// Returns all metadata entries in insertion order.
+    pub fn metadata(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+
+    /// Returns the value of the first metadata entry with the given key.
+    pub fn metadata_value(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Decrypts the mnemonic with `password`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::WrongPassword`] - If the password does not match (or the
+    ///   KDF parameters, salt or key check value were altered)
+    /// * [`Error::VaultTampered`] - If the password matches but the header or
+    ///   ciphertext fails authentication
+    /// * [`Error::InvalidVault`] - If the KDF parameters are unusable or their
+    ///   cost exceeds the default [`KdfLimits`]
+    pub fn unseal(&self, password: &str) -> Result<Mnemonic> {
+        self.unseal_with_limits(password, &KdfLimits::default())
+    }
+
+    /// Decrypts the mnemonic with `password`, accepting KDF costs up to `limits`.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The vault password, NFKD-normalized before key derivation
+    /// * `limits` - The largest KDF cost to run; check [`kdf`](Self::kdf)
+    ///   before raising them for an untrusted file
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::WrongPassword`] - If the password does not match (or the
+    ///   KDF parameters, salt or key check value were altered)
+    /// * [`Error::VaultTampered`] - If the password matches but the header or
+    ///   ciphertext fails authentication
+    /// * [`Error::InvalidVault`] - If the KDF parameters are unusable or their
+    ///   cost exceeds `limits`
+    pub fn unseal_with_limits(&self, password: &str, limits: &KdfLimits) -> Result<Mnemonic> {
+        let derived = self.kdf.derive(password, &self.salt, limits)?;
+        let (key, key_check) = derived.split_at(KEY_LENGTH);
+        if !constant_time_eq(key_check, &self.key_check) {
+            return Err(Error::WrongPassword);
+        }
+
+        let cipher = XChaCha20Poly1305::new_from_slice(key).expect("key length is 32 bytes");
+        let plaintext = cipher
+            .decrypt(
+                XNonce::from_slice(&self.nonce),
+                Payload {
+                    msg: &self.ciphertext,
+                    aad: &self.header(),
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| Error::VaultTampered)?;
+
+        let (&code, entropy) = plaintext.split_first().ok_or(Error::VaultTampered)?;
+        let language = language_from_code(code).ok_or_else(|| Error::InvalidVault {
+            reason: format!("Unknown language code {code}"),
+        })?;
+        Mnemonic::new(entropy, language)
+    }
+
+    /// Serializes everything before the ciphertext length, i.e. the associated data.
+    fn header(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(128);
+        out.extend_from_slice(&MAGIC);
+        out.push(FORMAT_VERSION);
+        self.kdf.write(&mut out);
+        out.extend_from_slice(&self.salt);
+        out.extend_from_slice(&self.nonce);
+        out.extend_from_slice(&self.key_check);
+        write_string(&mut out, self.passphrase_hint.as_deref().unwrap_or(""));
+        out.extend_from_slice(&(self.metadata.len() as u16).to_be_bytes());
+        for (key, value) in &self.metadata {
+            write_string(&mut out, key);
+            write_string(&mut out, value);
+        }
+        out
+    }
+}
+
+impl Mnemonic {
+    /// Encrypts this mnemonic into a vault file with the default options.
+    ///
+    /// Uses Argon2id with 64 MiB of memory (see [`Kdf::default`]), which
+    /// takes a noticeable fraction of a second by design.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::RandomGeneration`] - If the OS random number generator fails
+    #[cfg(feature = "rand")]
+    pub fn seal(&self, password: &str) -> Result<Vec<u8>> {
+        self.seal_with(password, &VaultOptions::new())
+    }
+
+    /// Encrypts this mnemonic into a vault file.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The vault password, NFKD-normalized before key derivation
+    /// * `options` - KDF parameters and limits, passphrase hint and metadata
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidVault`] - If the KDF parameters are invalid or above
+    ///   the options' [`KdfLimits`], the hint or metadata exceed the format's
+    ///   65535-byte field limit, or the mnemonic uses a [`Language::Custom`]
+    ///   wordlist
+    /// * [`Error::RandomGeneration`] - If the OS random number generator fails
+    #[cfg(feature = "rand")]
+    pub fn seal_with(&self, password: &str, options: &VaultOptions) -> Result<Vec<u8>> {
+        self.seal_with_source(password, options, &mut OsEntropy)
+    }
+
+    /// Encrypts this mnemonic into a vault file, drawing the salt and nonce
+    /// from `source`.
+    ///
+    /// This is the same as [`seal_with`](Self::seal_with), which uses
+    /// [`OsEntropy`](crate::OsEntropy), for targets that provide their own
+    /// random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The vault password, NFKD-normalized before key derivation
+    /// * `options` - KDF parameters and limits, passphrase hint and metadata
+    /// * `source` - The source of the random salt and nonce
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidVault`] - If the KDF parameters are invalid or above
+    ///   the options' [`KdfLimits`], the hint or metadata exceed the format's
+    ///   65535-byte field limit, or the mnemonic uses a [`Language::Custom`]
+    ///   wordlist
+    /// * [`Error::RandomGeneration`] - If the source fails to produce bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{DeterministicEntropy, Kdf, Language, Mnemonic, VaultOptions};
+    ///
+    /// let mnemonic = Mnemonic::new(&[1u8; 16], Language::English)?;
+    /// let options = VaultOptions::new().kdf(Kdf::Scrypt { log_n: 10, r: 8, p: 1 });
+    /// let mut source = DeterministicEntropy::new(b"device rng");
+    /// let vault = mnemonic.seal_with_source("hunter2", &options, &mut source)?;
+    /// assert_eq!(Mnemonic::unseal(&vault, "hunter2")?, mnemonic);
+    /// # Ok::<(), khodpay_bip39::Error>(())
+    /// ```
+    pub fn seal_with_source<S: EntropySource + ?Sized>(
+        &self,
+        password: &str,
+        options: &VaultOptions,
+        source: &mut S,
+    ) -> Result<Vec<u8>> {
+        let language_code = language_code(&self.language()).ok_or_else(|| Error::InvalidVault {
+            reason: "Mnemonics with a custom wordlist cannot be sealed".to_string(),
+        })?;
+        let mut fields = options
+            .passphrase_hint
+            .iter()
+            .chain(options.metadata.iter().flat_map(|(k, v)| [k, v]));
+        if options.metadata.len() > u16::MAX as usize || fields.any(|s| s.len() > u16::MAX as usize)
+        {
+            return Err(Error::InvalidVault {
+                reason: "Hint and metadata fields are limited to 65535 bytes".to_string(),
+            });
+        }
+
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        source.fill_entropy(&mut salt)?;
+        source.fill_entropy(&mut nonce)?;
+
+        let derived = options.kdf.derive(password, &salt, &options.limits)?;
+        let (key, key_check) = derived.split_at(KEY_LENGTH);
+        let mut sealed = SealedMnemonic {
+            kdf: options.kdf,
+            salt,
+            nonce,
+            key_check: key_check.try_into().expect("key check is 16 bytes"),
+            passphrase_hint: options.passphrase_hint.clone().filter(|h| !h.is_empty()),
+            metadata: options.metadata.clone(),
+            ciphertext: Vec::new(),
+        };
+
+        let mut plaintext = Zeroizing::new(Vec::with_capacity(1 + self.entropy().len()));
+        plaintext.push(language_code);
+        plaintext.extend_from_slice(self.entropy());
+
+        let cipher = XChaCha20Poly1305::new_from_slice(key).expect("key length is 32 bytes");
+        sealed.ciphertext = cipher
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &sealed.header(),
+                },
+            )
+            .expect("encryption of a short message cannot fail");
+        Ok(sealed.to_bytes())
+    }
+
+    /// Decrypts a vault file produced by [`Mnemonic::seal`] or [`Mnemonic::seal_with`].
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidVault`] - If the data is not a well-formed vault or
+    ///   its KDF cost exceeds the default [`KdfLimits`]
+    /// * [`Error::WrongPassword`] - If the password does not match
+    /// * [`Error::VaultTampered`] - If the vault was modified after sealing
+    pub fn unseal(vault: &[u8], password: &str) -> Result<Mnemonic> {
+        SealedMnemonic::from_bytes(vault)?.unseal(password)
+    }
+
+    /// Decrypts a vault file, accepting KDF costs up to `limits`.
+    ///
+    /// See [`SealedMnemonic::unseal_with_limits`].
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidVault`] - If the data is not a well-formed vault or
+    ///   its KDF cost exceeds `limits`
+    /// * [`Error::WrongPassword`] - If the password does not match
+    /// * [`Error::VaultTampered`] - If the vault was modified after sealing
+    pub fn unseal_with_limits(
+        vault: &[u8],
+        password: &str,
+        limits: &KdfLimits,
+    ) -> Result<Mnemonic> {
+        SealedMnemonic::from_bytes(vault)?.unseal_with_limits(password, limits)
+    }
+}
+
+/// Returns the stable on-disk code of a language.
+fn language_code(language: &Language) -> Option<u8> {
+    match language {
+        Language::English => Some(0),
+        Language::Japanese => Some(1),
+        Language::Korean => Some(2),
+        Language::French => Some(3),
+        Language::Italian => Some(4),
+        Language::Spanish => Some(5),
+        Language::SimplifiedChinese => Some(6),
+        Language::TraditionalChinese => Some(7),
+        Language::Czech => Some(8),
+        Language::Portuguese => Some(9),
+        Language::Custom(_) => None,
+    }
+}
+
+/// Inverse of [`language_code`].
+fn language_from_code(code: u8) -> Option<Language> {
+    Language::all_variants()
+        .iter()
+        .find(|language| language_code(language) == Some(code))
+        .cloned()
+}
+
+/// Writes a string with a 16-bit length prefix.
+fn write_string(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+/// A cursor over vault bytes that reports truncation as [`Error::InvalidVault`].
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        if self.data.len() < length {
+            return Err(Error::InvalidVault {
+                reason: "Vault data is truncated".to_string(),
+            });
+        }
+        let (head, tail) = self.data.split_at(length);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().expect("length checked by take"))
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let length = self.u16()? as usize;
+        String::from_utf8(self.take(length)?.to_vec()).map_err(|_| Error::InvalidVault {
+            reason: "Vault text field is not valid UTF-8".to_string(),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use super::*;
+    use alloc::sync::Arc;
+
+    use crate::{WordCount, Wordlist};
+
+    const PASSWORD: &str = "correct horse battery staple";
+
+    /// Cheap parameters so the tests run quickly.
+    fn fast_options() -> VaultOptions {
+        VaultOptions::new().kdf(Kdf::Scrypt {
+            log_n: 8,
+            r: 8,
+            p: 1,
+        })
+    }
+
+    fn mnemonic() -> Mnemonic {
+        Mnemonic::from_phrase(
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            Language::English,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_all_languages() {
+        for language in Language::all_variants() {
+            let mnemonic = Mnemonic::generate(WordCount::TwentyFour, language.clone()).unwrap();
+            let vault = mnemonic.seal_with(PASSWORD, &fast_options()).unwrap();
+            let unsealed = Mnemonic::unseal(&vault, PASSWORD).unwrap();
+            assert_eq!(unsealed, mnemonic);
+            assert_eq!(&unsealed.language(), language);
+        }
+    }
+
+    #[test]
+    fn test_argon2id_round_trip() {
+        let options = VaultOptions::new().kdf(Kdf::Argon2id {
+            memory_kib: 256,
+            iterations: 1,
+            parallelism: 1,
+        });
+        let vault = mnemonic().seal_with(PASSWORD, &options).unwrap();
+        assert_eq!(Mnemonic::unseal(&vault, PASSWORD).unwrap(), mnemonic());
+        assert_eq!(
+            SealedMnemonic::from_bytes(&vault).unwrap().kdf(),
+            Kdf::Argon2id {
+                memory_kib: 256,
+                iterations: 1,
+                parallelism: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_hint_and_metadata() {
+        let options = fast_options()
+            .passphrase_hint("grandmother's street")
+            .metadata("label", "savings")
+            .metadata("created", "2024-06-01");
+        let vault = mnemonic().seal_with(PASSWORD, &options).unwrap();
+
+        let sealed = SealedMnemonic::from_bytes(&vault).unwrap();
+        assert_eq!(sealed.passphrase_hint(), Some("grandmother's street"));
+        assert_eq!(sealed.metadata().len(), 2);
+        assert_eq!(sealed.metadata_value("label"), Some("savings"));
+        assert_eq!(sealed.metadata_value("missing"), None);
+        assert_eq!(sealed.to_bytes(), vault);
+
+        let plain = mnemonic().seal_with(PASSWORD, &fast_options()).unwrap();
+        assert_eq!(
+            SealedMnemonic::from_bytes(&plain)
+                .unwrap()
+                .passphrase_hint(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_wrong_password() {
+        let vault = mnemonic().seal_with(PASSWORD, &fast_options()).unwrap();
+        assert_eq!(
+            Mnemonic::unseal(&vault, "Correct horse battery staple").unwrap_err(),
+            Error::WrongPassword
+        );
+    }
+
+    #[test]
+    fn test_password_is_normalized() {
+        // "é" precomposed when sealing, decomposed when unsealing
+        let vault = mnemonic().seal_with("caf\u{e9}", &fast_options()).unwrap();
+        assert_eq!(Mnemonic::unseal(&vault, "cafe\u{301}").unwrap(), mnemonic());
+    }
+
+    #[test]
+    fn test_tampering_is_detected() {
+        let options = fast_options().passphrase_hint("hint");
+        let vault = mnemonic().seal_with(PASSWORD, &options).unwrap();
+
+        // Flip a ciphertext bit
+        let mut modified = vault.clone();
+        *modified.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            Mnemonic::unseal(&modified, PASSWORD).unwrap_err(),
+            Error::VaultTampered
+        );
+
+        // Change the authenticated hint
+        let position = vault.windows(4).position(|w| w == b"hint").unwrap();
+        let mut modified = vault.clone();
+        modified[position] = b'H';
+        assert_eq!(
+            Mnemonic::unseal(&modified, PASSWORD).unwrap_err(),
+            Error::VaultTampered
+        );
+
+        // Corrupt the nonce
+        let mut modified = vault;
+        modified[MAGIC.len() + 1 + 10 + SALT_LENGTH] ^= 0xFF;
+        assert_eq!(
+            Mnemonic::unseal(&modified, PASSWORD).unwrap_err(),
+            Error::VaultTampered
+        );
+    }
+
+    #[test]
+    fn test_malformed_vaults() {
+        let vault = mnemonic().seal_with(PASSWORD, &fast_options()).unwrap();
+        let invalid = |data: &[u8]| {
+            matches!(
+                SealedMnemonic::from_bytes(data),
+                Err(Error::InvalidVault { .. })
+            )
+        };
+
+        assert!(invalid(b""));
+        assert!(invalid(b"NOPE"));
+        assert!(invalid(&vault[..vault.len() - 1]));
+        assert!(invalid(&[&vault[..], &[0]].concat()));
+
+        let mut future = vault.clone();
+        future[MAGIC.len()] = 2;
+        assert!(invalid(&future));
+
+        let mut unknown_kdf = vault;
+        unknown_kdf[MAGIC.len() + 1] = 9;
+        assert!(invalid(&unknown_kdf));
+    }
+
+    #[test]
+    fn test_excessive_kdf_cost_is_rejected() {
+        let options = VaultOptions::new().kdf(Kdf::Scrypt {
+            log_n: 30,
+            r: 8,
+            p: 1,
+        });
+        assert!(matches!(
+            mnemonic().seal_with(PASSWORD, &options),
+            Err(Error::InvalidVault { .. })
+        ));
+    }
+
+    #[test]
+    fn test_crafted_kdf_cost_is_rejected() {
+        let vault = mnemonic().seal_with(PASSWORD, &fast_options()).unwrap();
+        let kdf_params = MAGIC.len() + 2;
+        // The header stays readable, but unsealing refuses to run the KDF
+        let rejected = |data: &[u8]| {
+            SealedMnemonic::from_bytes(data).is_ok()
+                && matches!(
+                    Mnemonic::unseal(data, PASSWORD),
+                    Err(Error::InvalidVault { .. })
+                )
+        };
+
+        // scrypt with 128·r·N far above the memory cap
+        let mut huge_r = vault.clone();
+        huge_r[kdf_params] = 22;
+        huge_r[kdf_params + 1..kdf_params + 5].copy_from_slice(&0x00ff_ffffu32.to_be_bytes());
+        assert!(rejected(&huge_r));
+
+        // scrypt with a cost exponent that would overflow the memory estimate
+        let mut huge_n = vault.clone();
+        huge_n[kdf_params] = u8::MAX;
+        assert!(rejected(&huge_n));
+
+        // scrypt with a cheap N but billions of mixes
+        let mut huge_p = vault.clone();
+        huge_p[kdf_params + 5..kdf_params + 9].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(rejected(&huge_p));
+
+        // Argon2id with a modest memory cost and an absurd pass count
+        let mut argon2 = vault[..MAGIC.len() + 1].to_vec();
+        Kdf::Argon2id {
+            memory_kib: 8,
+            iterations: u32::MAX,
+            parallelism: 1,
+        }
+        .write(&mut argon2);
+        argon2.extend_from_slice(&vault[kdf_params + 9..]);
+        assert!(rejected(&argon2));
+
+        let mut lanes = vault[..MAGIC.len() + 1].to_vec();
+        Kdf::Argon2id {
+            memory_kib: 64 * 1024,
+            iterations: 1,
+            parallelism: u32::MAX >> 8,
+        }
+        .write(&mut lanes);
+        lanes.extend_from_slice(&vault[kdf_params + 9..]);
+        assert!(rejected(&lanes));
+
+        // The caps do not affect vaults sealed with sensible parameters
+        assert_eq!(Mnemonic::unseal(&vault, PASSWORD).unwrap(), mnemonic());
+    }
+
+    #[test]
+    fn test_default_limits() {
+        let limits = KdfLimits::default();
+        let within = [
+            Kdf::default(),
+            Kdf::Argon2id {
+                memory_kib: 1024 * 1024,
+                iterations: 16,
+                parallelism: 16,
+            },
+            Kdf::Scrypt {
+                log_n: 20,
+                r: 8,
+                p: 4,
+            },
+        ];
+        for kdf in within {
+            assert!(kdf.check_cost(&limits).is_ok(), "{kdf:?}");
+        }
+
+        let above = [
+            Kdf::Argon2id {
+                memory_kib: 1024 * 1024 + 1,
+                iterations: 3,
+                parallelism: 1,
+            },
+            Kdf::Argon2id {
+                memory_kib: 64 * 1024,
+                iterations: 17,
+                parallelism: 1,
+            },
+            Kdf::Argon2id {
+                memory_kib: 64 * 1024,
+                iterations: 3,
+                parallelism: 17,
+            },
+            Kdf::Scrypt {
+                log_n: 21,
+                r: 8,
+                p: 1,
+            },
+            Kdf::Scrypt {
+                log_n: 14,
+                r: 8,
+                p: 5,
+            },
+        ];
+        for kdf in above {
+            assert!(kdf.check_cost(&limits).is_err(), "{kdf:?}");
+        }
+    }
+
+    #[test]
+    fn test_unseal_with_limits() {
+        let tight = KdfLimits::new().max_memory_bytes(64 * 1024);
+        let vault = mnemonic().seal_with(PASSWORD, &fast_options()).unwrap();
+
+        // 128·8·2^8 bytes is 256 KiB, above the tighter limit
+        assert!(matches!(
+            Mnemonic::unseal_with_limits(&vault, PASSWORD, &tight),
+            Err(Error::InvalidVault { .. })
+        ));
+        assert!(matches!(
+            mnemonic().seal_with(PASSWORD, &fast_options().limits(tight)),
+            Err(Error::InvalidVault { .. })
+        ));
+
+        // A vault sealed above the defaults opens only with raised limits
+        let heavy = Kdf::Scrypt {
+            log_n: 8,
+            r: 64,
+            p: 1,
+        };
+        let raised = KdfLimits::new().max_scrypt_r_times_p(64);
+        assert!(matches!(
+            mnemonic().seal_with(PASSWORD, &VaultOptions::new().kdf(heavy)),
+            Err(Error::InvalidVault { .. })
+        ));
+        let options = VaultOptions::new().kdf(heavy).limits(raised);
+        let vault = mnemonic().seal_with(PASSWORD, &options).unwrap();
+        assert!(matches!(
+            Mnemonic::unseal(&vault, PASSWORD),
+            Err(Error::InvalidVault { .. })
+        ));
+        assert_eq!(
+            Mnemonic::unseal_with_limits(&vault, PASSWORD, &raised).unwrap(),
+            mnemonic()
+        );
+    }
+
+    #[test]
+    fn test_vaults_are_randomized() {
+        let first = mnemonic().seal_with(PASSWORD, &fast_options()).unwrap();
+        let second = mnemonic().seal_with(PASSWORD, &fast_options()).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_custom_wordlist_is_rejected() {
+        let wordlist = Wordlist::new("Copy", Language::English.word_list()).unwrap();
+        let language = Language::Custom(Arc::new(wordlist));
+        let mnemonic = Mnemonic::new(&[0u8; 16], language).unwrap();
+        assert_eq!(
+            mnemonic.seal_with(PASSWORD, &fast_options()).unwrap_err(),
+            Error::InvalidVault {
+                reason: "Mnemonics with a custom wordlist cannot be sealed".to_string()
+            }
+        );
+    }
+}
@@ -154,6 +154,23 @@ impl WordCount {
         }
     }
 
+    /// Returns the number of entropy bits carried by the final word.
+    ///
+    /// The final word holds the last entropy bits followed by the checksum,
+    /// so this is how many bits (coin flips) pick it among the valid final
+    /// words; see [`Mnemonic::final_words`](crate::Mnemonic::final_words).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use khodpay_bip39::WordCount;
+    /// assert_eq!(WordCount::Twelve.final_word_entropy_bits(), 7);
+    /// assert_eq!(WordCount::TwentyFour.final_word_entropy_bits(), 3);
+    /// ```
+    pub const fn final_word_entropy_bits(&self) -> usize {
+        11 - self.checksum_bits()
+    }
+
     /// Creates a `WordCount` from the number of words.
     ///
     /// # Errors
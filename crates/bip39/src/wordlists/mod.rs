@@ -0,0 +1,289 @@
+//! The BIP39 wordlists and user-supplied [`Wordlist`]s.
+//!
+//! Each language submodule holds one 2048-word list from the BIP39
+//! repository as a `WORDS` static; a word's position in the list is its
+//! 11-bit index. Words are stored in NFKD form, as published.
+//!
+//! [`Wordlist`] wraps any other 2048-word list after checking it against the
+//! same rules the official lists follow, so it can be used through
+//! [`Language::Custom`](crate::Language::Custom).
+
+pub(crate) mod chinese_simplified;
+pub(crate) mod chinese_traditional;
+pub(crate) mod czech;
+pub(crate) mod english;
+pub(crate) mod french;
+pub(crate) mod italian;
+pub(crate) mod japanese;
+pub(crate) mod korean;
+pub(crate) mod portuguese;
+pub(crate) mod spanish;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{Error, Language, Result};
+
+/// A validated, user-supplied BIP39 wordlist.
+///
+/// Use it through [`Language::Custom`] to create and parse mnemonics whose
+/// words come from a list other than the built-in ones. [`Wordlist::new`]
+/// enforces the rules of the official lists:
+///
+/// - exactly 2048 words
+/// - no duplicates
+/// - every word is non-empty, lowercase, free of whitespace and in Unicode
+///   NFKD form
+/// - the first [`UNIQUE_PREFIX_LENGTH`](Language::UNIQUE_PREFIX_LENGTH)
+///   letters identify each word, ignoring accents
+///
+/// [`Language::Custom`] shares the list through an [`Arc`](alloc::sync::Arc).
+/// The words are `&'static str`, as returned by `include_str!` for a word
+/// file compiled into the wallet.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use khodpay_bip39::{Language, Mnemonic, Wordlist};
+///
+/// // Usually `Wordlist::from_text("Regional", include_str!("regional.txt"))`
+/// let wordlist = Wordlist::new("Regional", Language::English.word_list())?;
+/// let language = Language::Custom(Arc::new(wordlist));
+///
+/// let mnemonic = Mnemonic::new(&[0u8; 16], language.clone())?;
+/// assert_eq!(language.name(), "Regional");
+/// assert!(mnemonic.phrase().ends_with("about"));
+/// # Ok::<(), khodpay_bip39::Error>(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Wordlist {
+    name: &'static str,
+    words: Box<[&'static str; Wordlist::SIZE]>,
+}
+
+impl Wordlist {
+    /// The number of words in a BIP39 wordlist.
+    pub const SIZE: usize = 2048;
+
+    /// Validates `words` and creates a wordlist named `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A display name, returned by [`Language::name`]
+    /// * `words` - The 2048 words in index order
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidWordlist`] - If the words break any of the rules
+    ///   listed on [`Wordlist`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip39::{Error, Wordlist};
+    ///
+    /// let result = Wordlist::new("Short", &["abandon", "ability"]);
+    /// assert!(matches!(result, Err(Error::InvalidWordlist { .. })));
+    /// ```
+    pub fn new(name: &'static str, words: &[&'static str]) -> Result<Self> {
+        let words: Box<[&'static str; Self::SIZE]> = words
+            .to_vec()
+            .into_boxed_slice()
+            .try_into()
+            .map_err(|words: Box<[&str]>| {
+            invalid_wordlist(format!(
+                "Expected {} words, found {}",
+                Self::SIZE,
+                words.len()
+            ))
+        })?;
+
+        let mut prefixes: BTreeMap<String, &str> = BTreeMap::new();
+        for (index, &word) in words.iter().enumerate() {
+            if word.is_empty() {
+                return Err(invalid_wordlist(format!("Word {index} is empty")));
+            }
+            if word.chars().any(char::is_whitespace) {
+                return Err(invalid_wordlist(format!(
+                    "Word '{word}' contains whitespace"
+                )));
+            }
+            if word.to_lowercase() != word {
+                return Err(invalid_wordlist(format!("Word '{word}' is not lowercase")));
+            }
+            if !word.nfkd().eq(word.chars()) {
+                return Err(invalid_wordlist(format!(
+                    "Word '{word}' is not in NFKD form"
+                )));
+            }
+
+            // Compare letters without accents, recomposing scripts such as
+            // Hangul that NFKD splits into several characters per letter.
+            let prefix: String = word
+                .chars()
+                .filter(|&c| !is_combining_mark(c))
+                .nfc()
+                .take(Language::UNIQUE_PREFIX_LENGTH)
+                .collect();
+            if let Some(other) = prefixes.insert(prefix.clone(), word) {
+                return Err(invalid_wordlist(if other == word {
+                    format!("Duplicate word '{word}'")
+                } else {
+                    format!("Words '{other}' and '{word}' share the prefix '{prefix}'")
+                }));
+            }
+        }
+
+        Ok(Self { name, words })
+    }
+
+    /// Validates a wordlist given as text with one word per line.
+    ///
+    /// Surrounding whitespace and blank lines are ignored, so the word files
+    /// from the BIP39 repository can be used as they are, typically embedded
+    /// with `include_str!`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidWordlist`] - If the words break any of the rules
+    ///   listed on [`Wordlist`]
+    pub fn from_text(name: &'static str, text: &'static str) -> Result<Self> {
+        let words: Vec<&'static str> = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        Self::new(name, &words)
+    }
+
+    /// Returns the name given when the wordlist was created.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the words in index order.
+    pub fn words(&self) -> &[&'static str; Self::SIZE] {
+        &self.words
+    }
+}
+
+/// Shows the name only; the 2048 words are omitted.
+impl fmt::Debug for Wordlist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wordlist")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builds an [`Error::InvalidWordlist`] with the given reason.
+fn invalid_wordlist(reason: String) -> Error {
+    Error::InvalidWordlist { reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::sync::Arc;
+
+    use crate::Mnemonic;
+
+    /// The English list with the word at `index` replaced.
+    fn english_with(index: usize, word: &'static str) -> Vec<&'static str> {
+        let mut words = english::WORDS.to_vec();
+        words[index] = word;
+        words
+    }
+
+    #[test]
+    fn test_builtin_lists_follow_the_rules() {
+        for language in Language::all_variants() {
+            let wordlist = Wordlist::new(language.name(), language.word_list()).unwrap();
+            assert_eq!(wordlist.words(), language.word_list());
+        }
+    }
+
+    #[test]
+    fn test_wrong_word_count() {
+        assert_eq!(
+            Wordlist::new("Short", &english::WORDS[..2047]),
+            Err(Error::InvalidWordlist {
+                reason: "Expected 2048 words, found 2047".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_duplicate_word() {
+        assert_eq!(
+            Wordlist::new("Duplicate", &english_with(1, "abandon")),
+            Err(Error::InvalidWordlist {
+                reason: "Duplicate word 'abandon'".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_shared_prefix() {
+        assert_eq!(
+            Wordlist::new("Prefix", &english_with(2047, "abandoned")),
+            Err(Error::InvalidWordlist {
+                reason: "Words 'abandon' and 'abandoned' share the prefix 'aban'".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_word_form() {
+        let cases = [
+            ("", "Word 2047 is empty"),
+            ("zoo two", "Word 'zoo two' contains whitespace"),
+            ("Zoo", "Word 'Zoo' is not lowercase"),
+            ("zo\u{f6}", "Word 'zo\u{f6}' is not in NFKD form"),
+        ];
+        for (word, reason) in cases {
+            assert_eq!(
+                Wordlist::new("Form", &english_with(2047, word)),
+                Err(Error::InvalidWordlist {
+                    reason: reason.to_string()
+                })
+            );
+        }
+        // The decomposed form of the same word is accepted.
+        assert!(Wordlist::new("Form", &english_with(2047, "zo\u{6f}\u{308}")).is_ok());
+    }
+
+    #[test]
+    fn test_from_text() {
+        let text = "\n  abandon  \n\n".to_string() + &english::WORDS[1..].join("\r\n") + "\n";
+        let wordlist = Wordlist::from_text("Text", Box::leak(text.into_boxed_str())).unwrap();
+        assert_eq!(wordlist.words(), &english::WORDS);
+        assert_eq!(wordlist.name(), "Text");
+    }
+
+    #[test]
+    fn test_custom_language() {
+        let wordlist = Arc::new(Wordlist::new("Copy", &english::WORDS).unwrap());
+        let language = Language::Custom(Arc::clone(&wordlist));
+
+        let english = Mnemonic::new(&[0x7f; 32], Language::English).unwrap();
+        let custom = Mnemonic::new(&[0x7f; 32], language.clone()).unwrap();
+        assert_eq!(custom.phrase(), english.phrase());
+        assert_eq!(custom.language(), language);
+        assert_eq!(
+            Mnemonic::from_phrase(english.phrase(), language.clone()).unwrap(),
+            custom
+        );
+        assert!(crate::validate_phrase_in_language(english.phrase(), language).is_ok());
+        assert_eq!(format!("{wordlist:?}"), "Wordlist { name: \"Copy\", .. }");
+    }
+}
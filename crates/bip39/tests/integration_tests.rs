@@ -3,8 +3,9 @@
 //! These tests verify the complete workflow from mnemonic generation to seed derivation,
 //! ensuring all components work together correctly.
 
-use khodpay_bip39::{Error, Language, Mnemonic, WordCount};
+use khodpay_bip39::{Language, Mnemonic, WordCount};
 
+#[cfg(feature = "rand")]
 #[test]
 fn test_complete_workflow_new_wallet() {
     // Complete workflow: Generate → Validate → Seed → Recover
@@ -18,7 +19,7 @@ fn test_complete_workflow_new_wallet() {
 
     // 3. Generate seed with passphrase
     let seed = mnemonic.to_seed("my secure passphrase").unwrap();
-    assert_eq!(seed.len(), 64);
+    assert_eq!(seed.as_bytes().len(), 64);
 
     // 4. Simulate recovery: parse the phrase back
     let recovered = Mnemonic::from_phrase(phrase, Language::English).unwrap();
@@ -56,6 +57,7 @@ fn test_complete_workflow_from_entropy() {
     assert_eq!(seed1, seed2);
 }
 
+#[cfg(feature = "rand")]
 #[test]
 fn test_multi_language_workflow() {
     // Test that different languages work end-to-end
@@ -69,11 +71,11 @@ fn test_multi_language_workflow() {
 
     for language in languages {
         // Generate mnemonic in specific language
-        let mnemonic = Mnemonic::generate(WordCount::Twelve, language).unwrap();
+        let mnemonic = Mnemonic::generate(WordCount::Twelve, language.clone()).unwrap();
 
         // Create seed
         let seed = mnemonic.to_seed("test").unwrap();
-        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.as_bytes().len(), 64);
 
         // Recover from phrase
         let recovered = Mnemonic::from_phrase(mnemonic.phrase(), language).unwrap();
@@ -84,6 +86,7 @@ fn test_multi_language_workflow() {
     }
 }
 
+#[cfg(feature = "rand")]
 #[test]
 fn test_passphrase_variations() {
     // Test that different passphrases produce different seeds
@@ -102,6 +105,7 @@ fn test_passphrase_variations() {
     assert_eq!(seed_password, mnemonic.to_seed("password").unwrap());
 }
 
+#[cfg(feature = "rand")]
 #[test]
 fn test_all_word_counts_integration() {
     // Test complete workflow for all word counts
@@ -120,7 +124,7 @@ fn test_all_word_counts_integration() {
 
         // Create seed
         let seed = mnemonic.to_seed("").unwrap();
-        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.as_bytes().len(), 64);
 
         // Recover
         let recovered = Mnemonic::from_phrase(mnemonic.phrase(), Language::English).unwrap();
@@ -132,13 +136,17 @@ fn test_all_word_counts_integration() {
     }
 }
 
+#[cfg(feature = "rand")]
 #[test]
 fn test_error_handling_integration() {
     // Test error cases in realistic scenarios
 
     // Invalid entropy length
     let result = Mnemonic::new(&[0u8; 15], Language::English);
-    assert!(matches!(result, Err(Error::InvalidEntropyLength { .. })));
+    assert!(matches!(
+        result,
+        Err(khodpay_bip39::Error::InvalidEntropyLength { .. })
+    ));
 
     // Invalid phrase
     let result = Mnemonic::from_phrase("invalid words here not bip39", Language::English);
@@ -149,7 +157,7 @@ fn test_error_handling_integration() {
         "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
         Language::English
     );
-    assert!(matches!(result, Err(Error::InvalidChecksum)));
+    assert!(matches!(result, Err(khodpay_bip39::Error::InvalidChecksum)));
 
     // Wrong language
     let english_mnemonic = Mnemonic::generate(WordCount::Twelve, Language::English).unwrap();
@@ -202,9 +210,10 @@ fn test_hardware_wallet_integration() {
 
     // Generate seed for key derivation
     let seed = restored.to_seed("hardware wallet passphrase").unwrap();
-    assert_eq!(seed.len(), 64);
+    assert_eq!(seed.as_bytes().len(), 64);
 }
 
+#[cfg(feature = "rand")]
 #[test]
 fn test_concurrent_mnemonic_generation() {
     // Verify thread safety by generating mnemonics concurrently
@@ -243,13 +252,14 @@ fn test_bip39_test_vectors() {
 
     // Verify seed generation (without passphrase)
     let seed = mnemonic.to_seed("").unwrap();
-    assert_eq!(seed.len(), 64);
+    assert_eq!(seed.as_bytes().len(), 64);
 
     // Vector should be recoverable
     let recovered = Mnemonic::from_phrase(expected_phrase, Language::English).unwrap();
     assert_eq!(recovered.entropy(), &entropy);
 }
 
+#[cfg(feature = "rand")]
 #[test]
 fn test_cross_constructor_compatibility() {
     // Verify all constructors produce compatible mnemonics
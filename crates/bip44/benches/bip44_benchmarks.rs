@@ -96,7 +96,12 @@ fn bench_wallet_creation(c: &mut Criterion) {
         .unwrap();
 
     group.bench_function("from_seed", |b| {
-        b.iter(|| Wallet::from_seed(black_box(&seed), black_box(Network::BitcoinMainnet)))
+        b.iter(|| {
+            Wallet::from_seed(
+                black_box(seed.as_bytes()),
+                black_box(Network::BitcoinMainnet),
+            )
+        })
     });
 
     group.finish();
@@ -161,7 +166,7 @@ fn bench_address_derivation(c: &mut Criterion) {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -291,7 +296,7 @@ fn bench_account_indices(c: &mut Criterion) {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
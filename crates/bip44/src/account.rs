@@ -54,6 +54,14 @@ mod network_serde {
         let s = match network {
             Network::BitcoinMainnet => "BitcoinMainnet",
             Network::BitcoinTestnet => "BitcoinTestnet",
+            Network::LitecoinMainnet => "LitecoinMainnet",
+            Network::LitecoinTestnet => "LitecoinTestnet",
+            Network::DogecoinMainnet => "DogecoinMainnet",
+            Network::DogecoinTestnet => "DogecoinTestnet",
+            // Custom networks are stored by name and must be registered again
+            // before deserializing
+            Network::Custom(custom) => custom.name(),
+            other => other.name(),
         };
         serializer.serialize_str(s)
     }
@@ -66,7 +74,14 @@ mod network_serde {
         match s.as_str() {
             "BitcoinMainnet" => Ok(Network::BitcoinMainnet),
             "BitcoinTestnet" => Ok(Network::BitcoinTestnet),
-            _ => Err(serde::de::Error::custom(format!("Unknown network: {}", s))),
+            "LitecoinMainnet" => Ok(Network::LitecoinMainnet),
+            "LitecoinTestnet" => Ok(Network::LitecoinTestnet),
+            "DogecoinMainnet" => Ok(Network::DogecoinMainnet),
+            "DogecoinTestnet" => Ok(Network::DogecoinTestnet),
+            _ => Network::registered()
+                .into_iter()
+                .find(|network| matches!(network, Network::Custom(_)) && network.name() == s)
+                .ok_or_else(|| serde::de::Error::custom(format!("Unknown network: {}", s))),
         }
     }
 }
@@ -1341,6 +1356,23 @@ mod serde_tests {
         assert_eq!(metadata, deserialized);
     }
 
+    #[test]
+    fn test_account_metadata_network_round_trip() {
+        use khodpay_bip32::{CustomNetwork, Network};
+
+        let custom =
+            Network::register(CustomNetwork::new("Serde Coin", 0x7E57BB01, 0x7E57BB02)).unwrap();
+        for network in [Network::LitecoinMainnet, Network::DogecoinTestnet, custom] {
+            let metadata = AccountMetadata::new(Purpose::BIP44, CoinType::Litecoin, 0, network);
+            let json = serde_json::to_string(&metadata).unwrap();
+            let deserialized: AccountMetadata = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized.network(), network);
+        }
+
+        let json = r#"{"purpose":"BIP44","coin_type":"Bitcoin","account_index":0,"network":"Unregistered Coin"}"#;
+        assert!(serde_json::from_str::<AccountMetadata>(json).is_err());
+    }
+
     #[test]
     fn test_account_metadata_from_account() {
         use khodpay_bip32::{ChildNumber, ExtendedPrivateKey, Network};
@@ -20,13 +20,17 @@
 
 use crate::{Error, Result, Wallet};
 use khodpay_bip32::Network;
-use khodpay_bip39::Language;
+use khodpay_bip39::{Language, Mnemonic};
+use zeroize::Zeroizing;
 
 /// Builder for constructing a `Wallet` with a fluent API.
 ///
 /// This builder provides a convenient way to construct wallets with various
 /// configuration options in a type-safe manner.
 ///
+/// The mnemonic, seed and password are held in zeroizing buffers that are
+/// wiped when the builder is dropped, and are redacted from `Debug` output.
+///
 /// # Examples
 ///
 /// ```rust
@@ -49,13 +53,14 @@ use khodpay_bip39::Language;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct WalletBuilder {
-    mnemonic: Option<String>,
-    seed: Option<Vec<u8>>,
-    password: String,
+    mnemonic: Option<Zeroizing<String>>,
+    seed: Option<Zeroizing<Vec<u8>>>,
+    password: Zeroizing<String>,
     language: Language,
     network: Option<Network>,
+    reject_weak_mnemonics: bool,
 }
 
 impl WalletBuilder {
@@ -72,9 +77,10 @@ impl WalletBuilder {
         Self {
             mnemonic: None,
             seed: None,
-            password: String::new(),
+            password: Zeroizing::new(String::new()),
             language: Language::English,
             network: None,
+            reject_weak_mnemonics: false,
         }
     }
 
@@ -89,22 +95,30 @@ impl WalletBuilder {
     ///     .mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
     /// ```
     pub fn mnemonic(mut self, mnemonic: &str) -> Self {
-        self.mnemonic = Some(mnemonic.to_string());
+        self.mnemonic = Some(Zeroizing::new(mnemonic.to_string()));
         self
     }
 
     /// Sets the raw seed bytes.
     ///
+    /// The bytes are copied into a zeroizing buffer. A BIP39
+    /// [`Seed`](khodpay_bip39::Seed) can be passed with `as_bytes()`.
+    ///
     /// # Examples
     ///
     /// ```rust
     /// use khodpay_bip44::WalletBuilder;
+    /// use khodpay_bip39::{Language, Mnemonic};
     ///
     /// let seed = [0u8; 64];
     /// let builder = WalletBuilder::new().seed(&seed);
+    ///
+    /// let mnemonic = Mnemonic::new(&[0u8; 16], Language::English).unwrap();
+    /// let seed = mnemonic.to_seed("").unwrap();
+    /// let builder = WalletBuilder::new().seed(seed.as_bytes());
     /// ```
     pub fn seed(mut self, seed: &[u8]) -> Self {
-        self.seed = Some(seed.to_vec());
+        self.seed = Some(Zeroizing::new(seed.to_vec()));
         self
     }
 
@@ -122,13 +136,14 @@ impl WalletBuilder {
     ///     .password("my-secure-password");
     /// ```
     pub fn password(mut self, password: &str) -> Self {
-        self.password = password.to_string();
+        self.password = Zeroizing::new(password.to_string());
         self
     }
 
     /// Sets the language for the mnemonic phrase.
     ///
-    /// Defaults to English.
+    /// Defaults to English. The phrase is parsed in this language even if it
+    /// is also valid in another one.
     ///
     /// # Examples
     ///
@@ -161,6 +176,33 @@ impl WalletBuilder {
         self
     }
 
+    /// Rejects mnemonics that are valid but unsafe to hold funds.
+    ///
+    /// When enabled, [`build`](Self::build) checks the mnemonic with
+    /// [`Mnemonic::risk_report`] and fails if it is a published test phrase
+    /// (such as the BIP39 test vectors or the Hardhat default phrase) or
+    /// follows an obvious pattern. Wallets built from a raw seed are not
+    /// checked. Defaults to `false`; enable it in production builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip44::{Error, WalletBuilder};
+    /// use khodpay_bip32::Network;
+    ///
+    /// let result = WalletBuilder::new()
+    ///     .mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")
+    ///     .network(Network::BitcoinMainnet)
+    ///     .reject_weak_mnemonics(true)
+    ///     .build();
+    ///
+    /// assert!(matches!(result, Err(Error::WeakMnemonic(_))));
+    /// ```
+    pub fn reject_weak_mnemonics(mut self, reject: bool) -> Self {
+        self.reject_weak_mnemonics = reject;
+        self
+    }
+
     /// Builds the wallet with the configured options.
     ///
     /// # Returns
@@ -173,6 +215,8 @@ impl WalletBuilder {
     /// - Neither mnemonic nor seed is provided
     /// - Network is not specified
     /// - Mnemonic is invalid
+    /// - Mnemonic is weak and [`reject_weak_mnemonics`](Self::reject_weak_mnemonics)
+    ///   is enabled
     /// - Seed is invalid
     ///
     /// # Examples
@@ -195,6 +239,16 @@ impl WalletBuilder {
 
         // Build from mnemonic or seed
         if let Some(mnemonic) = self.mnemonic {
+            if self.reject_weak_mnemonics {
+                let report = Mnemonic::from_phrase_confirmed(&mnemonic, self.language.clone())
+                    .map_err(|e| {
+                        Error::InvalidMnemonic(format!("Failed to parse mnemonic: {}", e))
+                    })?
+                    .risk_report();
+                if report.is_weak() {
+                    return Err(Error::WeakMnemonic(report.to_string()));
+                }
+            }
             Wallet::from_mnemonic(&mnemonic, &self.password, self.language, network)
         } else if let Some(seed) = self.seed {
             Wallet::from_seed(&seed, network)
@@ -206,6 +260,21 @@ impl WalletBuilder {
     }
 }
 
+/// Redacts the mnemonic, seed and password.
+impl std::fmt::Debug for WalletBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = |set: bool| if set { "[REDACTED]" } else { "None" };
+        f.debug_struct("WalletBuilder")
+            .field("mnemonic", &redacted(self.mnemonic.is_some()))
+            .field("seed", &redacted(self.seed.is_some()))
+            .field("password", &redacted(!self.password.is_empty()))
+            .field("language", &self.language)
+            .field("network", &self.network)
+            .field("reject_weak_mnemonics", &self.reject_weak_mnemonics)
+            .finish()
+    }
+}
+
 impl Default for WalletBuilder {
     fn default() -> Self {
         Self::new()
@@ -270,6 +339,21 @@ mod tests {
         assert_eq!(wallet.network(), Network::BitcoinMainnet);
     }
 
+    #[test]
+    fn test_builder_ambiguous_chinese() {
+        let builder = WalletBuilder::new()
+            .mnemonic("的 的 的 的 的 的 的 的 的 的 的 在")
+            .language(Language::TraditionalChinese)
+            .network(Network::BitcoinMainnet);
+
+        let wallet = builder.clone().build().unwrap();
+        assert_eq!(wallet.network(), Network::BitcoinMainnet);
+
+        // Parsed in the given language, then rejected for its repeated words
+        let result = builder.reject_weak_mnemonics(true).build();
+        assert!(matches!(result, Err(Error::WeakMnemonic(_))));
+    }
+
     #[test]
     fn test_builder_testnet() {
         let seed = [0u8; 64];
@@ -333,10 +417,75 @@ mod tests {
         let builder = WalletBuilder::default();
 
         // Should have default values
-        assert_eq!(builder.password, "");
+        assert_eq!(builder.password.as_str(), "");
         assert!(matches!(builder.language, Language::English));
     }
 
+    #[test]
+    fn test_builder_debug_redacts_secrets() {
+        let builder = WalletBuilder::new()
+            .mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")
+            .password("hunter2")
+            .network(Network::BitcoinMainnet);
+
+        let debug = format!("{:?}", builder);
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("abandon"));
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("seed: \"None\""));
+    }
+
+    #[test]
+    fn test_builder_rejects_weak_mnemonics() {
+        let weak = [
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "test test test test test test test test test test test junk",
+        ];
+        for mnemonic in weak {
+            let builder = WalletBuilder::new()
+                .mnemonic(mnemonic)
+                .network(Network::BitcoinMainnet);
+            assert!(builder.clone().build().is_ok());
+
+            let result = builder.reject_weak_mnemonics(true).build();
+            assert!(matches!(result, Err(Error::WeakMnemonic(msg)) if msg.contains("published")));
+        }
+
+        // Parse errors are still reported as invalid mnemonics
+        let result = WalletBuilder::new()
+            .mnemonic("abandon abandon abandon")
+            .network(Network::BitcoinMainnet)
+            .reject_weak_mnemonics(true)
+            .build();
+        assert!(matches!(result, Err(Error::InvalidMnemonic(_))));
+    }
+
+    #[test]
+    fn test_builder_accepts_strong_mnemonic() {
+        let mnemonic = Mnemonic::new(
+            &hex::decode("1f4e7a2cb3d8059e6a41c7f2d3b8e0957c6a1d4f").unwrap(),
+            Language::English,
+        )
+        .unwrap();
+        assert!(!mnemonic.risk_report().is_weak());
+
+        let wallet = WalletBuilder::new()
+            .mnemonic(mnemonic.phrase())
+            .network(Network::BitcoinMainnet)
+            .reject_weak_mnemonics(true)
+            .build()
+            .unwrap();
+        assert_eq!(wallet.network(), Network::BitcoinMainnet);
+
+        // Raw seeds are not checked
+        let wallet = WalletBuilder::new()
+            .seed(&[0u8; 64])
+            .network(Network::BitcoinMainnet)
+            .reject_weak_mnemonics(true)
+            .build();
+        assert!(wallet.is_ok());
+    }
+
     #[test]
     fn test_builder_clone() {
         let builder1 = WalletBuilder::new()
@@ -45,6 +45,7 @@ use thiserror::Error;
 /// - **Index Validation**: [`InvalidAccount`], [`InvalidAddressIndex`]
 /// - **Path Validation**: [`InvalidPath`], [`InvalidDepth`], [`InvalidHardenedLevel`]
 /// - **Parsing**: [`ParseError`]
+/// - **Passphrase Search**: [`InvalidSearch`]
 /// - **External Dependencies**: [`Bip32Error`]
 ///
 /// [`InvalidPurpose`]: Error::InvalidPurpose
@@ -56,6 +57,7 @@ use thiserror::Error;
 /// [`InvalidDepth`]: Error::InvalidDepth
 /// [`InvalidHardenedLevel`]: Error::InvalidHardenedLevel
 /// [`ParseError`]: Error::ParseError
+/// [`InvalidSearch`]: Error::InvalidSearch
 /// [`Bip32Error`]: Error::Bip32Error
 #[derive(Debug, Error)]
 pub enum Error {
@@ -224,6 +226,21 @@ pub enum Error {
     #[error("Invalid mnemonic: {0}")]
     InvalidMnemonic(String),
 
+    /// The mnemonic is valid but unsafe to hold funds.
+    ///
+    /// Returned by [`WalletBuilder::build`](crate::WalletBuilder::build) when
+    /// [`reject_weak_mnemonics`](crate::WalletBuilder::reject_weak_mnemonics)
+    /// is enabled and the phrase is a known test phrase or follows an obvious
+    /// pattern. The message lists the risks found.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip44::Error;
+    /// let error = Error::WeakMnemonic("Phrase is a published BIP39 English test vector".to_string());
+    /// ```
+    #[error("Weak mnemonic: {0}")]
+    WeakMnemonic(String),
+
     /// Key derivation error.
     ///
     /// # Example
@@ -233,6 +250,19 @@ pub enum Error {
     /// ```
     #[error("Key derivation error: {0}")]
     KeyDerivation(String),
+
+    /// A passphrase search was configured incorrectly.
+    ///
+    /// Raised for an invalid mask, more candidates than the search's limit,
+    /// or a thread count or batch size of zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use khodpay_bip44::Error;
+    /// let error = Error::InvalidSearch("Unknown mask placeholder '?x'".to_string());
+    /// ```
+    #[error("Invalid passphrase search: {0}")]
+    InvalidSearch(String),
 }
 
 /// Custom equality implementation for [`Error`].
@@ -264,7 +294,9 @@ impl PartialEq for Error {
             (Error::ParseError { reason: r1 }, Error::ParseError { reason: r2 }) => r1 == r2,
             (Error::InvalidSeed(s1), Error::InvalidSeed(s2)) => s1 == s2,
             (Error::InvalidMnemonic(m1), Error::InvalidMnemonic(m2)) => m1 == m2,
+            (Error::WeakMnemonic(m1), Error::WeakMnemonic(m2)) => m1 == m2,
             (Error::KeyDerivation(k1), Error::KeyDerivation(k2)) => k1 == k2,
+            (Error::InvalidSearch(s1), Error::InvalidSearch(s2)) => s1 == s2,
             _ => false,
         }
     }
@@ -141,12 +141,20 @@
 //! # Ok::<(), khodpay_bip44::Error>(())
 //! ```
 //!
+//! ### Recovering a Forgotten Passphrase
+//!
+//! [`PassphraseSearch`] tries [`PassphraseCandidates`] from a list, a hashcat-style
+//! mask or typo variants of a guess on all cores, and reports the passphrase
+//! that reproduces a known master fingerprint, extended public key or address.
+//!
 //! ## Security Considerations
 //!
 //! - **Mnemonic Storage**: Never store mnemonics in plain text. Use secure storage.
 //! - **Password Protection**: Use strong passwords for additional security (BIP-39 passphrase).
 //! - **Key Material**: Private keys should never leave secure memory.
 //! - **Gap Limit**: Follow BIP-44 gap limit (20) for address discovery.
+//! - **Weak Mnemonics**: Enable [`WalletBuilder::reject_weak_mnemonics`] in production so
+//!   test phrases and patterned phrases are refused.
 //!
 //! ## Supported Cryptocurrencies
 //!
@@ -172,6 +180,7 @@ mod derived;
 mod discovery;
 mod error;
 mod iterator;
+mod passphrase_search;
 mod path;
 mod types;
 mod wallet;
@@ -185,6 +194,9 @@ pub use discovery::{
 };
 pub use error::Error;
 pub use iterator::AddressIterator;
+pub use passphrase_search::{
+    PassphraseCandidates, PassphraseMatch, PassphraseSearch, SearchProgress, SearchTarget,
+};
 pub use path::{Bip44Path, Bip44PathBuilder};
 pub use types::{Chain, CoinType, Purpose};
 pub use wallet::Wallet;
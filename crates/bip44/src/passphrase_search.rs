@@ -0,0 +1,834 @@
+//! Recovery of a forgotten BIP-39 passphrase.
+//!
+//! The optional BIP-39 passphrase (the "25th word") changes every key in the
+//! wallet, and a wallet opened with the wrong one looks valid but empty. When
+//! the mnemonic is known and the passphrase is half-remembered, the
+//! candidates can be checked against something known about the wallet:
+//!
+//! - The master key [fingerprint](SearchTarget::Fingerprint), as shown by
+//!   hardware wallets and stored in PSBTs and output descriptors
+//! - An [extended public key](SearchTarget::ExtendedPublicKey) exported from
+//!   the wallet
+//! - An [address](SearchTarget::Address) the wallet has received funds on
+//!
+//! [`PassphraseCandidates`] generates the guesses from a list, a mask or typo
+//! variants of a single guess, and [`PassphraseSearch`] checks them on all
+//! cores. Each candidate costs a 2048-round PBKDF2 seed derivation; the
+//! workers share one [`SeedDeriver`](khodpay_bip39::SeedDeriver), so the
+//! phrase is normalized and the HMAC key schedule computed once per search,
+//! and each batch is derived with
+//! [`SeedDeriver::derive_batch`](khodpay_bip39::SeedDeriver::derive_batch),
+//! which runs eight PBKDF2 instances side by side in AVX-512 builds.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use khodpay_bip32::{ExtendedPrivateKey, Network};
+//! use khodpay_bip39::{Language, Mnemonic};
+//! use khodpay_bip44::{PassphraseCandidates, PassphraseSearch, SearchTarget};
+//!
+//! let mnemonic = Mnemonic::from_phrase(
+//!     "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+//!     Language::English,
+//! )?;
+//! let fingerprint =
+//!     ExtendedPrivateKey::from_bip39_seed(&mnemonic.to_seed("TREZOR")?, Network::BitcoinMainnet)?
+//!         .fingerprint();
+//!
+//! // The last letter of "TREZOR" is unreadable
+//! let found = PassphraseSearch::new(
+//!     &mnemonic,
+//!     PassphraseCandidates::mask("TREZO?u")?,
+//!     SearchTarget::Fingerprint(fingerprint),
+//! )
+//! .run()?;
+//!
+//! assert_eq!(found.unwrap().passphrase(), "TREZOR");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use khodpay_bip32::{DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, Network};
+use khodpay_bip39::{Mnemonic, Seed};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{Error, Result};
+
+/// Default upper bound on the number of candidates one search may check.
+///
+/// At roughly a millisecond per seed and core, 2²⁴ candidates take a few
+/// hours on a desktop machine.
+const DEFAULT_MAX_CANDIDATES: u64 = 1 << 24;
+
+/// Default number of candidates a worker claims at once.
+const DEFAULT_BATCH_SIZE: usize = 32;
+
+/// Lowercase letters (`?l`).
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Uppercase letters (`?u`).
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Decimal digits (`?d`).
+const DIGITS: &str = "0123456789";
+
+/// Printable ASCII symbols including the space (`?s`).
+const SYMBOLS: &str = " !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// A source of passphrase guesses.
+///
+/// Candidates are addressed by index so that search workers can split the
+/// work without generating the same guess twice.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip44::PassphraseCandidates;
+///
+/// let list = PassphraseCandidates::list(["hunter2", "correct horse"]);
+/// assert_eq!(list.len(), 2);
+///
+/// let mask = PassphraseCandidates::mask("pin?d?d")?;
+/// assert_eq!(mask.len(), 100);
+/// assert_eq!(mask.get(42).as_deref().map(String::as_str), Some("pin42"));
+///
+/// let typos = PassphraseCandidates::typos("secret");
+/// assert_eq!(typos.get(0).as_deref().map(String::as_str), Some("secret"));
+/// # Ok::<(), khodpay_bip44::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct PassphraseCandidates {
+    source: CandidateSource,
+}
+
+#[derive(Clone)]
+enum CandidateSource {
+    /// Explicit guesses, tried in order.
+    List(Vec<Zeroizing<String>>),
+    /// One character set per position; the last position varies fastest.
+    Mask(Vec<Vec<char>>),
+}
+
+impl PassphraseCandidates {
+    /// Creates candidates from a list of guesses, tried in order.
+    pub fn list<I, S>(passphrases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            source: CandidateSource::List(
+                passphrases
+                    .into_iter()
+                    .map(|passphrase| Zeroizing::new(passphrase.into()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Creates candidates from a mask.
+    ///
+    /// The mask syntax follows hashcat: `?l` is a lowercase letter, `?u` an
+    /// uppercase letter, `?d` a digit, `?s` a printable symbol or space, `?a`
+    /// any of these and `??` a literal `?`. Every other character stands for
+    /// itself.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSearch`] - If the mask is empty or contains an
+    ///   unknown `?` placeholder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip44::PassphraseCandidates;
+    ///
+    /// let candidates = PassphraseCandidates::mask("Summer20?d?d?s")?;
+    /// assert_eq!(candidates.len(), 10 * 10 * 33);
+    ///
+    /// assert!(PassphraseCandidates::mask("?x").is_err());
+    /// # Ok::<(), khodpay_bip44::Error>(())
+    /// ```
+    pub fn mask(mask: &str) -> Result<Self> {
+        let mut positions = Vec::new();
+        let mut chars = mask.chars();
+        while let Some(c) = chars.next() {
+            if c != '?' {
+                positions.push(vec![c]);
+                continue;
+            }
+            let charset: Vec<char> = match chars.next() {
+                Some('l') => LOWERCASE.chars().collect(),
+                Some('u') => UPPERCASE.chars().collect(),
+                Some('d') => DIGITS.chars().collect(),
+                Some('s') => SYMBOLS.chars().collect(),
+                Some('a') => [LOWERCASE, UPPERCASE, DIGITS, SYMBOLS]
+                    .concat()
+                    .chars()
+                    .collect(),
+                Some('?') => vec!['?'],
+                Some(other) => {
+                    return Err(Error::InvalidSearch(format!(
+                        "Unknown mask placeholder '?{other}'"
+                    )))
+                }
+                None => {
+                    return Err(Error::InvalidSearch(
+                        "Mask ends with an incomplete placeholder".to_string(),
+                    ))
+                }
+            };
+            positions.push(charset);
+        }
+        if positions.is_empty() {
+            return Err(Error::InvalidSearch("Empty mask".to_string()));
+        }
+        Ok(Self {
+            source: CandidateSource::Mask(positions),
+        })
+    }
+
+    /// Creates candidates from a guess and its likely typos.
+    ///
+    /// The guess itself comes first, followed by its all-lowercase,
+    /// all-uppercase and capitalized forms, and then every passphrase one
+    /// edit away: a deleted character, two swapped neighbours, a character
+    /// with its case flipped, and a printable ASCII character substituted or
+    /// inserted anywhere. Duplicates are removed.
+    pub fn typos(guess: &str) -> Self {
+        let chars: Vec<char> = guess.chars().collect();
+        let printable: Vec<char> = (' '..='~').collect();
+        let mut variants: Vec<String> = vec![
+            guess.to_string(),
+            guess.to_lowercase(),
+            guess.to_uppercase(),
+            capitalize(guess),
+        ];
+
+        for i in 0..chars.len() {
+            let mut deleted = chars.clone();
+            deleted.remove(i);
+            variants.push(deleted.into_iter().collect());
+        }
+        for i in 1..chars.len() {
+            let mut swapped = chars.clone();
+            swapped.swap(i - 1, i);
+            variants.push(swapped.into_iter().collect());
+        }
+        for i in 0..chars.len() {
+            let mut flipped = chars.clone();
+            flipped[i] = flip_case(chars[i]);
+            variants.push(flipped.into_iter().collect());
+        }
+        for i in 0..chars.len() {
+            for &c in &printable {
+                let mut substituted = chars.clone();
+                substituted[i] = c;
+                variants.push(substituted.into_iter().collect());
+            }
+        }
+        for i in 0..=chars.len() {
+            for &c in &printable {
+                let mut inserted = chars.clone();
+                inserted.insert(i, c);
+                variants.push(inserted.into_iter().collect());
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let unique = variants
+            .iter()
+            .filter(|variant| seen.insert(variant.as_str()))
+            .map(|variant| Zeroizing::new(variant.clone()))
+            .collect();
+        variants.zeroize();
+        Self {
+            source: CandidateSource::List(unique),
+        }
+    }
+
+    /// Returns the number of candidates.
+    ///
+    /// Saturates at `u64::MAX` for very large masks.
+    pub fn len(&self) -> u64 {
+        match &self.source {
+            CandidateSource::List(list) => list.len() as u64,
+            CandidateSource::Mask(positions) => positions.iter().fold(1u64, |acc, charset| {
+                acc.saturating_mul(charset.len() as u64)
+            }),
+        }
+    }
+
+    /// Returns `true` if there are no candidates.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the candidate at `index`, or `None` if it is out of range.
+    pub fn get(&self, index: u64) -> Option<Zeroizing<String>> {
+        match &self.source {
+            CandidateSource::List(list) => usize::try_from(index)
+                .ok()
+                .and_then(|index| list.get(index))
+                .cloned(),
+            CandidateSource::Mask(positions) => {
+                if index >= self.len() {
+                    return None;
+                }
+                let mut chars = vec![' '; positions.len()];
+                let mut rest = index;
+                for (slot, charset) in chars.iter_mut().zip(positions).rev() {
+                    let radix = charset.len() as u64;
+                    *slot = charset[(rest % radix) as usize];
+                    rest /= radix;
+                }
+                Some(Zeroizing::new(chars.into_iter().collect()))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for PassphraseCandidates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.source {
+            CandidateSource::List(_) => "list",
+            CandidateSource::Mask(_) => "mask",
+        };
+        f.debug_struct("PassphraseCandidates")
+            .field("kind", &kind)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// What a correct passphrase must reproduce.
+#[derive(Debug, Clone)]
+pub enum SearchTarget {
+    /// The fingerprint of the master key.
+    Fingerprint([u8; 4]),
+
+    /// An extended public key at a known derivation path.
+    ///
+    /// The key's network is used for the master key, so the version bytes
+    /// must match as well.
+    ExtendedPublicKey {
+        /// The path of the key from the master, e.g. `m/84'/0'/0'`
+        path: DerivationPath,
+        /// The exported extended public key
+        key: ExtendedPublicKey,
+    },
+
+    /// An address at a known derivation path.
+    ///
+    /// Address formats differ between chains, so `encode` turns the derived
+    /// public key into the address string, for example a P2WPKH encoder or
+    /// `khodpay_signing::Address` for EVM chains. The master key uses the
+    /// search's [`network`](PassphraseSearch::network).
+    Address {
+        /// The path of the address key, e.g. `m/84'/0'/0'/0/0`
+        path: DerivationPath,
+        /// The known address
+        address: String,
+        /// Encodes a derived key as an address
+        encode: fn(&ExtendedPublicKey) -> String,
+    },
+}
+
+impl SearchTarget {
+    /// Returns `true` if the master key derived from `seed` reproduces the target.
+    fn matches(&self, seed: &Seed, network: Network) -> Result<bool> {
+        Ok(match self {
+            SearchTarget::Fingerprint(fingerprint) => {
+                ExtendedPrivateKey::from_bip39_seed(seed, network)?.fingerprint() == *fingerprint
+            }
+            SearchTarget::ExtendedPublicKey { path, key } => {
+                ExtendedPrivateKey::from_bip39_seed(seed, key.network())?
+                    .derive_path(path)?
+                    .to_extended_public_key()
+                    == *key
+            }
+            SearchTarget::Address {
+                path,
+                address,
+                encode,
+            } => {
+                let key = ExtendedPrivateKey::from_bip39_seed(seed, network)?
+                    .derive_path(path)?
+                    .to_extended_public_key();
+                encode(&key) == *address
+            }
+        })
+    }
+}
+
+/// Progress of a running [`PassphraseSearch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchProgress {
+    /// Number of candidates checked so far
+    pub checked: u64,
+    /// Total number of candidates
+    pub total: u64,
+}
+
+impl SearchProgress {
+    /// Returns the completed fraction, from 0.0 to 1.0.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.checked as f64 / self.total as f64
+        }
+    }
+}
+
+/// The passphrase found by a [`PassphraseSearch`].
+///
+/// The passphrase is zeroized on drop and redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PassphraseMatch {
+    passphrase: Zeroizing<String>,
+    index: u64,
+}
+
+impl PassphraseMatch {
+    /// Returns the recovered passphrase.
+    pub fn passphrase(&self) -> &str {
+        &self.passphrase
+    }
+
+    /// Returns the index of the passphrase among the candidates.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+impl fmt::Debug for PassphraseMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PassphraseMatch")
+            .field("passphrase", &"[REDACTED]")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+/// A callback that receives search progress.
+type ProgressCallback<'a> = Box<dyn Fn(SearchProgress) + Send + Sync + 'a>;
+
+/// A parallel search for a mnemonic's passphrase.
+///
+/// Configure the search with the builder methods and run it with
+/// [`run`](PassphraseSearch::run). Candidates are handed out to worker
+/// threads in batches; the search stops once a batch contains a match and
+/// returns the match with the lowest candidate index.
+///
+/// # Examples
+///
+/// ```rust
+/// use khodpay_bip32::{DerivationPath, ExtendedPrivateKey, Network};
+/// use khodpay_bip39::{Language, Mnemonic};
+/// use khodpay_bip44::{PassphraseCandidates, PassphraseSearch, SearchTarget};
+/// use std::str::FromStr;
+///
+/// let mnemonic = Mnemonic::new(&[0x11; 16], Language::English)?;
+/// let path = DerivationPath::from_str("m/84'/0'/0'")?;
+/// let xpub = ExtendedPrivateKey::from_bip39_seed(&mnemonic.to_seed("Tr0ub4dor")?, Network::BitcoinMainnet)?
+///     .derive_path(&path)?
+///     .to_extended_public_key();
+///
+/// let found = PassphraseSearch::new(
+///     &mnemonic,
+///     PassphraseCandidates::typos("tr0ub4dor"),
+///     SearchTarget::ExtendedPublicKey { path, key: xpub },
+/// )
+/// .threads(2)
+/// .on_progress(|progress| println!("{:.1}%", progress.fraction() * 100.0))
+/// .run()?;
+///
+/// assert_eq!(found.unwrap().passphrase(), "Tr0ub4dor");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct PassphraseSearch<'a> {
+    mnemonic: &'a Mnemonic,
+    candidates: PassphraseCandidates,
+    target: SearchTarget,
+    network: Network,
+    threads: usize,
+    batch_size: usize,
+    max_candidates: u64,
+    progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a> PassphraseSearch<'a> {
+    /// Creates a search for the passphrase of `mnemonic`.
+    pub fn new(
+        mnemonic: &'a Mnemonic,
+        candidates: PassphraseCandidates,
+        target: SearchTarget,
+    ) -> Self {
+        Self {
+            mnemonic,
+            candidates,
+            target,
+            network: Network::BitcoinMainnet,
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_candidates: DEFAULT_MAX_CANDIDATES,
+            progress: None,
+        }
+    }
+
+    /// Sets the network of the master key for fingerprint and address
+    /// targets (default Bitcoin mainnet).
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Sets the number of worker threads (default: one per available core).
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets the number of candidates a worker claims at once and checks
+    /// before reporting progress (default 32).
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets the maximum number of candidates the search may check (default 2²⁴).
+    pub fn max_candidates(mut self, limit: u64) -> Self {
+        self.max_candidates = limit;
+        self
+    }
+
+    /// Calls `callback` after each batch with the number of candidates checked.
+    ///
+    /// The callback runs on the worker threads.
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(SearchProgress) + Send + Sync + 'a,
+    {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Runs the search.
+    ///
+    /// # Returns
+    ///
+    /// The matching passphrase, or `None` if no candidate reproduces the target
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSearch`] - If there are more candidates than the
+    ///   limit, or the thread count or batch size is zero
+    /// * [`Error::Bip32Error`] - If key derivation fails
+    pub fn run(self) -> Result<Option<PassphraseMatch>> {
+        let total = self.candidates.len();
+        if total > self.max_candidates {
+            return Err(Error::InvalidSearch(format!(
+                "{total} candidates exceed the limit of {}",
+                self.max_candidates
+            )));
+        }
+        if self.threads == 0 || self.batch_size == 0 {
+            return Err(Error::InvalidSearch(
+                "Thread count and batch size must be at least 1".to_string(),
+            ));
+        }
+
+        let deriver = self.mnemonic.seed_deriver();
+        let next = AtomicU64::new(0);
+        let checked = AtomicU64::new(0);
+        let stop = AtomicBool::new(false);
+        let found: Mutex<Option<PassphraseMatch>> = Mutex::new(None);
+
+        let worker = || -> Result<()> {
+            while !stop.load(Ordering::Relaxed) {
+                let start = next.fetch_add(self.batch_size as u64, Ordering::Relaxed);
+                if start >= total {
+                    break;
+                }
+                let end = total.min(start + self.batch_size as u64);
+                let batch: Vec<(u64, Zeroizing<String>)> = (start..end)
+                    .filter_map(|index| Some((index, self.candidates.get(index)?)))
+                    .collect();
+                let passphrases: Vec<&str> = batch.iter().map(|(_, p)| p.as_str()).collect();
+                let seeds = deriver.derive_batch(&passphrases);
+
+                let mut batch_checked = 0;
+                for ((index, passphrase), seed) in batch.iter().zip(&seeds) {
+                    batch_checked += 1;
+                    let matched = match self.target.matches(seed, self.network) {
+                        Ok(matched) => matched,
+                        Err(err) => {
+                            // Stop the other workers before reporting the error
+                            stop.store(true, Ordering::Relaxed);
+                            return Err(err);
+                        }
+                    };
+                    if matched {
+                        let mut found = found.lock().expect("search result lock poisoned");
+                        if found.as_ref().map_or(true, |m| *index < m.index) {
+                            *found = Some(PassphraseMatch {
+                                passphrase: passphrase.clone(),
+                                index: *index,
+                            });
+                        }
+                        stop.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                }
+
+                let done = checked.fetch_add(batch_checked, Ordering::Relaxed) + batch_checked;
+                if let Some(progress) = &self.progress {
+                    progress(SearchProgress {
+                        checked: done,
+                        total,
+                    });
+                }
+            }
+            Ok(())
+        };
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..self.threads).map(|_| scope.spawn(worker)).collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+                })
+                .collect::<Result<Vec<()>>>()
+        })?;
+
+        Ok(found.into_inner().expect("search result lock poisoned"))
+    }
+}
+
+impl fmt::Debug for PassphraseSearch<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PassphraseSearch")
+            .field("candidates", &self.candidates)
+            .field("target", &self.target)
+            .field("network", &self.network)
+            .field("threads", &self.threads)
+            .field("batch_size", &self.batch_size)
+            .field("max_candidates", &self.max_candidates)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Uppercases the first character and lowercases the rest.
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Flips the case of an ASCII letter; other characters are returned unchanged.
+fn flip_case(c: char) -> char {
+    if c.is_ascii_lowercase() {
+        c.to_ascii_uppercase()
+    } else {
+        c.to_ascii_lowercase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khodpay_bip39::Language;
+    use std::str::FromStr;
+    use std::sync::atomic::AtomicUsize;
+
+    fn mnemonic() -> Mnemonic {
+        Mnemonic::new(&[0x5a; 16], Language::English).unwrap()
+    }
+
+    fn fingerprint(mnemonic: &Mnemonic, passphrase: &str) -> [u8; 4] {
+        let seed = mnemonic.to_seed(passphrase).unwrap();
+        ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet)
+            .unwrap()
+            .fingerprint()
+    }
+
+    #[test]
+    fn test_mask_enumeration() {
+        let candidates = PassphraseCandidates::mask("a?d?l").unwrap();
+        assert_eq!(candidates.len(), 260);
+        assert_eq!(candidates.get(0).unwrap().as_str(), "a0a");
+        assert_eq!(candidates.get(27).unwrap().as_str(), "a1b");
+        assert_eq!(candidates.get(259).unwrap().as_str(), "a9z");
+        assert!(candidates.get(260).is_none());
+
+        let literal = PassphraseCandidates::mask("why??").unwrap();
+        assert_eq!(literal.len(), 1);
+        assert_eq!(literal.get(0).unwrap().as_str(), "why?");
+        assert_eq!(PassphraseCandidates::mask("?a").unwrap().len(), 95);
+    }
+
+    #[test]
+    fn test_invalid_masks() {
+        for mask in ["", "abc?", "?q"] {
+            assert!(matches!(
+                PassphraseCandidates::mask(mask),
+                Err(Error::InvalidSearch(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_typo_variants() {
+        let candidates = PassphraseCandidates::typos("Pass");
+        let all: Vec<String> = (0..candidates.len())
+            .map(|i| candidates.get(i).unwrap().to_string())
+            .collect();
+        assert_eq!(all[0], "Pass");
+        for expected in [
+            "pass", "PASS", "Pas", "aPss", "PAss", "Pbss", "Passs", "Pas!s",
+        ] {
+            assert!(all.contains(&expected.to_string()), "{expected}");
+        }
+        let unique: HashSet<_> = all.iter().collect();
+        assert_eq!(unique.len(), all.len());
+    }
+
+    #[test]
+    fn test_finds_fingerprint_from_list() {
+        let mnemonic = mnemonic();
+        let target = SearchTarget::Fingerprint(fingerprint(&mnemonic, "hunter2"));
+        let candidates = PassphraseCandidates::list(["", "password", "hunter2", "letmein"]);
+
+        let found = PassphraseSearch::new(&mnemonic, candidates, target)
+            .threads(2)
+            .batch_size(1)
+            .run()
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.passphrase(), "hunter2");
+        assert_eq!(found.index(), 2);
+        assert!(!format!("{found:?}").contains("hunter2"));
+    }
+
+    #[test]
+    fn test_finds_typo_against_address() {
+        let mnemonic = mnemonic();
+        let path = DerivationPath::from_str("m/44'/0'/0'/0/0").unwrap();
+        let encode: fn(&ExtendedPublicKey) -> String =
+            |key| hex::encode(key.public_key().to_bytes());
+        let seed = mnemonic.to_seed("Satoshi").unwrap();
+        let address = encode(
+            &ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet)
+                .unwrap()
+                .derive_path(&path)
+                .unwrap()
+                .to_extended_public_key(),
+        );
+
+        let found = PassphraseSearch::new(
+            &mnemonic,
+            PassphraseCandidates::typos("Satosih"),
+            SearchTarget::Address {
+                path,
+                address,
+                encode,
+            },
+        )
+        .run()
+        .unwrap();
+        assert_eq!(found.unwrap().passphrase(), "Satoshi");
+    }
+
+    #[test]
+    fn test_no_match_reports_full_progress() {
+        let mnemonic = mnemonic();
+        let target = SearchTarget::Fingerprint(fingerprint(&mnemonic, "not in the list"));
+        let calls = AtomicUsize::new(0);
+        let last = Mutex::new(None);
+
+        let found =
+            PassphraseSearch::new(&mnemonic, PassphraseCandidates::mask("?d").unwrap(), target)
+                .threads(3)
+                .batch_size(4)
+                .on_progress(|progress| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    let mut last = last.lock().unwrap();
+                    if last.map_or(true, |p: SearchProgress| progress.checked > p.checked) {
+                        *last = Some(progress);
+                    }
+                })
+                .run()
+                .unwrap();
+
+        assert!(found.is_none());
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+        let last = last.into_inner().unwrap().unwrap();
+        assert_eq!(
+            last,
+            SearchProgress {
+                checked: 10,
+                total: 10
+            }
+        );
+        assert_eq!(last.fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_progress_counts_only_checked_candidates() {
+        let mnemonic = mnemonic();
+        let target = SearchTarget::Fingerprint(fingerprint(&mnemonic, "2"));
+        let last = Mutex::new(None);
+
+        let found =
+            PassphraseSearch::new(&mnemonic, PassphraseCandidates::mask("?d").unwrap(), target)
+                .threads(1)
+                .batch_size(10)
+                .on_progress(|progress| *last.lock().unwrap() = Some(progress))
+                .run()
+                .unwrap();
+
+        assert_eq!(found.unwrap().index(), 2);
+        // The search stops after the match; the rest of the batch is unchecked
+        assert_eq!(
+            last.into_inner().unwrap(),
+            Some(SearchProgress {
+                checked: 3,
+                total: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_limits() {
+        let mnemonic = mnemonic();
+        let target = SearchTarget::Fingerprint([0; 4]);
+        let candidates = PassphraseCandidates::mask("?a?a?a?a").unwrap();
+        assert!(matches!(
+            PassphraseSearch::new(&mnemonic, candidates.clone(), target.clone())
+                .max_candidates(1000)
+                .run(),
+            Err(Error::InvalidSearch(_))
+        ));
+        assert!(matches!(
+            PassphraseSearch::new(&mnemonic, PassphraseCandidates::list(["a"]), target)
+                .threads(0)
+                .run(),
+            Err(Error::InvalidSearch(_))
+        ));
+    }
+}
@@ -20,6 +20,7 @@
 //! ```
 
 use crate::{Error, Result};
+use khodpay_bip32::ScriptType;
 use std::fmt;
 
 /// Derivation purpose constants defining different address format standards.
@@ -144,6 +145,29 @@ impl Purpose {
             Purpose::BIP86 => "Taproot",
         }
     }
+
+    /// Returns the SLIP-132 script type used to export this purpose's account keys.
+    ///
+    /// BIP-86 (Taproot) has no SLIP-132 encoding; Taproot descriptors use plain
+    /// xpub strings instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use khodpay_bip32::ScriptType;
+    /// use khodpay_bip44::Purpose;
+    ///
+    /// assert_eq!(Purpose::BIP84.script_type(), Some(ScriptType::P2wpkh));
+    /// assert_eq!(Purpose::BIP86.script_type(), None);
+    /// ```
+    pub const fn script_type(&self) -> Option<ScriptType> {
+        match self {
+            Purpose::BIP44 => Some(ScriptType::P2pkh),
+            Purpose::BIP49 => Some(ScriptType::P2shP2wpkh),
+            Purpose::BIP84 => Some(ScriptType::P2wpkh),
+            Purpose::BIP86 => None,
+        }
+    }
 }
 
 impl TryFrom<u32> for Purpose {
@@ -238,6 +262,14 @@ mod tests {
         assert_eq!(Purpose::BIP86.description(), "Taproot");
     }
 
+    #[test]
+    fn test_purpose_script_types() {
+        assert_eq!(Purpose::BIP44.script_type(), Some(ScriptType::P2pkh));
+        assert_eq!(Purpose::BIP49.script_type(), Some(ScriptType::P2shP2wpkh));
+        assert_eq!(Purpose::BIP84.script_type(), Some(ScriptType::P2wpkh));
+        assert_eq!(Purpose::BIP86.script_type(), None);
+    }
+
     #[test]
     fn test_purpose_try_from_valid() {
         assert_eq!(Purpose::try_from(44).unwrap(), Purpose::BIP44);
@@ -721,6 +753,10 @@ pub enum CoinType {
     ///
     /// Proof-of-stake blockchain with academic research foundation.
     ///
+    /// Cardano keys are not secp256k1 keys: derive them with
+    /// `khodpay_bip32::CardanoExtendedPrivateKey` (BIP32-Ed25519 with an
+    /// Icarus master key) along the CIP-1852 path `m/1852'/1815'/0'/0/0`.
+    ///
     /// Network: Mainnet  
     /// Symbol: ADA  
     /// Path example: `m/44'/1815'/0'/0/0`
@@ -51,6 +51,11 @@ impl Wallet {
     /// The mnemonic is converted to a seed using BIP39 standard derivation,
     /// then used to generate the master extended private key.
     ///
+    /// The phrase is parsed in `language` as given, so a phrase that is valid
+    /// in several languages (such as Simplified and Traditional Chinese
+    /// phrases built from shared characters) is accepted rather than
+    /// rejected as ambiguous.
+    ///
     /// # Arguments
     ///
     /// * `mnemonic` - BIP39 mnemonic phrase (12, 15, 18, 21, or 24 words)
@@ -87,16 +92,17 @@ impl Wallet {
         network: Network,
     ) -> Result<Self> {
         // Parse the mnemonic using khodpay-bip39
-        let mnemonic = Mnemonic::from_phrase(mnemonic, language)
+        // The caller names the language, so it counts as confirmed
+        let mnemonic = Mnemonic::from_phrase_confirmed(mnemonic, language)
             .map_err(|e| Error::InvalidMnemonic(format!("Failed to parse mnemonic: {}", e)))?;
 
-        // Convert to seed using BIP39
+        // Convert to seed using BIP39; the seed is zeroized on drop
         let seed = mnemonic
             .to_seed(password)
             .map_err(|e| Error::InvalidMnemonic(format!("Failed to generate seed: {}", e)))?;
 
         // Create wallet from seed
-        Self::from_seed(&seed, network)
+        Self::from_seed(seed.as_bytes(), network)
     }
 
     /// Creates a new wallet from an English BIP39 mnemonic phrase.
@@ -414,6 +420,31 @@ mod tests {
         assert_eq!(wallet.network(), Network::BitcoinMainnet);
     }
 
+    #[test]
+    fn test_wallet_from_mnemonic_ambiguous_chinese() {
+        // Every word is shared by the Simplified and Traditional Chinese lists
+        let mnemonic = "的 的 的 的 的 的 的 的 的 的 的 在";
+        let simplified = Wallet::from_mnemonic(
+            mnemonic,
+            "",
+            Language::SimplifiedChinese,
+            Network::BitcoinMainnet,
+        )
+        .unwrap();
+        let traditional = Wallet::from_mnemonic(
+            mnemonic,
+            "",
+            Language::TraditionalChinese,
+            Network::BitcoinMainnet,
+        )
+        .unwrap();
+
+        assert_eq!(
+            simplified.master_key().to_string(),
+            traditional.master_key().to_string()
+        );
+    }
+
     #[test]
     fn test_wallet_from_english_mnemonic() {
         let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
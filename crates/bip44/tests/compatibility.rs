@@ -164,7 +164,7 @@ fn scenario_address_gap_limit_scanning() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -292,7 +292,7 @@ fn scenario_hardware_wallet_paths() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // Ledger Bitcoin path: m/44'/0'/0'/0/0
     let ledger_btc =
@@ -384,7 +384,7 @@ fn scenario_merchant_batch_addresses() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -492,7 +492,7 @@ fn scenario_change_address_management() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -565,7 +565,7 @@ fn scenario_high_volume_addresses() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
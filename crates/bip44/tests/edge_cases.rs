@@ -57,7 +57,7 @@ fn edge_case_max_address_index() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -171,7 +171,7 @@ fn edge_case_batch_derivation_zero_count() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -192,7 +192,7 @@ fn edge_case_batch_derivation_single() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -217,7 +217,7 @@ fn edge_case_batch_derivation_high_start() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -245,7 +245,7 @@ fn edge_case_batch_derivation_near_max() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -427,7 +427,7 @@ fn edge_case_chains_always_different() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -458,7 +458,7 @@ fn edge_case_sequential_addresses_unique() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -19,7 +19,7 @@ fn test_complete_workflow_mnemonic_to_addresses() {
     let seed = mnemonic.to_seed("").unwrap();
 
     // Step 3: Create master key from seed (BIP-32)
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // Step 4: Derive BIP-44 path manually (BIP-32 + BIP-44)
     // m/44'/0'/0'/0/0
@@ -128,7 +128,7 @@ fn test_external_vs_internal_chains() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // Derive to account level
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
@@ -153,7 +153,7 @@ fn test_bip44_path_construction_and_derivation() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // Create BIP-44 path
     let path = Bip44Path::new(Purpose::BIP44, CoinType::Bitcoin, 0, Chain::External, 5).unwrap();
@@ -224,7 +224,7 @@ fn test_seed_vs_mnemonic_equivalence() {
     // Create wallet from seed
     let mnemonic = Mnemonic::from_phrase(mnemonic_phrase, Language::English).unwrap();
     let seed = mnemonic.to_seed("").unwrap();
-    let wallet2 = Wallet::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let wallet2 = Wallet::from_seed(seed.as_bytes(), Network::BitcoinMainnet).unwrap();
 
     // Both wallets should produce the same master key
     assert_eq!(
@@ -298,7 +298,7 @@ fn test_address_batch_derivation() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // Derive to account level
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
@@ -379,7 +379,7 @@ fn test_large_address_indices() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -26,7 +26,7 @@ fn test_vector_1_bitcoin_standard_mnemonic() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // Expected master key fingerprint and chain code from BIP-32/39 test vectors
     // Master key should be deterministic from this seed
@@ -107,7 +107,7 @@ fn test_vector_3_external_vs_internal_chains() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // Derive account
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
@@ -162,7 +162,7 @@ fn test_vector_5_different_purposes() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
 
     // BIP-44: m/44'/0'/0'/0/0
     let bip44_path =
@@ -211,7 +211,7 @@ fn test_vector_6_sequential_addresses() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -283,9 +283,9 @@ fn test_vector_8_password_protected() {
 
     // Master keys should be different
     let master_no_pass =
-        ExtendedPrivateKey::from_seed(&seed_no_pass, Network::BitcoinMainnet).unwrap();
+        ExtendedPrivateKey::from_bip39_seed(&seed_no_pass, Network::BitcoinMainnet).unwrap();
     let master_with_pass =
-        ExtendedPrivateKey::from_seed(&seed_with_pass, Network::BitcoinMainnet).unwrap();
+        ExtendedPrivateKey::from_bip39_seed(&seed_with_pass, Network::BitcoinMainnet).unwrap();
 
     assert_ne!(master_no_pass.private_key(), master_with_pass.private_key());
 }
@@ -334,7 +334,7 @@ fn test_vector_10_high_address_indices() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();
@@ -466,7 +466,7 @@ fn test_vector_14_batch_derivation_consistency() {
         .to_seed("")
         .unwrap();
 
-    let master_key = ExtendedPrivateKey::from_seed(&seed, Network::BitcoinMainnet).unwrap();
+    let master_key = ExtendedPrivateKey::from_bip39_seed(&seed, Network::BitcoinMainnet).unwrap();
     let purpose_key = master_key.derive_child(ChildNumber::Hardened(44)).unwrap();
     let coin_key = purpose_key.derive_child(ChildNumber::Hardened(0)).unwrap();
     let account_key = coin_key.derive_child(ChildNumber::Hardened(0)).unwrap();